@@ -0,0 +1,252 @@
+use burn_tensor::{backend::Backend, Data, ElementConversion, Shape};
+
+/// Subscripts of an einsum equation with two operands, used to compute the gradient of each
+/// operand.
+///
+/// The gradient of an operand is the einsum of the output gradient with the other operand, where
+/// the distinct subscripts of the operand become the output. The gradient is then broadcasted
+/// over the subscripts only summed over in the operand, and kept on the diagonal of the
+/// repeated subscripts.
+#[derive(Debug, Clone)]
+pub(crate) struct EinsumSubscripts {
+    lhs: Vec<String>,
+    rhs: Vec<String>,
+    output: Vec<String>,
+}
+
+impl EinsumSubscripts {
+    /// Parse the equation, panicking if the gradients of its operands can't be computed.
+    pub(crate) fn parse(equation: &str, num_dims: [usize; 2]) -> Self {
+        let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+        let (lhs, rhs) = match inputs.split_once(',') {
+            Some((lhs, rhs)) if !rhs.contains(',') => (Self::tokens(lhs), Self::tokens(rhs)),
+            _ => panic!("Invalid einsum equation '{equation}': expected 2 operands"),
+        };
+
+        let output = match output {
+            Some(output) => Self::tokens(output),
+            None => {
+                // Implicit output: ellipsis followed by the labels appearing only once, sorted.
+                let count = |token: &String| lhs.iter().chain(&rhs).filter(|t| *t == token).count();
+                let mut output: Vec<String> = lhs
+                    .iter()
+                    .chain(&rhs)
+                    .filter(|token| token.as_str() != "..." && count(token) == 1)
+                    .cloned()
+                    .collect();
+                output.sort();
+                if lhs.iter().chain(&rhs).any(|token| token == "...") {
+                    output.insert(0, "...".to_string());
+                }
+                output
+            }
+        };
+
+        let subscripts = Self { lhs, rhs, output };
+        subscripts.check_differentiable(&equation, num_dims);
+        subscripts
+    }
+
+    /// Compute the gradient of the left operand from the output gradient and the right operand.
+    pub(crate) fn lhs_grad<B: Backend, const D: usize, const D2: usize, const D3: usize>(
+        &self,
+        grad: B::TensorPrimitive<D3>,
+        rhs: B::TensorPrimitive<D2>,
+        shape_lhs: Shape<D>,
+    ) -> B::TensorPrimitive<D> {
+        self.operand_grad::<B, D, D2, D3>(&self.lhs, &self.rhs, grad, rhs, shape_lhs)
+    }
+
+    /// Compute the gradient of the right operand from the output gradient and the left operand.
+    pub(crate) fn rhs_grad<B: Backend, const D: usize, const D2: usize, const D3: usize>(
+        &self,
+        grad: B::TensorPrimitive<D3>,
+        lhs: B::TensorPrimitive<D>,
+        shape_rhs: Shape<D2>,
+    ) -> B::TensorPrimitive<D2> {
+        self.operand_grad::<B, D2, D, D3>(&self.rhs, &self.lhs, grad, lhs, shape_rhs)
+    }
+
+    fn operand_grad<B: Backend, const D: usize, const D2: usize, const D3: usize>(
+        &self,
+        term: &[String],
+        other: &[String],
+        grad: B::TensorPrimitive<D3>,
+        other_tensor: B::TensorPrimitive<D2>,
+        shape: Shape<D>,
+    ) -> B::TensorPrimitive<D> {
+        let num_ellipsis_dims = D + 1 - term.len();
+        let dims = |token: &String| match token.as_str() {
+            "..." => num_ellipsis_dims,
+            _ => 1,
+        };
+
+        // The subscripts of the gradient before broadcasting, in the order of the operand.
+        let kept: Vec<String> = term
+            .iter()
+            .enumerate()
+            .filter(|(i, token)| {
+                !term[..*i].contains(token)
+                    && (token.as_str() == "..."
+                        || self.output.contains(token)
+                        || other.contains(token))
+            })
+            .map(|(_, token)| token.clone())
+            .collect();
+        let equation = format!(
+            "{},{}->{}",
+            self.output.concat(),
+            other.concat(),
+            kept.concat()
+        );
+        let num_kept_dims: usize = kept.iter().map(dims).sum();
+
+        if num_kept_dims == D {
+            return B::einsum(&equation, grad, other_tensor);
+        }
+
+        macro_rules! reshaped {
+            ($($rank:literal),*) => {
+                match num_kept_dims.max(1) {
+                    $($rank => {
+                        let grad: B::TensorPrimitive<$rank> =
+                            B::einsum(&equation, grad, other_tensor);
+                        Self::reshape_kept::<B, $rank, D>(term, &kept, grad, num_ellipsis_dims)
+                    })*
+                    rank => panic!("Einsum backward isn't supported for a gradient of rank {rank}"),
+                }
+            };
+        }
+        let grad = reshaped!(1, 2, 3, 4, 5, 6, 7, 8);
+
+        let mask = Self::mask::<B, D>(term, &kept, &shape, num_ellipsis_dims, &B::device(&grad));
+        B::mul(grad, mask)
+    }
+
+    /// Reshape the gradient of the kept subscripts to the rank of the operand, with dimensions
+    /// of size one for the other subscripts.
+    fn reshape_kept<B: Backend, const K: usize, const D: usize>(
+        term: &[String],
+        kept: &[String],
+        grad: B::TensorPrimitive<K>,
+        num_ellipsis_dims: usize,
+    ) -> B::TensorPrimitive<D> {
+        let shape_grad = B::shape(&grad);
+        let mut dims_grad = shape_grad.dims.iter();
+        let mut dims = [1; D];
+        let mut dim = 0;
+
+        for (i, token) in term.iter().enumerate() {
+            let is_kept = !term[..i].contains(token) && kept.contains(token);
+            let num_dims = match token.as_str() {
+                "..." => num_ellipsis_dims,
+                _ => 1,
+            };
+
+            for _ in 0..num_dims {
+                if is_kept {
+                    dims[dim] = *dims_grad.next().unwrap();
+                }
+                dim += 1;
+            }
+        }
+
+        B::reshape(grad, Shape::new(dims))
+    }
+
+    /// Mask broadcasting the gradient over the subscripts only summed over in the operand, and
+    /// keeping the diagonal of the repeated subscripts.
+    fn mask<B: Backend, const D: usize>(
+        term: &[String],
+        kept: &[String],
+        shape: &Shape<D>,
+        num_ellipsis_dims: usize,
+        device: &B::Device,
+    ) -> B::TensorPrimitive<D> {
+        let mut dims = [1; D];
+        let mut diagonals = Vec::new();
+        let mut dim = 0;
+
+        for (i, token) in term.iter().enumerate() {
+            if token == "..." {
+                dim += num_ellipsis_dims;
+                continue;
+            }
+
+            match term[..i].iter().position(|t| t == token) {
+                Some(first) => {
+                    let first = match term[..first].iter().any(|t| t == "...") {
+                        true => first + num_ellipsis_dims - 1,
+                        false => first,
+                    };
+                    dims[first] = shape.dims[first];
+                    dims[dim] = shape.dims[dim];
+                    diagonals.push((first, dim));
+                }
+                None if !kept.contains(token) => dims[dim] = shape.dims[dim],
+                None => {}
+            }
+            dim += 1;
+        }
+
+        let shape_mask = Shape::new(dims);
+        let values = (0..shape_mask.num_elements())
+            .map(|index| {
+                let mut index = index;
+                let mut position = [0; D];
+                for d in (0..D).rev() {
+                    position[d] = index % dims[d];
+                    index /= dims[d];
+                }
+
+                let on_diagonals = diagonals.iter().all(|(a, b)| position[*a] == position[*b]);
+                (on_diagonals as i32 as f32).elem()
+            })
+            .collect();
+
+        B::from_data(Data::new(values, shape_mask), device)
+    }
+
+    fn tokens(term: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut rest = term;
+
+        while let Some(c) = rest.chars().next() {
+            match rest.strip_prefix("...") {
+                Some(remaining) => {
+                    tokens.push("...".to_string());
+                    rest = remaining;
+                }
+                None if c.is_ascii_alphabetic() => {
+                    tokens.push(c.to_string());
+                    rest = &rest[1..];
+                }
+                None => panic!("Invalid einsum subscripts '{term}'"),
+            }
+        }
+
+        tokens
+    }
+
+    fn check_differentiable(&self, equation: &str, num_dims: [usize; 2]) {
+        let num_ellipsis_dims = |term: &Vec<String>, num_dims: usize| {
+            term.iter()
+                .any(|token| token == "...")
+                .then(|| num_dims + 1 - term.len())
+        };
+        if let (Some(lhs), Some(rhs)) = (
+            num_ellipsis_dims(&self.lhs, num_dims[0]),
+            num_ellipsis_dims(&self.rhs, num_dims[1]),
+        ) {
+            if lhs != rhs {
+                panic!(
+                    "Einsum backward of '{equation}' isn't supported: the ellipsis covers {lhs} and {rhs} dimensions"
+                );
+            }
+        }
+    }
+}
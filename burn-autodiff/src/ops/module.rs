@@ -151,31 +151,461 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     }
 
     fn conv_transpose2d(
-        _x: ADTensor<B, 4>,
-        _weight: ADTensor<B, 4>,
-        _bias: Option<ADTensor<B, 1>>,
-        _options: ConvTransposeOptions<2>,
+        x: ADTensor<B, 4>,
+        weight: ADTensor<B, 4>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvTransposeOptions<2>,
     ) -> ADTensor<B, 4> {
-        todo!("Transposed 2D convolution doesn't yet support backward.");
+        #[derive(Debug)]
+        struct ConvTranspose2DWithBias;
+        #[derive(Debug)]
+        struct ConvTranspose2DNoBias;
+
+        impl<B: Backend> Backward<B, 4, 3> for ConvTranspose2DWithBias {
+            type State = (
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<1>,
+                ConvTransposeOptions<2>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv_transpose2d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 4>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 4>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 4, 2> for ConvTranspose2DNoBias {
+            type State = (
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                ConvTransposeOptions<2>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv_transpose2d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 4>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 4>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => {
+                match ConvTranspose2DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv_transpose2d(
+                            x.primitive,
+                            weight.primitive,
+                            Some(bias.primitive),
+                            options,
+                        ),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose2d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match ConvTranspose2DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv_transpose2d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose2d(
+                        x.primitive,
+                        weight.primitive,
+                        None,
+                        options,
+                    )),
+                }
+            }
+        }
+    }
+
+    fn conv3d(
+        x: ADTensor<B, 5>,
+        weight: ADTensor<B, 5>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct Conv3DWithBias;
+        #[derive(Debug)]
+        struct Conv3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for Conv3DWithBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<1>,
+                ConvOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv3d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for Conv3DNoBias {
+            type State = (B::TensorPrimitive<5>, B::TensorPrimitive<5>, ConvOptions<3>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => {
+                match Conv3DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv3d(x.primitive, weight.primitive, Some(bias.primitive), options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv3d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match Conv3DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv3d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => {
+                        prep.finish(B::conv3d(x.primitive, weight.primitive, None, options))
+                    }
+                }
+            }
+        }
+    }
+
+    fn conv_transpose3d(
+        x: ADTensor<B, 5>,
+        weight: ADTensor<B, 5>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct ConvTranspose3DWithBias;
+        #[derive(Debug)]
+        struct ConvTranspose3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for ConvTranspose3DWithBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<1>,
+                ConvTransposeOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv_transpose3d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for ConvTranspose3DNoBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                ConvTransposeOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv_transpose3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => {
+                match ConvTranspose3DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv_transpose3d(
+                            x.primitive,
+                            weight.primitive,
+                            Some(bias.primitive),
+                            options,
+                        ),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose3d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match ConvTranspose3DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv_transpose3d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose3d(
+                        x.primitive,
+                        weight.primitive,
+                        None,
+                        options,
+                    )),
+                }
+            }
+        }
+    }
+
+    fn conv1d(
+        x: ADTensor<B, 3>,
+        weight: ADTensor<B, 3>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvOptions<1>,
+    ) -> ADTensor<B, 3> {
+        #[derive(Debug)]
+        struct Conv1DWithBias;
+        #[derive(Debug)]
+        struct Conv1DNoBias;
+
+        impl<B: Backend> Backward<B, 3, 3> for Conv1DWithBias {
+            type State = (
+                B::TensorPrimitive<3>,
+                B::TensorPrimitive<3>,
+                B::TensorPrimitive<1>,
+                ConvOptions<1>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 3>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv1d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 3>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 3>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 3, 2> for Conv1DNoBias {
+            type State = (B::TensorPrimitive<3>, B::TensorPrimitive<3>, ConvOptions<1>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 3>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv1d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 3>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 3>(node, backward.weights_grad)
+                }
+            }
+        }
+        match bias {
+            Some(bias) => {
+                match Conv1DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv1d(x.primitive, weight.primitive, Some(bias.primitive), options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv1d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match Conv1DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv1d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => {
+                        prep.finish(B::conv1d(x.primitive, weight.primitive, None, options))
+                    }
+                }
+            }
+        }
     }
 
-    fn conv1d(
+    fn conv_transpose1d(
         x: ADTensor<B, 3>,
         weight: ADTensor<B, 3>,
         bias: Option<ADTensor<B, 1>>,
-        options: ConvOptions<1>,
+        options: ConvTransposeOptions<1>,
     ) -> ADTensor<B, 3> {
         #[derive(Debug)]
-        struct Conv1DWithBias;
+        struct ConvTranspose1DWithBias;
         #[derive(Debug)]
-        struct Conv1DNoBias;
+        struct ConvTranspose1DNoBias;
 
-        impl<B: Backend> Backward<B, 3, 3> for Conv1DWithBias {
+        impl<B: Backend> Backward<B, 3, 3> for ConvTranspose1DWithBias {
             type State = (
                 B::TensorPrimitive<3>,
                 B::TensorPrimitive<3>,
                 B::TensorPrimitive<1>,
-                ConvOptions<1>,
+                ConvTransposeOptions<1>,
             );
 
             fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
@@ -183,7 +613,7 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let grad = grads.consume::<B, 3>(&ops.node);
 
                 let (x, weight, bias, options) = ops.state;
-                let backward = B::conv1d_backward(x, weight, Some(bias), grad, options);
+                let backward = B::conv_transpose1d_backward(x, weight, Some(bias), grad, options);
 
                 if let Some(node) = node_x {
                     grads.register::<B, 3>(node, backward.x_grad)
@@ -197,15 +627,19 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             }
         }
 
-        impl<B: Backend> Backward<B, 3, 2> for Conv1DNoBias {
-            type State = (B::TensorPrimitive<3>, B::TensorPrimitive<3>, ConvOptions<1>);
+        impl<B: Backend> Backward<B, 3, 2> for ConvTranspose1DNoBias {
+            type State = (
+                B::TensorPrimitive<3>,
+                B::TensorPrimitive<3>,
+                ConvTransposeOptions<1>,
+            );
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
                 let [node_x, node_weight] = ops.parents;
                 let grad = grads.consume::<B, 3>(&ops.node);
 
                 let (x, weight, options) = ops.state;
-                let backward = B::conv1d_backward(x, weight, None, grad, options);
+                let backward = B::conv_transpose1d_backward(x, weight, None, grad, options);
 
                 if let Some(node) = node_x {
                     grads.register::<B, 3>(node, backward.x_grad)
@@ -215,9 +649,10 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 }
             }
         }
+
         match bias {
             Some(bias) => {
-                match Conv1DWithBias
+                match ConvTranspose1DWithBias
                     .prepare(
                         [x.node, weight.node, bias.node],
                         [x.graph, weight.graph, bias.graph],
@@ -231,9 +666,14 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                             bias.primitive.clone(),
                             options.clone(),
                         ),
-                        B::conv1d(x.primitive, weight.primitive, Some(bias.primitive), options),
+                        B::conv_transpose1d(
+                            x.primitive,
+                            weight.primitive,
+                            Some(bias.primitive),
+                            options,
+                        ),
                     ),
-                    OpsKind::UnTracked(prep) => prep.finish(B::conv1d(
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose1d(
                         x.primitive,
                         weight.primitive,
                         Some(bias.primitive),
@@ -242,7 +682,7 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 }
             }
             None => {
-                match Conv1DNoBias
+                match ConvTranspose1DNoBias
                     .prepare([x.node, weight.node], [x.graph, weight.graph])
                     .statefull()
                 {
@@ -252,23 +692,136 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                             weight.primitive.clone(),
                             options.clone(),
                         ),
-                        B::conv1d(x.primitive, weight.primitive, None, options),
+                        B::conv_transpose1d(x.primitive, weight.primitive, None, options),
                     ),
-                    OpsKind::UnTracked(prep) => {
-                        prep.finish(B::conv1d(x.primitive, weight.primitive, None, options))
-                    }
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose1d(
+                        x.primitive,
+                        weight.primitive,
+                        None,
+                        options,
+                    )),
                 }
             }
         }
     }
 
-    fn conv_transpose1d(
-        _x: ADTensor<B, 3>,
-        _weight: ADTensor<B, 3>,
-        _bias: Option<ADTensor<B, 1>>,
-        _options: ConvTransposeOptions<1>,
+    fn avg_pool1d(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> ADTensor<B, 3> {
+        #[derive(Debug)]
+        struct AvgPool1D;
+
+        impl<B: Backend> Backward<B, 3, 1> for AvgPool1D {
+            type State = (B::TensorPrimitive<3>, usize, usize, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 3>(&ops.node);
+                let (x, kernel_size, stride, padding) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool1d_backward(x, grad, kernel_size, stride, padding);
+                    grads.register::<B, 3>(node, grad);
+                }
+            }
+        }
+
+        match AvgPool1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::avg_pool1d(x.primitive.clone(), kernel_size, stride, padding);
+                prep.finish((x.primitive, kernel_size, stride, padding), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::avg_pool1d(x.primitive, kernel_size, stride, padding))
+            }
+        }
+    }
+    fn avg_pool1d_backward(
+        x: ADTensor<B, 3>,
+        grad: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> ADTensor<B, 3> {
+        let tensor =
+            B::avg_pool1d_backward(x.primitive, grad.primitive, kernel_size, stride, padding);
+        ADTensor::new(tensor)
+    }
+
+    fn max_pool1d(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
     ) -> ADTensor<B, 3> {
-        todo!("Transposed 1D convolution doesn't yet support backward.");
+        match MaxPool1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output =
+                    B::max_pool1d_with_indexes(x.primitive.clone(), kernel_size, stride, padding);
+                prep.finish(
+                    (x.primitive, output.indexes, kernel_size, stride, padding),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::max_pool1d(x.primitive, kernel_size, stride, padding))
+            }
+        }
+    }
+
+    fn max_pool1d_with_indexes(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> MaxPool1dWithIndexes<ADBackendDecorator<B>> {
+        match MaxPool1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output =
+                    B::max_pool1d_with_indexes(x.primitive.clone(), kernel_size, stride, padding);
+
+                let output_tensor = prep.finish(
+                    (
+                        x.primitive,
+                        output.indexes.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                    ),
+                    output.output,
+                );
+
+                MaxPool1dWithIndexes::new(output_tensor, output.indexes)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::max_pool1d_with_indexes(x.primitive, kernel_size, stride, padding);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool1dWithIndexes::new(output_tensor, output.indexes)
+            }
+        }
+    }
+
+    fn max_pool1d_with_indexes_backward(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        output_grad: ADTensor<B, 3>,
+        indexes: IntTensor<B, 3>,
+    ) -> MaxPool1dBackward<ADBackendDecorator<B>> {
+        let output = B::max_pool1d_with_indexes_backward(
+            x.primitive,
+            kernel_size,
+            stride,
+            padding,
+            output_grad.primitive,
+            indexes,
+        );
+        MaxPool1dBackward::new(ADTensor::new(output.x_grad))
     }
 
     fn avg_pool2d(
@@ -389,6 +942,213 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         );
         MaxPool2dBackward::new(ADTensor::new(output.x_grad))
     }
+
+    fn avg_pool3d(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AvgPool3D {
+            type State = (B::TensorPrimitive<5>, [usize; 3], [usize; 3], [usize; 3]);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+                let (x, kernel_size, stride, padding) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool3d_backward(x, grad, kernel_size, stride, padding);
+                    grads.register::<B, 5>(node, grad);
+                }
+            }
+        }
+
+        match AvgPool3D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::avg_pool3d(x.primitive.clone(), kernel_size, stride, padding);
+                prep.finish((x.primitive, kernel_size, stride, padding), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::avg_pool3d(x.primitive, kernel_size, stride, padding))
+            }
+        }
+    }
+    fn avg_pool3d_backward(
+        x: ADTensor<B, 5>,
+        grad: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> ADTensor<B, 5> {
+        let tensor =
+            B::avg_pool3d_backward(x.primitive, grad.primitive, kernel_size, stride, padding);
+        ADTensor::new(tensor)
+    }
+
+    fn max_pool3d(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> ADTensor<B, 5> {
+        match MaxPool3D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indexes(x.primitive.clone(), kernel_size, stride, padding);
+                prep.finish(
+                    (x.primitive, output.indexes, kernel_size, stride, padding),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::max_pool3d(x.primitive, kernel_size, stride, padding))
+            }
+        }
+    }
+
+    fn max_pool3d_with_indexes(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<ADBackendDecorator<B>> {
+        match MaxPool3D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indexes(x.primitive.clone(), kernel_size, stride, padding);
+
+                let output_tensor = prep.finish(
+                    (
+                        x.primitive,
+                        output.indexes.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndexes::new(output_tensor, output.indexes)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::max_pool3d_with_indexes(x.primitive, kernel_size, stride, padding);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndexes::new(output_tensor, output.indexes)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indexes_backward(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        output_grad: ADTensor<B, 5>,
+        indexes: IntTensor<B, 5>,
+    ) -> MaxPool3dBackward<ADBackendDecorator<B>> {
+        let output = B::max_pool3d_with_indexes_backward(
+            x.primitive,
+            kernel_size,
+            stride,
+            padding,
+            output_grad.primitive,
+            indexes,
+        );
+        MaxPool3dBackward::new(ADTensor::new(output.x_grad))
+    }
+
+    fn adaptive_avg_pool1d(x: ADTensor<B, 3>, output_size: usize) -> ADTensor<B, 3> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool1D;
+
+        impl<B: Backend> Backward<B, 3, 1> for AdaptiveAvgPool1D {
+            type State = B::TensorPrimitive<3>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 3>(&ops.node);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool1d_backward(ops.state, grad);
+                    grads.register::<B, 3>(node, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::adaptive_avg_pool1d(x.primitive.clone(), output_size);
+                prep.finish(x.primitive, output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool1d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool1d_backward(x: ADTensor<B, 3>, grad: ADTensor<B, 3>) -> ADTensor<B, 3> {
+        let tensor = B::adaptive_avg_pool1d_backward(x.primitive, grad.primitive);
+        ADTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool2d(x: ADTensor<B, 4>, output_size: [usize; 2]) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool2D;
+
+        impl<B: Backend> Backward<B, 4, 1> for AdaptiveAvgPool2D {
+            type State = B::TensorPrimitive<4>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool2d_backward(ops.state, grad);
+                    grads.register::<B, 4>(node, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool2D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::adaptive_avg_pool2d(x.primitive.clone(), output_size);
+                prep.finish(x.primitive, output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool2d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool2d_backward(x: ADTensor<B, 4>, grad: ADTensor<B, 4>) -> ADTensor<B, 4> {
+        let tensor = B::adaptive_avg_pool2d_backward(x.primitive, grad.primitive);
+        ADTensor::new(tensor)
+    }
+}
+
+#[derive(Debug)]
+struct MaxPool1D;
+
+impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
+    type State = (B::TensorPrimitive<3>, IntTensor<B, 3>, usize, usize, usize);
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 3>(&ops.node);
+        let (x, indexes, kernel_size, stride, padding) = ops.state;
+
+        if let Some(node) = node_parent {
+            let grad =
+                B::max_pool1d_with_indexes_backward(x, kernel_size, stride, padding, grad, indexes);
+
+            grads.register::<B, 3>(node, grad.x_grad);
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -416,3 +1176,29 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 5, 1> for MaxPool3D {
+    type State = (
+        B::TensorPrimitive<5>,
+        IntTensor<B, 5>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+    );
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 5>(&ops.node);
+        let (x, indexes, kernel_size, stride, padding) = ops.state;
+
+        if let Some(node) = node_parent {
+            let grad =
+                B::max_pool3d_with_indexes_backward(x, kernel_size, stride, padding, grad, indexes);
+
+            grads.register::<B, 5>(node, grad.x_grad);
+        }
+    }
+}
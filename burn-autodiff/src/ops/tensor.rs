@@ -11,7 +11,7 @@ use crate::{
 
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape, Tensor};
 
-use super::maxmin::MaxMinDim;
+use super::{einsum::EinsumSubscripts, maxmin::MaxMinDim};
 
 impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn from_data<const D: usize>(
@@ -1370,48 +1370,238 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         tensor: ADTensor<B, D>,
         dim: usize,
     ) -> Vec<ADTensor<B, D2>> {
-        unimplemented!()
+        let size = B::shape(&tensor.primitive).dims[dim];
+
+        (0..size)
+            .map(|index| Self::select(tensor.clone(), dim as i64, index as i64))
+            .collect()
     }
+
     fn cumsum<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct CumSum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad = B::cumsum(B::flip(grad, vec![dim]), dim);
+                    B::flip(grad, vec![dim])
+                });
+            }
+        }
+
+        match CumSum.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumsum(tensor.primitive, dim)),
+        }
     }
+
     fn stack<const D: usize, const D2: usize>(
         tensors: Vec<ADTensor<B, D>>,
         dim: usize,
     ) -> ADTensor<B, D2> {
-        unimplemented!()
+        #[derive(new, Debug)]
+        struct StackStep<B: Backend, const D: usize, const D2: usize> {
+            nodes: Vec<Option<NodeRef>>,
+            output: NodeRef,
+            phantom: PhantomData<B>,
+            dim: usize,
+        }
+
+        impl<B: Backend, const D: usize, const D2: usize> Step for StackStep<B, D, D2> {
+            fn step(self: Box<Self>, grads: &mut Gradients) {
+                let grad = grads.consume::<B, D2>(&self.output);
+
+                self.nodes
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, node)| node.map(|node| (i, node)))
+                    .for_each(|(i, node)| {
+                        let grad = B::select::<D2, D>(grad.clone(), self.dim as i64, i as i64);
+                        grads.register::<B, D>(node, grad);
+                    });
+            }
+
+            fn node(&self) -> NodeRef {
+                self.output.clone()
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(tensors.len());
+        let mut graphs = Vec::with_capacity(tensors.len());
+        let mut primitives = Vec::with_capacity(tensors.len());
+
+        tensors.into_iter().for_each(|tensor| {
+            nodes.push(tensor.node);
+            primitives.push(tensor.primitive);
+            graphs.push(tensor.graph);
+        });
+
+        let requirement = Requirement::from_nodes(&nodes);
+
+        let output = B::stack(primitives, dim);
+        if requirement.is_none() {
+            return ADTensor::from_parents(output, &nodes, graphs.into_iter(), requirement);
+        }
+
+        let output = ADTensor::from_parents(output, &nodes, graphs.into_iter(), requirement);
+        let nodes = nodes
+            .into_iter()
+            .map(|node| node.clone_if_require_grad())
+            .collect::<Vec<_>>();
+
+        let ops = StackStep::<B, D, D2>::new(nodes, output.node.clone(), dim);
+        output.register_step(ops)
     }
+
     fn narrow<const D: usize>(
         tensor: ADTensor<B, D>,
         dim: usize,
         start: usize,
         length: usize,
     ) -> ADTensor<B, D> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct Narrow;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Narrow {
+            type State = (usize, usize, Shape<D>, B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, start, shape, device) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let length = B::shape(&grad).dims[dim];
+                    let mut indexes = shape.dims.map(|size| 0..size);
+                    indexes[dim] = start..start + length;
+
+                    B::index_assign(B::zeros(shape, &device), indexes, grad)
+                });
+            }
+        }
+
+        match Narrow.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    dim,
+                    start,
+                    B::shape(&tensor.primitive),
+                    B::device(&tensor.primitive),
+                ),
+                B::narrow(tensor.primitive, dim, start, length),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::narrow(tensor.primitive, dim, start, length))
+            }
+        }
     }
+
     fn upsample_linear1d<const D: usize, const D2: usize>(
-        tensor: ADTensor<B, D>,
-        output_size: &[usize],
-        align_corners: bool,
-        scales: impl Into<Option<f64>>,
+        _tensor: ADTensor<B, D>,
+        _output_size: &[usize],
+        _align_corners: bool,
+        _scales: impl Into<Option<f64>>,
     ) -> ADTensor<B, D2> {
         unimplemented!()
     }
+
     fn pad<const D: usize>(
         tensor: ADTensor<B, D>,
         pad: &[usize],
         mode: &str,
         value: impl Into<Option<f64>>,
     ) -> ADTensor<B, D> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct Pad;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Pad {
+            type State = (Vec<usize>, String, Shape<D>, B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (pad, mode, shape, device) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |mut grad| {
+                    for (i, padding) in pad.chunks(2).enumerate() {
+                        let dim = D - 1 - i;
+                        let size = shape.dims[dim];
+
+                        grad = match mode.as_str() {
+                            "constant" => B::narrow(grad, dim, padding[0], size),
+                            _ => {
+                                // Each padded position is a copy of an input position, so its
+                                // gradient is accumulated into the copied position.
+                                let indexes = pad_indexes(&mode, size, padding[0], padding[1])
+                                    .into_iter()
+                                    .map(|index| (index as i64).elem())
+                                    .collect::<Vec<_>>();
+                                let num_indexes = indexes.len();
+                                let indexes = B::int_from_data(
+                                    Data::new(indexes, Shape::new([num_indexes])),
+                                    &device,
+                                );
+
+                                let mut shape_grad = B::shape(&grad);
+                                shape_grad.dims[dim] = size;
+                                let zeros = B::zeros(shape_grad, &device);
+
+                                B::index_select_dim_assign(zeros, dim, indexes, grad)
+                            }
+                        };
+                    }
+
+                    grad
+                });
+            }
+        }
+
+        let value = value.into();
+
+        match Pad.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    pad.to_vec(),
+                    mode.to_string(),
+                    B::shape(&tensor.primitive),
+                    B::device(&tensor.primitive),
+                ),
+                B::pad(tensor.primitive, pad, mode, value),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::pad(tensor.primitive, pad, mode, value)),
+        }
     }
+
     fn expand<const D: usize>(
         tensor: ADTensor<B, D>,
         size: Vec<usize>,
         implicit: bool,
     ) -> ADTensor<B, D> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct Expand;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Expand {
+            type State = Shape<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let shape = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    broadcast_shape::<B, D>(grad, shape)
+                });
+            }
+        }
+
+        match Expand.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                B::shape(&tensor.primitive),
+                B::expand(tensor.primitive, size, implicit),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::expand(tensor.primitive, size, implicit)),
+        }
     }
+
     fn upsample_bilinear2d<const D: usize, const D2: usize>(
         tensor: ADTensor<B, D>,
         output_size: Vec<usize>,
@@ -1419,54 +1609,344 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         scales_h: impl Into<Option<f64>>,
         scales_w: impl Into<Option<f64>>,
     ) -> ADTensor<B, D2> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct UpsampleBilinear2d<const D: usize>;
+
+        impl<B: Backend, const D: usize, const D2: usize> Backward<B, D2, 1> for UpsampleBilinear2d<D> {
+            type State = (Shape<D>, bool, [Option<f64>; 2], B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (shape, align_corners, [scales_h, scales_w], device) = ops.state;
+
+                unary::<B, D2, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape_grad = B::shape(&grad);
+                    // The interpolation is linear along each spatial dimension, so the
+                    // gradient is the output gradient multiplied by the transposed
+                    // interpolation matrices.
+                    let weights_h = interpolation_matrix::<B, D2>(
+                        shape.dims[D - 2],
+                        shape_grad.dims[D2 - 2],
+                        align_corners,
+                        scales_h,
+                        &device,
+                    );
+                    let weights_w = interpolation_matrix::<B, D2>(
+                        shape.dims[D - 1],
+                        shape_grad.dims[D2 - 1],
+                        align_corners,
+                        scales_w,
+                        &device,
+                    );
+
+                    let grad = B::matmul(B::transpose(weights_h), grad);
+                    let grad = B::matmul(grad, weights_w);
+
+                    B::reshape(grad, shape)
+                });
+            }
+        }
+
+        let scales = [scales_h.into(), scales_w.into()];
+
+        match UpsampleBilinear2d::<D>
+            .prepare([tensor.node], [tensor.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    B::shape(&tensor.primitive),
+                    align_corners,
+                    scales,
+                    B::device(&tensor.primitive),
+                ),
+                B::upsample_bilinear2d(
+                    tensor.primitive,
+                    output_size,
+                    align_corners,
+                    scales[0],
+                    scales[1],
+                ),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::upsample_bilinear2d(
+                tensor.primitive,
+                output_size,
+                align_corners,
+                scales[0],
+                scales[1],
+            )),
+        }
     }
+
     fn select<const D: usize, const D2: usize>(
         tensor: ADTensor<B, D>,
         dim: i64,
         index: i64,
     ) -> ADTensor<B, D2> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct Select<const D: usize>;
+
+        impl<B: Backend, const D: usize, const D2: usize> Backward<B, D2, 1> for Select<D> {
+            type State = (usize, usize, Shape<D>, B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, index, shape, device) = ops.state;
+
+                unary::<B, D2, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let mut shape_grad = shape.clone();
+                    shape_grad.dims[dim] = 1;
+                    let grad = B::reshape(grad, shape_grad);
+
+                    let mut indexes = shape.dims.map(|size| 0..size);
+                    indexes[dim] = index..index + 1;
+
+                    B::index_assign(B::zeros(shape, &device), indexes, grad)
+                });
+            }
+        }
+
+        match Select::<D>
+            .prepare([tensor.node], [tensor.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let dim_positive = match dim < 0 {
+                    true => (dim + D as i64) as usize,
+                    false => dim as usize,
+                };
+                let index_positive = match index < 0 {
+                    true => (index + shape.dims[dim_positive] as i64) as usize,
+                    false => index as usize,
+                };
+
+                prep.finish(
+                    (
+                        dim_positive,
+                        index_positive,
+                        shape,
+                        B::device(&tensor.primitive),
+                    ),
+                    B::select(tensor.primitive, dim, index),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::select(tensor.primitive, dim, index)),
+        }
     }
+
     fn flip<const D: usize>(tensor: ADTensor<B, D>, dims: Vec<usize>) -> ADTensor<B, D> {
-        unimplemented!()
+        #[derive(Debug)]
+        struct Flip;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Flip {
+            type State = Vec<usize>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dims = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| B::flip(grad, dims));
+            }
+        }
+
+        match Flip.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(dims.clone(), B::flip(tensor.primitive, dims)),
+            OpsKind::UnTracked(prep) => prep.finish(B::flip(tensor.primitive, dims)),
+        }
     }
-    fn permute<const D: usize>(
-        tensor: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
-        dims: [usize; D],
-    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
-        unimplemented!()
+
+    fn permute<const D: usize>(tensor: ADTensor<B, D>, dims: [usize; D]) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Permute;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Permute {
+            type State = [usize; D];
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dims = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let mut dims_inverse = [0; D];
+                    dims.iter()
+                        .enumerate()
+                        .for_each(|(i, dim)| dims_inverse[*dim] = i);
+
+                    B::permute(grad, dims_inverse)
+                });
+            }
+        }
+
+        match Permute.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(dims, B::permute(tensor.primitive, dims)),
+            OpsKind::UnTracked(prep) => prep.finish(B::permute(tensor.primitive, dims)),
+        }
     }
+
     fn einsum<const D: usize, const D2: usize, const D3: usize>(
         equation: &str,
-        tensor1: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
-        tensor2: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D2>,
-    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D3> {
-        unimplemented!()
+        lhs: ADTensor<B, D>,
+        rhs: ADTensor<B, D2>,
+    ) -> ADTensor<B, D3> {
+        #[derive(Debug)]
+        struct Einsum<const D: usize, const D2: usize>;
+
+        impl<B: Backend, const D: usize, const D2: usize, const D3: usize> Backward<B, D3, 2>
+            for Einsum<D, D2>
+        {
+            type State = (
+                EinsumSubscripts,
+                Shape<D>,
+                Shape<D2>,
+                Option<B::TensorPrimitive<D>>,
+                Option<B::TensorPrimitive<D2>>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (subscripts, shape_lhs, shape_rhs, lhs, rhs) = ops.state;
+
+                binary::<B, D3, D, D2, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let grad = subscripts.lhs_grad::<B, D, D2, D3>(
+                            grad,
+                            rhs.unwrap(),
+                            shape_lhs.clone(),
+                        );
+                        broadcast_shape::<B, D>(grad, shape_lhs)
+                    },
+                    |grad| {
+                        let grad = subscripts.rhs_grad::<B, D, D2, D3>(
+                            grad,
+                            lhs.unwrap(),
+                            shape_rhs.clone(),
+                        );
+                        broadcast_shape::<B, D2>(grad, shape_rhs)
+                    },
+                );
+            }
+        }
+
+        let lhs_tracked = lhs.is_tracked();
+        let rhs_tracked = rhs.is_tracked();
+
+        match Einsum::<D, D2>
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::einsum(equation, lhs.primitive.clone(), rhs.primitive.clone());
+                let subscripts = EinsumSubscripts::parse(equation, [D, D2]);
+
+                prep.finish(
+                    (
+                        subscripts,
+                        B::shape(&lhs.primitive),
+                        B::shape(&rhs.primitive),
+                        rhs_tracked.then_some(lhs.primitive),
+                        lhs_tracked.then_some(rhs.primitive),
+                    ),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::einsum(equation, lhs.primitive, rhs.primitive))
+            }
+        }
     }
+
     fn index_tch<const D: usize, const D2: usize>(
-        tensor: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
-        indices: Vec<<ADBackendDecorator<B> as Backend>::IntTensorPrimitive<D>>,
-    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D2> {
+        _tensor: ADTensor<B, D>,
+        _indices: Vec<IntTensor<B, D>>,
+    ) -> ADTensor<B, D2> {
         unimplemented!()
     }
+
     fn repeat_interleave_self_int<const D: usize, const D2: usize>(
-        tensor: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
-        repeats: usize,
-        dim: Option<usize>,
-        output_size: Option<usize>,
-    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D2> {
+        _tensor: ADTensor<B, D>,
+        _repeats: usize,
+        _dim: Option<usize>,
+        _output_size: Option<usize>,
+    ) -> ADTensor<B, D2> {
         unimplemented!()
     }
+
     fn where_self<const D: usize>(
-            tensor: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
-            condition: <ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
-            other: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
-        ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
-        unimplemented!()
+        tensor: ADTensor<B, D>,
+        condition: BoolTensor<B, D>,
+        other: ADTensor<B, D>,
+    ) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct WhereSelf;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for WhereSelf {
+            type State = (B::BoolTensorPrimitive<D>, Shape<D>, Shape<D>, B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (condition, shape_lhs, shape_rhs, device) = ops.state;
+                let [condition_4lhs, condition_4rhs] = duplicate(&ops.parents, Some(condition));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let zeros = B::zeros(B::shape(&grad), &device);
+                        let grad = B::where_self(grad, condition_4lhs.unwrap(), zeros);
+                        broadcast_shape::<B, D>(grad, shape_lhs)
+                    },
+                    |grad| {
+                        let zeros = B::zeros(B::shape(&grad), &device);
+                        let grad = B::where_self(zeros, condition_4rhs.unwrap(), grad);
+                        broadcast_shape::<B, D>(grad, shape_rhs)
+                    },
+                );
+            }
+        }
+
+        match WhereSelf
+            .prepare([tensor.node, other.node], [tensor.graph, other.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    condition.clone(),
+                    B::shape(&tensor.primitive),
+                    B::shape(&other.primitive),
+                    B::device(&tensor.primitive),
+                ),
+                B::where_self(tensor.primitive, condition, other.primitive),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::where_self(tensor.primitive, condition, other.primitive))
+            }
+        }
     }
-    fn copy_<const D:usize>(tensor: &mut <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>, src: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>) {
-        unimplemented!()
+
+    fn copy_<const D: usize>(tensor: &mut ADTensor<B, D>, src: ADTensor<B, D>) {
+        #[derive(Debug)]
+        struct CopyFrom;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CopyFrom {
+            type State = Shape<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let shape = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    broadcast_shape::<B, D>(grad, shape)
+                });
+            }
+        }
+
+        // The values of the tensor are overwritten, so the result only depends on the source.
+        let shape = B::shape(&src.primitive);
+        let mut primitive = tensor.primitive.clone();
+        B::copy_(&mut primitive, src.primitive);
+
+        *tensor = match CopyFrom.prepare([src.node], [src.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(shape, primitive),
+            OpsKind::UnTracked(prep) => prep.finish(primitive),
+        };
     }
 }
 
@@ -1494,3 +1974,61 @@ fn broadcast_shape<B: Backend, const D: usize>(
 
     grad
 }
+
+/// Source position of each position of a dimension padded with the given mode.
+fn pad_indexes(mode: &str, size: usize, left: usize, right: usize) -> Vec<usize> {
+    (0..left + size + right)
+        .map(|i| {
+            let i = i as i64 - left as i64;
+            let size = size as i64;
+            let index = match mode {
+                "reflect" if i < 0 => -i,
+                "reflect" if i >= size => 2 * (size - 1) - i,
+                "replicate" => i.clamp(0, size - 1),
+                "circular" => i.rem_euclid(size),
+                _ => i,
+            };
+            index as usize
+        })
+        .collect()
+}
+
+/// Matrix of shape `[1, .., 1, size_out, size_in]` computing the linear interpolation of a
+/// dimension, following torch's `upsample_bilinear2d` coordinates.
+fn interpolation_matrix<B: Backend, const D: usize>(
+    size_in: usize,
+    size_out: usize,
+    align_corners: bool,
+    scale: Option<f64>,
+    device: &B::Device,
+) -> B::TensorPrimitive<D> {
+    let ratio = match align_corners {
+        true if size_out > 1 => (size_in - 1) as f64 / (size_out - 1) as f64,
+        true => 0.0,
+        false => match scale {
+            Some(scale) if scale > 0.0 => 1.0 / scale,
+            _ => size_in as f64 / size_out as f64,
+        },
+    };
+
+    let mut weights = vec![0.0; size_out * size_in];
+    for i in 0..size_out {
+        let source = match align_corners {
+            true => ratio * i as f64,
+            false => f64::max(ratio * (i as f64 + 0.5) - 0.5, 0.0),
+        };
+        let index = usize::min(source.floor() as usize, size_in - 1);
+        let index_next = usize::min(index + 1, size_in - 1);
+        let lambda = source - index as f64;
+
+        weights[i * size_in + index] += 1.0 - lambda;
+        weights[i * size_in + index_next] += lambda;
+    }
+
+    let mut dims = [1; D];
+    dims[D - 2] = size_out;
+    dims[D - 1] = size_in;
+    let weights = weights.into_iter().map(|weight| weight.elem()).collect();
+
+    B::from_data(Data::new(weights, Shape::new(dims)), device)
+}
@@ -0,0 +1,258 @@
+#[burn_tensor_testgen::testgen(ad_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv3d, ops::ConvOptions, Shape};
+
+    #[test]
+    fn test_conv3d_basic() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                    ],
+                    [
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                    ],
+                ],
+                [
+                    [
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                    ],
+                    [
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[954., 954.], [954., 954.]], [[954., 954.], [954., 954.]]],
+                    [
+                        [[1602., 1602.], [1602., 1602.]],
+                        [[1602., 1602.], [1602., 1602.]],
+                    ],
+                ],
+                [
+                    [[[954., 954.], [954., 954.]], [[954., 954.], [954., 954.]]],
+                    [
+                        [[1602., 1602.], [1602., 1602.]],
+                        [[1602., 1602.], [1602., 1602.]],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([96., 96.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 2,
+            groups: 2,
+            depth: 3,
+            height: 4,
+            width: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [
+                        [0., 50., 0., 26.],
+                        [0., 108., 0., 56.],
+                        [0., 124., 0., 64.],
+                        [0., 66., 0., 34.],
+                    ],
+                    [
+                        [0., 26., 0., 14.],
+                        [0., 60., 0., 32.],
+                        [0., 76., 0., 40.],
+                        [0., 42., 0., 22.],
+                    ],
+                    [
+                        [0., 50., 0., 26.],
+                        [0., 108., 0., 56.],
+                        [0., 124., 0., 64.],
+                        [0., 66., 0., 34.],
+                    ],
+                ],
+                [
+                    [
+                        [0., 146., 0., 74.],
+                        [0., 300., 0., 152.],
+                        [0., 316., 0., 160.],
+                        [0., 162., 0., 82.],
+                    ],
+                    [
+                        [0., 122., 0., 62.],
+                        [0., 252., 0., 128.],
+                        [0., 268., 0., 136.],
+                        [0., 138., 0., 70.],
+                    ],
+                    [
+                        [0., 146., 0., 74.],
+                        [0., 300., 0., 152.],
+                        [0., 316., 0., 160.],
+                        [0., 162., 0., 82.],
+                    ],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[
+                    [[38., 80.], [46., 96.], [54., 112.]],
+                    [[76., 160.], [92., 192.], [108., 224.]],
+                ]],
+                [[
+                    [[38., 80.], [46., 96.], [54., 112.]],
+                    [[76., 160.], [92., 192.], [108., 224.]],
+                ]],
+                [[
+                    [[134., 272.], [142., 288.], [150., 304.]],
+                    [[268., 544.], [284., 576.], [300., 608.]],
+                ]],
+                [[
+                    [[134., 272.], [142., 288.], [150., 304.]],
+                    [[268., 544.], [284., 576.], [300., 608.]],
+                ]],
+            ]),
+            bias: TestTensor::from_floats([8., 8., 8., 8.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose1d, ops::ConvTransposeOptions, Shape};
+
+    #[test]
+    fn test_conv_transpose1d_basic() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 0,
+            padding_out: 0,
+            stride: 1,
+            dilation: 1,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[44., 44., 44.], [44., 44., 44.]],
+                [[76., 76., 76.], [76., 76., 76.]],
+            ]),
+            bias: TestTensor::from_floats([12., 12.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_padding() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 2,
+            padding_out: 0,
+            stride: 1,
+            dilation: 1,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[7., 12., 8., 3.], [19., 36., 32., 15.]],
+                [[7., 12., 8., 3.], [19., 36., 32., 15.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[26., 22., 18.], [26., 22., 18.]],
+                [[42., 38., 34.], [42., 38., 34.]],
+            ]),
+            bias: TestTensor::from_floats([4., 4.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_stride() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 0,
+            padding_out: 1,
+            stride: 2,
+            dilation: 1,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[44., 44., 44.], [44., 44., 44.]],
+                [[76., 76., 76.], [76., 76., 76.]],
+            ]),
+            bias: TestTensor::from_floats([20., 20.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_dilation() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 0,
+            padding_out: 0,
+            stride: 1,
+            dilation: 2,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[44., 44., 44.], [44., 44., 44.]],
+                [[76., 76., 76.], [76., 76., 76.]],
+            ]),
+            bias: TestTensor::from_floats([16., 16.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_groups() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 0,
+            padding_out: 0,
+            stride: 1,
+            dilation: 1,
+            groups: 2,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[3., 3., 3., 3.], [12., 12., 12., 12.]],
+                [[3., 3., 3., 3.], [12., 12., 12., 12.]],
+            ]),
+            weight: TestTensor::from_floats([[[44., 44., 44.]], [[76., 76., 76.]]]),
+            bias: TestTensor::from_floats([12., 12.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct ConvTranspose1dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: usize,
+        padding: usize,
+        padding_out: usize,
+        stride: usize,
+        dilation: usize,
+        groups: usize,
+        length: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<3>,
+        weight: TestTensor<3>,
+        bias: TestTensor<1>,
+    }
+
+    impl ConvTranspose1dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([self.batch_size, self.channels_in, self.length]);
+            let shape_weight = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+
+            let output = conv_transpose1d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvTransposeOptions::new(
+                    [self.stride],
+                    [self.padding],
+                    [self.padding_out],
+                    [self.dilation],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+        }
+    }
+}
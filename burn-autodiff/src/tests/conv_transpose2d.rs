@@ -0,0 +1,361 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose2d, ops::ConvTransposeOptions, Shape};
+
+    #[test]
+    fn test_conv_transpose2d_basic() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 4,
+            width: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [84., 120., 120., 76.],
+                        [108., 153., 153., 96.],
+                        [108., 153., 153., 96.],
+                        [60., 84., 84., 52.],
+                    ],
+                    [
+                        [228., 336., 336., 220.],
+                        [324., 477., 477., 312.],
+                        [324., 477., 477., 312.],
+                        [204., 300., 300., 196.],
+                    ],
+                ],
+                [
+                    [
+                        [84., 120., 120., 76.],
+                        [108., 153., 153., 96.],
+                        [108., 153., 153., 96.],
+                        [60., 84., 84., 52.],
+                    ],
+                    [
+                        [228., 336., 336., 220.],
+                        [324., 477., 477., 312.],
+                        [324., 477., 477., 312.],
+                        [204., 300., 300., 196.],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[468., 612., 450.], [576., 752., 552.], [396., 516., 378.]],
+                    [[468., 612., 450.], [576., 752., 552.], [396., 516., 378.]],
+                ],
+                [
+                    [[756., 996., 738.], [960., 1264., 936.], [684., 900., 666.]],
+                    [[756., 996., 738.], [960., 1264., 936.], [684., 900., 666.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([32., 32.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_different_channels() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [[180., 261., 168.], [243., 351., 225.], [144., 207., 132.]],
+                [[504., 747., 492.], [729., 1080., 711.], [468., 693., 456.]],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                    [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                    [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                ],
+                [
+                    [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+                    [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+                    [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([9., 9., 9.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_stride_2_padding_out() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 1,
+            padding_out_2: 1,
+            stride_1: 2,
+            stride_2: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [[84., 120., 120.], [108., 153., 153.], [108., 153., 153.]],
+                [[228., 336., 336.], [324., 477., 477.], [324., 477., 477.]],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [[24., 33., 33.], [27., 36., 36.], [27., 36., 36.]],
+                    [[24., 33., 33.], [27., 36., 36.], [27., 36., 36.]],
+                ],
+                [
+                    [[60., 87., 87.], [81., 117., 117.], [81., 117., 117.]],
+                    [[60., 87., 87.], [81., 117., 117.], [81., 117., 117.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([36., 36.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_dilation_2() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 2,
+            dilation_2: 2,
+            groups: 1,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [[84., 120., 76.], [108., 153., 96.], [60., 84., 52.]],
+                [[228., 336., 220.], [324., 477., 312.], [204., 300., 196.]],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                    [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                ],
+                [
+                    [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+                    [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([25., 25.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_groups_2() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]]],
+                [[[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]]],
+            ]),
+            bias: TestTensor::from_floats([9., 9.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_complex() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 4,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            padding_1: 0,
+            padding_2: 1,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            stride_1: 2,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 2,
+            groups: 2,
+            height: 2,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [[12., 15., 8.], [12., 15., 8.]],
+                    [[36., 51., 32.], [36., 51., 32.]],
+                    [[60., 87., 56.], [60., 87., 56.]],
+                    [[84., 123., 80.], [84., 123., 80.]],
+                ],
+                [
+                    [[12., 15., 8.], [12., 15., 8.]],
+                    [[36., 51., 32.], [36., 51., 32.]],
+                    [[60., 87., 56.], [60., 87., 56.]],
+                    [[84., 123., 80.], [84., 123., 80.]],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [[[120., 174., 112.], [120., 174., 112.]]],
+                [[[168., 246., 160.], [168., 246., 160.]]],
+                [[[216., 318., 208.], [216., 318., 208.]]],
+                [[[264., 390., 256.], [264., 390., 256.]]],
+            ]),
+            bias: TestTensor::from_floats([50., 50.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct ConvTranspose2dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        stride_1: usize,
+        stride_2: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        groups: usize,
+        height: usize,
+        width: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<4>,
+        weight: TestTensor<4>,
+        bias: TestTensor<1>,
+    }
+
+    impl ConvTranspose2dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([self.batch_size, self.channels_in, self.height, self.width]);
+            let shape_weight = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv_transpose2d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2],
+                    [self.padding_1, self.padding_2],
+                    [self.padding_out_1, self.padding_out_2],
+                    [self.dilation_1, self.dilation_2],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_copy)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_copy() {
+        let data_1 = Data::<f32, 2>::from([[0.0, 0.0], [0.0, 0.0]]);
+        let data_2 = Data::<f32, 2>::from([[5.0, 6.0]]);
+        let weights = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let mut tensor_3 = tensor_1.clone().mul_scalar(2.0);
+        tensor_3.copy_(tensor_2.clone());
+        let tensor_4 = tensor_3.mul(weights);
+        let grads = tensor_4.backward();
+
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(tensor_4.to_data(), Data::from([[5.0, 12.0], [15.0, 24.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[4.0, 6.0]]));
+        assert!(tensor_1.grad(&grads).is_none());
+    }
+}
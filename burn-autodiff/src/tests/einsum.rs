@@ -0,0 +1,106 @@
+#[burn_tensor_testgen::testgen(ad_einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_einsum_matmul() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[5.0, 6.0], [7.0, 8.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3: TestADTensor<2, _> =
+            TestADTensor::einsum("ij,jk->ik", tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[11.0, 15.0], [11.0, 15.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[4.0, 4.0], [6.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_diff_einsum_implicit_outer_product() {
+        let data_1 = Data::<f32, 1>::from([1.0, 2.0]);
+        let data_2 = Data::<f32, 1>::from([3.0, 4.0, 5.0]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3: TestADTensor<2, _> =
+            TestADTensor::einsum("i,j", tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([12.0, 12.0]));
+        assert_eq!(grad_2.to_data(), Data::from([3.0, 3.0, 3.0]));
+    }
+
+    #[test]
+    fn should_diff_einsum_broadcasted_batch() {
+        let data_1 = Data::<f32, 3>::from([[[1.0, 2.0]]]);
+        let data_2 = Data::<f32, 3>::from([[[1.0], [2.0]], [[3.0], [4.0]]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3: TestADTensor<3, _> =
+            TestADTensor::einsum("bij,bjk->bik", tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[[4.0, 6.0]]]));
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[[1.0], [2.0]], [[1.0], [2.0]]])
+        );
+    }
+
+    #[test]
+    fn should_diff_einsum_label_summed_in_one_operand() {
+        let data_1 = Data::<f32, 3>::from([[[1.0, 2.0], [3.0, 4.0]]]);
+        let data_2 = Data::<f32, 3>::from([[[5.0, 6.0], [7.0, 8.0], [9.0, 10.0]]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3: TestADTensor<2, _> =
+            TestADTensor::einsum("bij,bkj->bk", tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[[21.0, 24.0], [21.0, 24.0]]]));
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[[4.0, 6.0], [4.0, 6.0], [4.0, 6.0]]])
+        );
+    }
+
+    #[test]
+    fn should_diff_einsum_repeated_subscripts() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 1>::from([5.0, 6.0, 7.0]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3: TestADTensor<1, _> =
+            TestADTensor::einsum("ii,j->j", tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[18.0, 0.0], [0.0, 18.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([5.0, 5.0, 5.0]));
+    }
+}
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_expand() {
+        let data = Data::<f32, 2>::from([[1.0], [2.0]]);
+        let weights = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2 = tensor_1.expand(vec![2, 3], false).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[6.0], [15.0]]));
+    }
+
+    #[test]
+    fn should_diff_expand_keeping_dims() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+
+        let tensor_2 = tensor_1.expand(vec![3, usize::MAX], false);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[3.0, 3.0]]));
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(ad_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::max_pool1d, Data};
+
+    #[test]
+    fn test_max_pool1d_simple() {
+        let kernel_size = 4;
+        let padding = 0;
+        let stride = 1;
+
+        let x = TestADTensor::from_floats([[[0.2380, 0.5442, 0.3700, 0.6039, 0.6257, 0.0655]]])
+            .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[0., 0., 0., 1., 2., 0.]]]);
+
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_complex() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestADTensor::from_floats([[
+            [0.0132, 0.8375, 0.2594, 0.2343, 0.9956, 0.4703, 0.8365],
+            [0.4764, 0.6391, 0.1506, 0.6349, 0.8680, 0.5232, 0.7413],
+        ]])
+        .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[
+            [0., 2., 0., 0., 1., 0., 1.],
+            [0., 2., 0., 0., 1., 0., 1.],
+        ]]);
+
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
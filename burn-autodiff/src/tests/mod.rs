@@ -1,17 +1,30 @@
+mod adaptive_avgpool1d;
+mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod broadcast;
 mod cat;
 mod complex;
 mod conv1d;
 mod conv2d;
+mod conv3d;
+mod conv_transpose1d;
+mod conv_transpose2d;
+mod conv_transpose3d;
+mod copy;
 mod cos;
 mod cross_entropy;
+mod cumsum;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
+mod expand;
+mod flip;
 mod gelu;
 mod index;
 mod index_select;
@@ -21,19 +34,29 @@ mod log1p;
 mod mask;
 mod matmul;
 mod maxmin;
+mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod mul;
 mod multithread;
+mod narrow;
 mod neg;
+mod pad;
+mod permute;
 mod pow;
 mod relu;
 mod reshape;
+mod select;
 mod sin;
 mod softmax;
 mod sqrt;
+mod stack;
 mod sub;
 mod tanh;
 mod transpose;
+mod unbind;
+mod upsample_bilinear2d;
+mod where_self;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -51,8 +74,18 @@ macro_rules! testgen_all {
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_conv_transpose1d!();
+        burn_autodiff::testgen_ad_conv_transpose2d!();
         burn_autodiff::testgen_ad_max_pool2d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_conv3d!();
+        burn_autodiff::testgen_ad_conv_transpose3d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
+        burn_autodiff::testgen_ad_max_pool1d!();
+        burn_autodiff::testgen_ad_avg_pool1d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
@@ -84,5 +117,18 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_transpose!();
+        burn_autodiff::testgen_ad_copy!();
+        burn_autodiff::testgen_ad_cumsum!();
+        burn_autodiff::testgen_ad_einsum!();
+        burn_autodiff::testgen_ad_expand!();
+        burn_autodiff::testgen_ad_flip!();
+        burn_autodiff::testgen_ad_narrow!();
+        burn_autodiff::testgen_ad_pad!();
+        burn_autodiff::testgen_ad_permute!();
+        burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_stack!();
+        burn_autodiff::testgen_ad_unbind!();
+        burn_autodiff::testgen_ad_upsample_bilinear2d!();
+        burn_autodiff::testgen_ad_where_self!();
     };
 }
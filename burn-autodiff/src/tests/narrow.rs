@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_narrow)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_narrow() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let weights = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2 = tensor_1.narrow(1, 1, 2).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[0.0, 1.0, 2.0], [0.0, 3.0, 4.0]])
+        );
+    }
+}
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_pad_constant() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let weights = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2 = tensor_1.pad(&[1, 0, 0, 1], "constant", 5.0).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 3.0], [5.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let grad = pad_grad(&[2, 1], "reflect");
+
+        assert_eq!(grad, Data::from([[3.0, 12.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_replicate() {
+        let grad = pad_grad(&[1, 2], "replicate");
+
+        assert_eq!(grad, Data::from([[3.0, 3.0, 15.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_circular() {
+        let grad = pad_grad(&[1, 1], "circular");
+
+        assert_eq!(grad, Data::from([[7.0, 3.0, 5.0]]));
+    }
+
+    /// Gradient of a `[1, 3]` tensor padded along its last dimension and multiplied by the
+    /// weights `1, 2, 3, ...`.
+    fn pad_grad(pad: &[usize], mode: &str) -> Data<f32, 2> {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0]]);
+        let size = 3 + pad[0] + pad[1];
+        let weights = Data::new(
+            (1..=size).map(|i| i as f32).collect(),
+            burn_tensor::Shape::new([1, size]),
+        );
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2 = tensor_1.pad(pad, mode, None).mul(weights);
+        let grads = tensor_2.backward();
+
+        tensor_1.grad(&grads).unwrap().to_data()
+    }
+}
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(ad_select)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_select() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let weights = Data::<f32, 1>::from([2.0, 3.0]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2: TestADTensor<1, _> = tensor_1.select(1, -1);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[0.0, 0.0, 2.0], [0.0, 0.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_select_dim0() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let weights = Data::<f32, 1>::from([1.0, 2.0, 3.0]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2: TestADTensor<1, _> = tensor_1.select(0, 1);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]])
+        );
+    }
+}
@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(ad_stack)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_stack() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0])).require_grad();
+        let tensor_2 = TestADTensor::from_data(Data::from([3.0, 4.0])).require_grad();
+        let weights = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        let tensor_3: TestADTensor<2, _> =
+            TestADTensor::stack(vec![tensor_1.clone(), tensor_2.clone()], 1);
+        let tensor_4 = tensor_3.mul(weights);
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([1.0, 3.0]));
+        assert_eq!(grad_2.to_data(), Data::from([2.0, 4.0]));
+    }
+
+    #[test]
+    fn should_diff_stack_with_untracked_tensor() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0])).require_grad();
+        let tensor_2 = TestADTensor::from_data(Data::from([3.0, 4.0]));
+        let weights = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        let tensor_3: TestADTensor<2, _> =
+            TestADTensor::stack(vec![tensor_2.clone(), tensor_1.clone()], 0);
+        let tensor_4 = tensor_3.mul(weights);
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([3.0, 4.0]));
+        assert!(tensor_2.grad(&grads).is_none());
+    }
+}
@@ -0,0 +1,23 @@
+#[burn_tensor_testgen::testgen(ad_unbind)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_unbind() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+
+        let tensors: Vec<TestADTensor<1, _>> = tensor_1.unbind(0);
+        let tensor_2 = tensors[0]
+            .clone()
+            .mul(tensors[0].clone())
+            .add(tensors[1].clone().mul_scalar(3.0));
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 4.0], [3.0, 3.0]]));
+    }
+}
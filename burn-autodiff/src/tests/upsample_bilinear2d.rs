@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(ad_upsample_bilinear2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_upsample_bilinear2d_align_corners() {
+        let data = Data::<f32, 4>::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let weights = Data::<f32, 4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let weights = TestADTensor::from_data(weights);
+
+        let tensor_2: TestADTensor<4, _> =
+            tensor_1.upsample_bilinear2d(vec![3, 3], true, None, None);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[5.25, 8.25], [14.25, 17.25]]]]), 3);
+    }
+
+    #[test]
+    fn should_diff_upsample_bilinear2d() {
+        let data = Data::<f32, 4>::from([[[[1.0, 2.0], [3.0, 4.0]]], [[[5.0, 6.0], [7.0, 8.0]]]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+
+        let tensor_2: TestADTensor<4, _> =
+            tensor_1.upsample_bilinear2d(vec![4, 4], false, None, None);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[[[4.0, 4.0], [4.0, 4.0]]], [[[4.0, 4.0], [4.0, 4.0]]]]),
+            3,
+        );
+    }
+}
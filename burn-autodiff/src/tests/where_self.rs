@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(ad_where_self)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_where_self() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[5.0, 6.0], [7.0, 8.0]]);
+        let weights = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let condition = Data::<bool, 2>::from([[true, false], [false, true]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+        let weights = TestADTensor::from_data(weights);
+        let condition = TestADTensor::from_bool(condition);
+
+        let tensor_3 = tensor_1
+            .clone()
+            .where_self(condition, tensor_2.clone())
+            .mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 0.0], [0.0, 4.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[0.0, 2.0], [3.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_diff_where_self_broadcasted() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[5.0, 6.0]]);
+        let weights = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let condition = Data::<bool, 2>::from([[true, false], [false, true]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+        let weights = TestADTensor::from_data(weights);
+        let condition = TestADTensor::from_bool(condition);
+
+        let tensor_3 = tensor_1
+            .clone()
+            .where_self(condition, tensor_2.clone())
+            .mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 0.0], [0.0, 4.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[3.0, 2.0]]));
+    }
+}
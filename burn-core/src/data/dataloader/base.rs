@@ -13,4 +13,11 @@ pub trait DataLoaderIterator<O>: Iterator<Item = O> {
 
 pub trait DataLoader<O> {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a>;
+
+    /// Set the epoch of the next [iteration](DataLoader::iter), starting from zero, which
+    /// selects the items sampled at each epoch and their order when they are shuffled.
+    ///
+    /// The epoch is otherwise incremented at each iteration, so setting it allows a resumed
+    /// training to iterate over the same items in the same order.
+    fn set_epoch(&self, _epoch: usize) {}
 }
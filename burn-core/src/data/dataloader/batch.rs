@@ -1,13 +1,15 @@
+use super::sampled::{EpochDataset, SampledDatasets};
 use super::{
     batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, MultiThreadDataLoader,
-    Progress,
+    OrderedMultiThreadDataLoader, Progress,
 };
-use burn_dataset::{transform::PartialDataset, Dataset};
+use burn_dataset::transform::{PartialDataset, Sampler};
+use burn_dataset::Dataset;
 use std::sync::Arc;
 
 pub struct BatchDataLoader<I, O> {
     strategy: Box<dyn BatchStrategy<I>>,
-    dataset: Arc<dyn Dataset<I>>,
+    dataset: EpochDataset<I>,
     batcher: Arc<dyn Batcher<I, O>>,
 }
 
@@ -26,7 +28,7 @@ impl<I, O> BatchDataLoader<I, O> {
     ) -> Self {
         Self {
             strategy,
-            dataset,
+            dataset: EpochDataset::Fixed(dataset),
             batcher,
         }
     }
@@ -52,15 +54,112 @@ where
         }
         MultiThreadDataLoader::new(dataloaders)
     }
+
+    /// Create a multi-threaded dataloader yielding the same batches in the same order as a
+    /// single-threaded one, whatever the number of threads.
+    ///
+    /// # Notes
+    ///
+    /// The items are read and grouped by a single thread, while the batcher runs on
+    /// `num_threads` threads.
+    pub fn multi_thread_ordered(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        num_threads: usize,
+    ) -> OrderedMultiThreadDataLoader<I, O> {
+        OrderedMultiThreadDataLoader::new(
+            strategy,
+            EpochDataset::Fixed(dataset),
+            batcher,
+            num_threads,
+        )
+    }
+
+    /// Create a dataloader iterating over the items selected by the [sampler](Sampler),
+    /// which is called again at each epoch.
+    pub fn with_sampler(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        sampler: Arc<dyn Sampler>,
+        batcher: Arc<dyn Batcher<I, O>>,
+    ) -> Self {
+        let datasets = Arc::new(SampledDatasets::new(dataset, sampler, 1));
+        Self::sampled(strategy, datasets, 0, batcher)
+    }
+
+    /// Create a multi-threaded dataloader iterating over the items selected by the
+    /// [sampler](Sampler) at each epoch, which are split between the threads.
+    pub fn multi_thread_with_sampler(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        sampler: Arc<dyn Sampler>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        num_threads: usize,
+    ) -> MultiThreadDataLoader<O> {
+        let datasets = Arc::new(SampledDatasets::new(dataset, sampler, num_threads));
+        let dataloaders = (0..num_threads)
+            .map(|split| {
+                let dataloader = Self::sampled(
+                    strategy.new_like(),
+                    datasets.clone(),
+                    split,
+                    batcher.clone(),
+                );
+                Arc::new(dataloader) as Arc<dyn DataLoader<_> + Send + Sync>
+            })
+            .collect();
+        MultiThreadDataLoader::new(dataloaders)
+    }
+
+    /// Create a multi-threaded dataloader iterating over the items selected by the
+    /// [sampler](Sampler) at each epoch, in the same order as a
+    /// [single-threaded](Self::with_sampler) one.
+    ///
+    /// See [multi_thread_ordered](Self::multi_thread_ordered).
+    pub fn multi_thread_ordered_with_sampler(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        sampler: Arc<dyn Sampler>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        num_threads: usize,
+    ) -> OrderedMultiThreadDataLoader<I, O> {
+        let datasets = Arc::new(SampledDatasets::new(dataset, sampler, 1));
+
+        OrderedMultiThreadDataLoader::new(
+            strategy,
+            EpochDataset::sampled(datasets, 0),
+            batcher,
+            num_threads,
+        )
+    }
+
+    fn sampled(
+        strategy: Box<dyn BatchStrategy<I>>,
+        datasets: Arc<SampledDatasets<I>>,
+        split: usize,
+        batcher: Arc<dyn Batcher<I, O>>,
+    ) -> Self {
+        Self {
+            strategy,
+            dataset: EpochDataset::sampled(datasets, split),
+            batcher,
+        }
+    }
 }
 
 impl<I, O> DataLoader<O> for BatchDataLoader<I, O> {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
-        Box::new(BatchDataloaderIterator::new(
+        let iterator = BatchDataloaderIterator::new(
             self.strategy.new_like(),
-            self.dataset.clone(),
+            self.dataset.next(),
             self.batcher.clone(),
-        ))
+        );
+        Box::new(iterator)
+    }
+
+    fn set_epoch(&self, epoch: usize) {
+        self.dataset.set_epoch(epoch);
     }
 }
 
@@ -118,10 +217,12 @@ impl<I, O> DataLoaderIterator<O> for BatchDataloaderIterator<I, O> {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
     use crate::data::dataloader::batcher::TestBatcher;
     use crate::data::dataloader::FixBatchStrategy;
+    use crate::data::dataset::transform::RandomSampler;
     use crate::data::dataset::FakeDataset;
 
     #[test]
@@ -176,4 +277,140 @@ mod tests {
 
         assert_eq!(items_single_thread, items_multi_thread);
     }
+
+    #[test]
+    fn test_multi_thread_ordered_batch_dataloader_is_independent_of_num_threads() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader_single_thread = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            batcher.clone(),
+        );
+        let dataloader = |num_threads| {
+            BatchDataLoader::multi_thread_ordered(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                batcher.clone(),
+                num_threads,
+            )
+        };
+
+        let batches_single_thread: Vec<_> = dataloader_single_thread.iter().collect();
+        let batches_two_threads: Vec<_> = dataloader(2).iter().collect();
+        let batches_four_threads: Vec<_> = dataloader(4).iter().collect();
+
+        assert_eq!(batches_single_thread.len(), 6);
+        assert_eq!(batches_two_threads, batches_single_thread);
+        assert_eq!(batches_four_threads, batches_single_thread);
+    }
+
+    #[test]
+    fn test_multi_thread_ordered_batch_dataloader_progress() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = BatchDataLoader::multi_thread_ordered(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset,
+            batcher,
+            3,
+        );
+
+        let mut iterator = dataloader.iter();
+        let mut items_processed = Vec::new();
+        while iterator.next().is_some() {
+            let progress = iterator.progress();
+            assert_eq!(progress.items_total, 27);
+            items_processed.push(progress.items_processed);
+        }
+
+        assert_eq!(items_processed.len(), 6);
+        assert_eq!(items_processed[..5], [5, 10, 15, 20, 25]);
+    }
+
+    #[test]
+    fn test_multi_thread_ordered_batch_dataloader_reads_each_item_once() {
+        struct CountingDataset {
+            dataset: FakeDataset<String>,
+            reads: AtomicUsize,
+        }
+
+        impl Dataset<String> for CountingDataset {
+            fn get(&self, index: usize) -> Option<String> {
+                self.reads.fetch_add(1, Ordering::Relaxed);
+                self.dataset.get(index)
+            }
+
+            fn len(&self) -> usize {
+                self.dataset.len()
+            }
+        }
+
+        let dataset = Arc::new(CountingDataset {
+            dataset: FakeDataset::new(27),
+            reads: AtomicUsize::new(0),
+        });
+        let dataloader = BatchDataLoader::multi_thread_ordered(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            Arc::new(TestBatcher::new()),
+            4,
+        );
+
+        assert_eq!(dataloader.iter().count(), 6);
+        // The 27 items, plus the read past the end.
+        assert_eq!(dataset.reads.load(Ordering::Relaxed), 28);
+    }
+
+    #[test]
+    fn test_multi_thread_sampler_dataloader_splits_items_of_each_epoch() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = BatchDataLoader::multi_thread_with_sampler(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            Arc::new(RandomSampler::new(42)),
+            batcher,
+            4,
+        );
+        let items_dataset: HashSet<_> = dataset.iter().collect();
+
+        let epochs: Vec<Vec<String>> = (0..2)
+            .map(|_| dataloader.iter().flatten().collect())
+            .collect();
+
+        for items in epochs.iter() {
+            assert_eq!(items.len(), 27);
+            assert_eq!(items.iter().cloned().collect::<HashSet<_>>(), items_dataset);
+        }
+    }
+
+    #[test]
+    fn test_multi_thread_ordered_sampler_dataloader_is_independent_of_num_threads() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = |num_threads| {
+            BatchDataLoader::multi_thread_ordered_with_sampler(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Arc::new(RandomSampler::new(42)),
+                batcher.clone(),
+                num_threads,
+            )
+        };
+        let dataloader_single_thread = BatchDataLoader::with_sampler(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            Arc::new(RandomSampler::new(42)),
+            batcher.clone(),
+        );
+        let (dataloader_two_threads, dataloader_three_threads) = (dataloader(2), dataloader(3));
+
+        for _ in 0..2 {
+            let batches: Vec<_> = dataloader_single_thread.iter().collect();
+
+            assert_eq!(dataloader_two_threads.iter().collect::<Vec<_>>(), batches);
+            assert_eq!(dataloader_three_threads.iter().collect::<Vec<_>>(), batches);
+        }
+    }
 }
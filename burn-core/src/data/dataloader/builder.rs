@@ -1,5 +1,9 @@
-use super::{batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
-use burn_dataset::{transform::ShuffledDataset, Dataset};
+use super::{
+    batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, DropLastBatchStrategy,
+    FixBatchStrategy,
+};
+use burn_dataset::transform::{RandomSampler, Sampler};
+use burn_dataset::Dataset;
 use std::sync::Arc;
 
 pub struct DataLoaderBuilder<I, O> {
@@ -7,6 +11,9 @@ pub struct DataLoaderBuilder<I, O> {
     batcher: Arc<dyn Batcher<I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    sampler: Option<Arc<dyn Sampler>>,
+    ordered: bool,
+    drop_last: bool,
 }
 
 impl<I, O> DataLoaderBuilder<I, O>
@@ -23,6 +30,9 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            sampler: None,
+            ordered: false,
+            drop_last: false,
         }
     }
 
@@ -31,37 +41,247 @@ where
         self
     }
 
+    /// Use the given [strategy](BatchStrategy) to group the items into batches, instead of
+    /// batches of a [fixed size](Self::batch_size).
+    pub fn batch_strategy<S>(mut self, strategy: S) -> Self
+    where
+        S: BatchStrategy<I> + 'static,
+    {
+        self.strategy = Some(Box::new(strategy));
+        self
+    }
+
+    /// Drop the incomplete batches left at the end of the dataset, whatever the strategy.
+    pub fn drop_last(mut self) -> Self {
+        self.drop_last = true;
+        self
+    }
+
+    /// Shuffle the items differently at each epoch.
+    ///
+    /// The order of each epoch is derived from the seed and the epoch number, so it is the
+    /// same from one run to another.
     pub fn shuffle(mut self, seed: u64) -> Self {
         self.shuffle = Some(seed);
         self
     }
 
+    /// Iterate over the items selected by the given [sampler](Sampler) at each epoch.
+    ///
+    /// The sampler selects the items from the dataset in its original order, so its weights
+    /// and labels are those of the dataset items. The [shuffle](Self::shuffle) only changes
+    /// the order of the sampled items, which are then split between the workers.
+    pub fn sampler<S>(mut self, sampler: S) -> Self
+    where
+        S: Sampler + 'static,
+    {
+        self.sampler = Some(Arc::new(sampler));
+        self
+    }
+
     pub fn num_workers(mut self, num_workers: usize) -> Self {
         self.num_threads = Some(num_workers);
         self
     }
 
+    /// Yield the batches in the same order as with a single worker, independently of the
+    /// thread scheduling and of the number of workers.
+    ///
+    /// # Notes
+    ///
+    /// A single thread reads the items and groups them into batches, which are then batched by
+    /// the workers in parallel.
+    pub fn ordered(mut self) -> Self {
+        self.ordered = true;
+        self
+    }
+
     pub fn build<D>(self, dataset: D) -> Arc<dyn DataLoader<O>>
     where
         D: Dataset<I> + 'static,
     {
-        let dataset: Arc<dyn Dataset<I>> = match self.shuffle {
-            Some(seed) => Arc::new(ShuffledDataset::with_seed(dataset, seed)),
-            None => Arc::new(dataset),
+        let dataset: Arc<dyn Dataset<I>> = Arc::new(dataset);
+        let sampler: Option<Arc<dyn Sampler>> = match (self.shuffle, self.sampler) {
+            (Some(seed), Some(sampler)) => Some(Arc::new(ShuffledSampler { seed, sampler })),
+            (Some(seed), None) => Some(Arc::new(RandomSampler::new(seed))),
+            (None, sampler) => sampler,
         };
         let strategy = match self.strategy {
             Some(strategy) => strategy,
             None => Box::new(FixBatchStrategy::new(1)),
         };
-        if let Some(num_threads) = self.num_threads {
-            return Arc::new(BatchDataLoader::multi_thread(
+        let strategy: Box<dyn BatchStrategy<I>> = match self.drop_last {
+            true => Box::new(DropLastBatchStrategy::new(strategy)),
+            false => strategy,
+        };
+        let batcher = self.batcher;
+
+        match (sampler, self.num_threads, self.ordered) {
+            (Some(sampler), Some(num_threads), true) => {
+                Arc::new(BatchDataLoader::multi_thread_ordered_with_sampler(
+                    strategy,
+                    dataset,
+                    sampler,
+                    batcher,
+                    num_threads,
+                ))
+            }
+            (Some(sampler), Some(num_threads), false) => {
+                Arc::new(BatchDataLoader::multi_thread_with_sampler(
+                    strategy,
+                    dataset,
+                    sampler,
+                    batcher,
+                    num_threads,
+                ))
+            }
+            (Some(sampler), None, _) => Arc::new(BatchDataLoader::with_sampler(
+                strategy, dataset, sampler, batcher,
+            )),
+            (None, Some(num_threads), true) => Arc::new(BatchDataLoader::multi_thread_ordered(
+                strategy,
+                dataset,
+                batcher,
+                num_threads,
+            )),
+            (None, Some(num_threads), false) => Arc::new(BatchDataLoader::multi_thread(
                 strategy,
                 dataset,
-                self.batcher,
+                batcher,
                 num_threads,
-            ));
+            )),
+            (None, None, _) => Arc::new(BatchDataLoader::new(strategy, dataset, batcher)),
         }
+    }
+}
+
+/// Shuffle the items selected by a sampler differently at each epoch.
+struct ShuffledSampler {
+    seed: u64,
+    sampler: Arc<dyn Sampler>,
+}
+
+impl Sampler for ShuffledSampler {
+    fn indexes(&self, len: usize, epoch: usize) -> Vec<usize> {
+        let indexes = self.sampler.indexes(len, epoch);
 
-        Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher))
+        RandomSampler::new(self.seed)
+            .indexes(indexes.len(), epoch)
+            .into_iter()
+            .map(|position| indexes[position])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataset::transform::{
+        DistributedSampler, SequentialSampler, WeightedRandomSampler,
+    };
+    use crate::data::dataset::{FakeDataset, InMemDataset};
+    use std::collections::HashSet;
+
+    fn epochs(dataloader: &dyn DataLoader<Vec<String>>, num_epochs: usize) -> Vec<Vec<String>> {
+        (0..num_epochs)
+            .map(|_| dataloader.iter().flatten().collect())
+            .collect()
+    }
+
+    #[test]
+    fn shuffle_should_reshuffle_deterministically_at_each_epoch() {
+        let dataset = FakeDataset::<String>::new(27);
+        let items: Vec<String> = dataset.iter().collect();
+        let dataloader = |dataset| {
+            DataLoaderBuilder::new(TestBatcher)
+                .batch_size(5)
+                .shuffle(42)
+                .build(dataset)
+        };
+
+        let epochs_1 = epochs(dataloader(InMemDataset::new(items.clone())).as_ref(), 3);
+        let epochs_2 = epochs(dataloader(InMemDataset::new(items)).as_ref(), 3);
+
+        assert_eq!(epochs_1, epochs_2);
+        assert_ne!(epochs_1[0], epochs_1[1]);
+        assert_ne!(epochs_1[1], epochs_1[2]);
+    }
+
+    #[test]
+    fn shuffle_with_workers_should_split_all_items_at_each_epoch() {
+        let dataset = FakeDataset::<String>::new(27);
+        let items: HashSet<String> = dataset.iter().collect();
+        let dataloader = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(5)
+            .shuffle(42)
+            .num_workers(4)
+            .build(dataset);
+
+        for epoch in epochs(dataloader.as_ref(), 3) {
+            assert_eq!(epoch.len(), 27);
+            assert_eq!(epoch.into_iter().collect::<HashSet<_>>(), items);
+        }
+    }
+
+    #[test]
+    fn shuffle_with_sampler_should_keep_the_sampled_items() {
+        let dataset = FakeDataset::<String>::new(27);
+        let items: Vec<String> = dataset.iter().collect();
+        let weights = (0..27).map(|i| if i < 3 { 1.0 } else { 0.0 }).collect();
+        let dataloader = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(5)
+            .shuffle(42)
+            .sampler(WeightedRandomSampler::new(weights, 20, true, 42))
+            .build(dataset);
+
+        for epoch in epochs(dataloader.as_ref(), 3) {
+            assert_eq!(epoch.len(), 20);
+            assert!(epoch.iter().all(|item| items[..3].contains(item)));
+        }
+    }
+
+    #[test]
+    fn shuffle_with_sampler_should_only_reorder_the_sampled_items() {
+        let dataset = FakeDataset::<String>::new(27);
+        let items: HashSet<String> = dataset.iter().take(9).collect();
+        let dataloader = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(5)
+            .shuffle(42)
+            .sampler(DistributedSampler::new(SequentialSampler, 0, 3))
+            .build(dataset);
+
+        let epochs = epochs(dataloader.as_ref(), 2);
+
+        assert_ne!(epochs[0], epochs[1]);
+        for epoch in epochs {
+            assert_eq!(epoch.len(), 9);
+            assert_eq!(epoch.into_iter().collect::<HashSet<_>>(), items);
+        }
+    }
+
+    #[test]
+    fn set_epoch_should_replay_the_items_of_the_epoch() {
+        let dataset = FakeDataset::<String>::new(27);
+        let items: Vec<String> = dataset.iter().collect();
+        let dataloaders = [
+            DataLoaderBuilder::new(TestBatcher)
+                .batch_size(5)
+                .shuffle(42)
+                .build(InMemDataset::new(items.clone())),
+            DataLoaderBuilder::new(TestBatcher)
+                .batch_size(5)
+                .shuffle(42)
+                .num_workers(3)
+                .ordered()
+                .build(InMemDataset::new(items)),
+        ];
+
+        for dataloader in dataloaders {
+            let expected = epochs(dataloader.as_ref(), 3);
+
+            dataloader.set_epoch(1);
+            assert_eq!(epochs(dataloader.as_ref(), 2), expected[1..]);
+        }
     }
 }
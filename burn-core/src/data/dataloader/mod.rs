@@ -2,6 +2,8 @@ mod base;
 mod batch;
 mod builder;
 mod multithread;
+mod ordered;
+mod sampled;
 mod strategy;
 
 pub mod batcher;
@@ -10,4 +12,5 @@ pub use base::*;
 pub use batch::*;
 pub use builder::*;
 pub use multithread::*;
+pub use ordered::*;
 pub use strategy::*;
@@ -59,6 +59,12 @@ where
 
         Box::new(MultiThreadsDataloaderIterator::new(receiver, handlers))
     }
+
+    fn set_epoch(&self, epoch: usize) {
+        for dataloader in self.dataloaders.iter() {
+            dataloader.set_epoch(epoch);
+        }
+    }
 }
 
 impl<O> MultiThreadsDataloaderIterator<O> {
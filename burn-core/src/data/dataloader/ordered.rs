@@ -0,0 +1,192 @@
+use super::sampled::EpochDataset;
+use super::{batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, Progress};
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+static MAX_QUEUED_BATCHES: usize = 100;
+
+/// Multi-threaded dataloader yielding the batches in the same order as a single-threaded one,
+/// independently of the thread scheduling and of the number of threads.
+///
+/// A producer thread reads the items and groups them into batches with the
+/// [strategy](BatchStrategy), then sends each group to the workers, which run the
+/// [batcher](Batcher) in parallel. The batches are put back in order with a reorder buffer
+/// keyed by the batch index, and at most 100 batches are processed ahead of the iterator.
+pub struct OrderedMultiThreadDataLoader<I, O> {
+    strategy: Box<dyn BatchStrategy<I>>,
+    dataset: EpochDataset<I>,
+    batcher: Arc<dyn Batcher<I, O>>,
+    num_threads: usize,
+}
+
+/// A group of items to batch, with the progress of the producer once the group was formed.
+struct Job<I> {
+    index: usize,
+    items: Vec<I>,
+    progress: Progress,
+}
+
+enum Message<O> {
+    Batch(usize, O, Progress),
+    Panic(Box<dyn Any + Send>),
+}
+
+struct OrderedMultiThreadDataLoaderIterator<O> {
+    current: usize,
+    buffer: HashMap<usize, (O, Progress)>,
+    receiver: mpsc::Receiver<Message<O>>,
+    slots: mpsc::Receiver<()>,
+    workers: Vec<thread::JoinHandle<()>>,
+    progress: Progress,
+}
+
+impl<I, O> OrderedMultiThreadDataLoader<I, O> {
+    pub(crate) fn new(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: EpochDataset<I>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        num_threads: usize,
+    ) -> Self {
+        Self {
+            strategy,
+            dataset,
+            batcher,
+            num_threads: num_threads.max(1),
+        }
+    }
+}
+
+impl<I, O> DataLoader<O> for OrderedMultiThreadDataLoader<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let dataset = self.dataset.next();
+        let items_total = dataset.len();
+
+        // Each batch in flight takes a slot, which is released once the iterator yields it.
+        let (slots_sender, slots) = mpsc::sync_channel::<()>(MAX_QUEUED_BATCHES);
+        let (jobs_sender, jobs_receiver) = mpsc::channel::<Job<I>>();
+        let (sender, receiver) = mpsc::channel::<Message<O>>();
+        let jobs_receiver = Arc::new(Mutex::new(jobs_receiver));
+
+        let mut workers: Vec<_> = (0..self.num_threads)
+            .map(|_| {
+                let jobs_receiver = jobs_receiver.clone();
+                let batcher = self.batcher.clone();
+                let sender = sender.clone();
+
+                thread::spawn(move || loop {
+                    let job = match jobs_receiver.lock().unwrap().recv() {
+                        Ok(job) => job,
+                        Err(_) => return,
+                    };
+
+                    let items = job.items;
+                    let message =
+                        match panic::catch_unwind(AssertUnwindSafe(|| batcher.batch(items))) {
+                            Ok(batch) => Message::Batch(job.index, batch, job.progress),
+                            Err(error) => Message::Panic(error),
+                        };
+
+                    // The iterator might have been dropped before the end.
+                    if sender.send(message).is_err() {
+                        return;
+                    }
+                })
+            })
+            .collect();
+
+        let mut strategy = self.strategy.new_like();
+        let producer = thread::spawn(move || {
+            let mut index = 0;
+            let progress = |items_processed| Progress {
+                items_processed,
+                items_total,
+            };
+            let mut send = |items, progress| {
+                let job = Job {
+                    index,
+                    items,
+                    progress,
+                };
+                index += 1;
+
+                slots_sender.send(()).is_ok() && jobs_sender.send(job).is_ok()
+            };
+
+            let mut items_processed = 0;
+            while let Some(item) = dataset.get(items_processed) {
+                items_processed += 1;
+                strategy.add(item);
+
+                if let Some(items) = strategy.batch(false) {
+                    if !send(items, progress(items_processed)) {
+                        return;
+                    }
+                }
+            }
+
+            if let Some(items) = strategy.batch(true) {
+                send(items, progress(items_total));
+            }
+        });
+        workers.push(producer);
+
+        Box::new(OrderedMultiThreadDataLoaderIterator {
+            current: 0,
+            buffer: HashMap::new(),
+            receiver,
+            slots,
+            workers,
+            progress: Progress {
+                items_processed: 0,
+                items_total,
+            },
+        })
+    }
+
+    fn set_epoch(&self, epoch: usize) {
+        self.dataset.set_epoch(epoch);
+    }
+}
+
+impl<O> DataLoaderIterator<O> for OrderedMultiThreadDataLoaderIterator<O> {
+    fn progress(&self) -> Progress {
+        self.progress.clone()
+    }
+}
+
+impl<O> Iterator for OrderedMultiThreadDataLoaderIterator<O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        loop {
+            if let Some((batch, progress)) = self.buffer.remove(&self.current) {
+                self.current += 1;
+                self.progress = progress;
+                self.slots.recv().ok();
+
+                return Some(batch);
+            }
+
+            match self.receiver.recv() {
+                Ok(Message::Batch(index, batch, progress)) => {
+                    self.buffer.insert(index, (batch, progress));
+                }
+                Ok(Message::Panic(error)) => panic::resume_unwind(error),
+                Err(_) => break,
+            }
+        }
+
+        while let Some(worker) = self.workers.pop() {
+            worker.join().unwrap();
+        }
+
+        None
+    }
+}
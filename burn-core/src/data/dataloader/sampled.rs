@@ -0,0 +1,96 @@
+use burn_dataset::transform::{IndexSampledDataset, PartialDataset, Sampler};
+use burn_dataset::Dataset;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+type Datasets<I> = Vec<Arc<dyn Dataset<I>>>;
+
+/// The dataset iterated over by a dataloader, which might change at each epoch.
+pub(crate) enum EpochDataset<I> {
+    Fixed(Arc<dyn Dataset<I>>),
+    Sampled {
+        datasets: Arc<SampledDatasets<I>>,
+        split: usize,
+        epoch: AtomicUsize,
+    },
+}
+
+impl<I> EpochDataset<I> {
+    /// The split `split` of the sampled datasets, starting at the first epoch.
+    pub(crate) fn sampled(datasets: Arc<SampledDatasets<I>>, split: usize) -> Self {
+        Self::Sampled {
+            datasets,
+            split,
+            epoch: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set the epoch of the next dataset.
+    pub(crate) fn set_epoch(&self, epoch: usize) {
+        if let Self::Sampled { epoch: current, .. } = self {
+            current.store(epoch, Ordering::Relaxed);
+        }
+    }
+
+    /// The dataset of the next epoch.
+    pub(crate) fn next(&self) -> Arc<dyn Dataset<I>> {
+        match self {
+            Self::Fixed(dataset) => dataset.clone(),
+            Self::Sampled {
+                datasets,
+                split,
+                epoch,
+            } => datasets.get(epoch.fetch_add(1, Ordering::Relaxed), *split),
+        }
+    }
+}
+
+/// Sample a dataset at each epoch, and split the sampled items between workers.
+pub(crate) struct SampledDatasets<I> {
+    sample: Box<dyn Fn(usize) -> Datasets<I> + Send + Sync>,
+    current: Mutex<Option<(usize, Datasets<I>)>>,
+}
+
+impl<I> SampledDatasets<I> {
+    pub(crate) fn new(
+        dataset: Arc<dyn Dataset<I>>,
+        sampler: Arc<dyn Sampler>,
+        num_splits: usize,
+    ) -> Self
+    where
+        I: Send + Sync + Clone + 'static,
+    {
+        let sample = move |epoch| -> Datasets<I> {
+            let dataset = IndexSampledDataset::new(dataset.clone(), sampler.as_ref(), epoch);
+
+            match num_splits {
+                1 => vec![Arc::new(dataset)],
+                num => PartialDataset::split(dataset, num)
+                    .into_iter()
+                    .map(|dataset| Arc::new(dataset) as Arc<dyn Dataset<I>>)
+                    .collect(),
+            }
+        };
+
+        Self {
+            sample: Box::new(sample),
+            current: Mutex::new(None),
+        }
+    }
+
+    /// Get the split of the given epoch, sampling the dataset once per epoch for all splits.
+    fn get(&self, epoch: usize, split: usize) -> Arc<dyn Dataset<I>> {
+        let mut current = self.current.lock().unwrap();
+
+        if let Some((current_epoch, datasets)) = current.as_ref() {
+            if *current_epoch == epoch {
+                return datasets[split].clone();
+            }
+        }
+
+        let datasets = (self.sample)(epoch);
+        let dataset = datasets[split].clone();
+        *current = Some((epoch, datasets));
+        dataset
+    }
+}
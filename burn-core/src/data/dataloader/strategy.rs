@@ -1,3 +1,9 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Function returning the size of an item, such as its number of tokens.
+pub type ItemSizeFn<I> = Arc<dyn Fn(&I) -> usize + Send + Sync>;
+
 pub trait BatchStrategy<I>: Send + Sync {
     fn add(&mut self, item: I);
     fn batch(&mut self, force: bool) -> Option<Vec<I>>;
@@ -42,3 +48,249 @@ impl<I: Send + Sync + 'static> BatchStrategy<I> for FixBatchStrategy<I> {
         Box::new(Self::new(self.batch_size))
     }
 }
+
+/// Strategy batching items of similar sizes together.
+///
+/// Each item is put in the bucket of its size, where it is batched with the inner strategy.
+/// The bucket of an item is the number of boundaries lower than or equal to its size, so `n`
+/// sorted boundaries create `n + 1` buckets.
+pub struct BucketBatchStrategy<I> {
+    boundaries: Vec<usize>,
+    size: ItemSizeFn<I>,
+    buckets: Vec<Box<dyn BatchStrategy<I>>>,
+    ready: VecDeque<Vec<I>>,
+}
+
+impl<I: Send + Sync + 'static> BucketBatchStrategy<I> {
+    /// Create the strategy from the bucket boundaries, the size function and the strategy used
+    /// to batch the items of each bucket.
+    pub fn new<F>(boundaries: Vec<usize>, size: F, strategy: Box<dyn BatchStrategy<I>>) -> Self
+    where
+        F: Fn(&I) -> usize + Send + Sync + 'static,
+    {
+        Self::from_parts(boundaries, Arc::new(size), strategy.as_ref())
+    }
+
+    fn from_parts(
+        mut boundaries: Vec<usize>,
+        size: ItemSizeFn<I>,
+        strategy: &dyn BatchStrategy<I>,
+    ) -> Self {
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let buckets = (0..=boundaries.len())
+            .map(|_| strategy.new_like())
+            .collect();
+
+        Self {
+            boundaries,
+            size,
+            buckets,
+            ready: VecDeque::new(),
+        }
+    }
+}
+
+impl<I: Send + Sync + 'static> BatchStrategy<I> for BucketBatchStrategy<I> {
+    fn add(&mut self, item: I) {
+        let size = (self.size)(&item);
+        let bucket = &mut self.buckets[self.boundaries.partition_point(|b| *b <= size)];
+
+        bucket.add(item);
+        if let Some(items) = bucket.batch(false) {
+            self.ready.push_back(items);
+        }
+    }
+
+    fn batch(&mut self, force: bool) -> Option<Vec<I>> {
+        if let Some(items) = self.ready.pop_front() {
+            return Some(items);
+        }
+
+        if !force {
+            return None;
+        }
+
+        self.buckets
+            .iter_mut()
+            .find_map(|bucket| bucket.batch(true))
+    }
+
+    fn new_like(&self) -> Box<dyn BatchStrategy<I>> {
+        Box::new(Self::from_parts(
+            self.boundaries.clone(),
+            self.size.clone(),
+            self.buckets[0].as_ref(),
+        ))
+    }
+}
+
+/// Strategy batching as many items as possible within a maximum number of tokens.
+///
+/// Since the items of a batch are padded to the largest one, the number of tokens of a batch
+/// is the size of its largest item multiplied by its number of items. An item larger than the
+/// budget is batched alone.
+pub struct TokenBudgetBatchStrategy<I> {
+    max_tokens: usize,
+    size: ItemSizeFn<I>,
+    items: Vec<I>,
+    max_size: usize,
+    ready: VecDeque<Vec<I>>,
+}
+
+impl<I> TokenBudgetBatchStrategy<I> {
+    /// Create the strategy from the maximum number of tokens of a batch and the size function.
+    pub fn new<F>(max_tokens: usize, size: F) -> Self
+    where
+        F: Fn(&I) -> usize + Send + Sync + 'static,
+    {
+        Self::from_parts(max_tokens, Arc::new(size))
+    }
+
+    fn from_parts(max_tokens: usize, size: ItemSizeFn<I>) -> Self {
+        Self {
+            max_tokens,
+            size,
+            items: Vec::new(),
+            max_size: 0,
+            ready: VecDeque::new(),
+        }
+    }
+}
+
+impl<I: Send + Sync + 'static> BatchStrategy<I> for TokenBudgetBatchStrategy<I> {
+    fn add(&mut self, item: I) {
+        let size = (self.size)(&item);
+        let max_size = usize::max(self.max_size, size);
+
+        if !self.items.is_empty() && max_size * (self.items.len() + 1) > self.max_tokens {
+            let items = std::mem::take(&mut self.items);
+            self.ready.push_back(items);
+            self.max_size = size;
+        } else {
+            self.max_size = max_size;
+        }
+
+        self.items.push(item);
+    }
+
+    fn batch(&mut self, force: bool) -> Option<Vec<I>> {
+        if let Some(items) = self.ready.pop_front() {
+            return Some(items);
+        }
+
+        if !force || self.items.is_empty() {
+            return None;
+        }
+
+        self.max_size = 0;
+        Some(std::mem::take(&mut self.items))
+    }
+
+    fn new_like(&self) -> Box<dyn BatchStrategy<I>> {
+        Box::new(Self::from_parts(self.max_tokens, self.size.clone()))
+    }
+}
+
+/// Strategy dropping the incomplete batches of another strategy, which are the ones only
+/// returned when the batching is forced at the end of the dataset.
+pub struct DropLastBatchStrategy<I> {
+    strategy: Box<dyn BatchStrategy<I>>,
+}
+
+impl<I> DropLastBatchStrategy<I> {
+    pub fn new(strategy: Box<dyn BatchStrategy<I>>) -> Self {
+        Self { strategy }
+    }
+}
+
+impl<I: Send + Sync + 'static> BatchStrategy<I> for DropLastBatchStrategy<I> {
+    fn add(&mut self, item: I) {
+        self.strategy.add(item);
+    }
+
+    fn batch(&mut self, force: bool) -> Option<Vec<I>> {
+        if let Some(items) = self.strategy.batch(false) {
+            return Some(items);
+        }
+
+        if force {
+            while self.strategy.batch(true).is_some() {}
+        }
+
+        None
+    }
+
+    fn new_like(&self) -> Box<dyn BatchStrategy<I>> {
+        Box::new(Self::new(self.strategy.new_like()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batches(strategy: &mut dyn BatchStrategy<usize>, items: &[usize]) -> Vec<Vec<usize>> {
+        let mut batches = Vec::new();
+
+        for item in items {
+            strategy.add(*item);
+            if let Some(batch) = strategy.batch(false) {
+                batches.push(batch);
+            }
+        }
+        while let Some(batch) = strategy.batch(true) {
+            batches.push(batch);
+        }
+
+        batches
+    }
+
+    #[test]
+    fn bucket_strategy_should_batch_items_of_similar_sizes() {
+        let mut strategy = BucketBatchStrategy::new(
+            vec![10, 5],
+            |item| *item,
+            Box::new(FixBatchStrategy::new(2)),
+        );
+
+        let batches = batches(&mut strategy, &[1, 12, 6, 2, 7, 3, 15, 4]);
+
+        assert_eq!(
+            batches,
+            vec![vec![1, 2], vec![6, 7], vec![12, 15], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn token_budget_strategy_should_batch_padded_items_within_budget() {
+        let mut strategy = TokenBudgetBatchStrategy::new(12, |item| *item);
+
+        let batches = batches(&mut strategy, &[2, 3, 4, 5, 20, 1, 1]);
+
+        assert_eq!(batches, vec![vec![2, 3, 4], vec![5], vec![20], vec![1, 1]]);
+    }
+
+    #[test]
+    fn token_budget_strategy_should_keep_batches_not_taken_yet() {
+        let mut strategy = TokenBudgetBatchStrategy::new(4, |item| *item);
+
+        for item in [2, 2, 3, 4, 1] {
+            strategy.add(item);
+        }
+        let batches: Vec<_> = std::iter::from_fn(|| strategy.batch(true)).collect();
+
+        assert_eq!(batches, vec![vec![2, 2], vec![3], vec![4], vec![1]]);
+    }
+
+    #[test]
+    fn drop_last_strategy_should_drop_incomplete_batches() {
+        let strategy =
+            BucketBatchStrategy::new(vec![5], |item| *item, Box::new(FixBatchStrategy::new(2)));
+        let mut strategy = DropLastBatchStrategy::new(Box::new(strategy));
+
+        let batches = batches(&mut strategy, &[1, 6, 2, 7, 3, 8, 9]);
+
+        assert_eq!(batches, vec![vec![1, 2], vec![6, 7], vec![8, 9]]);
+    }
+}
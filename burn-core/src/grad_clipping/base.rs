@@ -1,5 +1,6 @@
 use crate as burn;
 
+use super::GlobalNormClipping;
 use crate::{config::Config, tensor::Tensor};
 use burn_tensor::{backend::Backend, ElementConversion};
 
@@ -7,20 +8,44 @@ use burn_tensor::{backend::Backend, ElementConversion};
 pub enum GradientClippingConfig {
     Value(f32),
     Norm(f32),
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
-    pub fn init(&self) -> GradientClipping {
+    pub fn init(&self) -> ClippingStrategy {
         match self {
-            GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
-            GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::Value(val) => GradientClipping::Value(*val).into(),
+            GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val).into(),
+            GradientClippingConfig::GlobalNorm(val) => GlobalNormClipping::new(*val).into(),
         }
     }
 }
 
+/// How an optimizer clips the gradients, either each gradient tensor on its own or all the
+/// gradients of the module together.
+pub enum ClippingStrategy {
+    PerTensor(GradientClipping),
+    GlobalNorm(GlobalNormClipping),
+}
+
+impl From<GradientClipping> for ClippingStrategy {
+    fn from(clipping: GradientClipping) -> Self {
+        Self::PerTensor(clipping)
+    }
+}
+
+impl From<GlobalNormClipping> for ClippingStrategy {
+    fn from(clipping: GlobalNormClipping) -> Self {
+        Self::GlobalNorm(clipping)
+    }
+}
+
 /// Gradient Clipping provides a way to mitigate exploding gradients
 /// by clipping every component of the gradient by value or by norm during
 /// backpropagation.
+///
+/// Each gradient tensor is clipped on its own, see [GlobalNormClipping] to clip all the
+/// gradients by their combined norm.
 pub enum GradientClipping {
     Value(f32),
     Norm(f32),
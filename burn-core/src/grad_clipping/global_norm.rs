@@ -0,0 +1,202 @@
+use core::marker::PhantomData;
+
+use crate::module::{ADModule, ModuleVisitor, ParamId};
+use crate::optim::GradientsParams;
+
+use burn_tensor::{
+    backend::{ADBackend, Backend},
+    ElementConversion, Tensor,
+};
+
+/// Clip all the gradients of a module by their combined L2 norm, see [clip_grad_norm].
+#[derive(new, Clone, Copy, Debug)]
+pub struct GlobalNormClipping {
+    max_norm: f32,
+}
+
+impl GlobalNormClipping {
+    /// Clip the gradients of the given module, returning their norm before clipping.
+    pub fn clip_gradients<B: ADBackend, M: ADModule<B>>(
+        &self,
+        module: &M,
+        grads: &mut GradientsParams,
+    ) -> f32 {
+        clip_grad_norm(module, grads, self.max_norm)
+    }
+
+    /// Clip the gradients of the given module registered for the backend `G`, such as the full
+    /// precision gradients of the [mixed precision](crate::optim::MixedPrecision) optimizer.
+    pub(crate) fn clip_gradients_of<B, G, M>(&self, module: &M, grads: &mut GradientsParams) -> f32
+    where
+        B: ADBackend,
+        G: Backend,
+        M: ADModule<B>,
+    {
+        let norm = grads_norm_of::<B, G, M>(module, grads);
+
+        if norm > self.max_norm {
+            scale_grads_of::<B, G, M>(module, grads, self.max_norm / norm);
+        }
+
+        norm
+    }
+}
+
+/// Clip the gradients of every parameter of the given [module](ADModule) so that their combined
+/// L2 norm is at most `max_norm`.
+///
+/// The norm is computed over all the gradients as if they were concatenated into a single vector,
+/// and every gradient is scaled by the same factor, matching PyTorch's `clip_grad_norm_`.
+///
+/// # Returns
+///
+/// The total norm of the gradients before clipping.
+pub fn clip_grad_norm<B: ADBackend, M: ADModule<B>>(
+    module: &M,
+    grads: &mut GradientsParams,
+    max_norm: f32,
+) -> f32 {
+    let norm = grads_norm(module, grads);
+
+    if norm > max_norm {
+        scale_grads(module, grads, max_norm / norm);
+    }
+
+    norm
+}
+
+/// Compute the L2 norm of all the gradients of the given module as a single vector.
+pub(crate) fn grads_norm<B: ADBackend, M: ADModule<B>>(module: &M, grads: &GradientsParams) -> f32 {
+    grads_norm_of::<B, B::InnerBackend, M>(module, grads)
+}
+
+fn grads_norm_of<B: ADBackend, G: Backend, M: ADModule<B>>(
+    module: &M,
+    grads: &GradientsParams,
+) -> f32 {
+    let mut visitor = GradientsSquaredNorm::<M, G>::new(grads, None);
+    module.visit(&mut visitor);
+
+    match visitor.sum {
+        Some(sum) => sum.sqrt().into_scalar().elem::<f32>(),
+        None => 0.0,
+    }
+}
+
+/// Multiply all the gradients of the given module by the same factor.
+pub(crate) fn scale_grads<B: ADBackend, M: ADModule<B>>(
+    module: &M,
+    grads: &mut GradientsParams,
+    scale: f32,
+) {
+    scale_grads_of::<B, B::InnerBackend, M>(module, grads, scale);
+}
+
+fn scale_grads_of<B: ADBackend, G: Backend, M: ADModule<B>>(
+    module: &M,
+    grads: &mut GradientsParams,
+    scale: f32,
+) {
+    let mut visitor = GradientsScaler::<M, G>::new(grads, scale);
+    module.visit(&mut visitor);
+}
+
+#[derive(new)]
+struct GradientsSquaredNorm<'a, M, B: Backend> {
+    grads: &'a GradientsParams,
+    sum: Option<Tensor<B, 1>>,
+    phantom: PhantomData<M>,
+}
+
+#[derive(new)]
+struct GradientsScaler<'a, M, B: Backend> {
+    grads: &'a mut GradientsParams,
+    scale: f32,
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<'a, B, M, G> ModuleVisitor<B> for GradientsSquaredNorm<'a, M, G>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+    G: Backend,
+{
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<G, D>(id) {
+            let squared = grad.powf(2.0).sum();
+
+            self.sum = Some(match self.sum.take() {
+                Some(sum) => {
+                    let device = sum.device();
+                    sum.add(squared.to_device(&device))
+                }
+                None => squared,
+            });
+        }
+    }
+}
+
+impl<'a, B, M, G> ModuleVisitor<B> for GradientsScaler<'a, M, G>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+    G: Backend,
+{
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<G, D>(id) {
+            self.grads
+                .register::<G, D>(id.clone(), grad.mul_scalar(self.scale));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{module::list_param_ids, nn::LinearConfig, TestADBackend, TestBackend};
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_clip_grad_norm_scales_all_gradients_by_the_same_factor() {
+        let layer = LinearConfig::new(20, 20).init::<TestADBackend>();
+        let loss = layer.forward(Tensor::random([2, 20], Distribution::Standard));
+        let mut grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let [weight_id, bias_id]: [ParamId; 2] = list_param_ids(&layer).try_into().unwrap();
+        let weight = grads.get::<TestBackend, 2>(&weight_id).unwrap();
+        let bias = grads.get::<TestBackend, 1>(&bias_id).unwrap();
+        let norm_expected = (weight.clone().powf(2.0).sum() + bias.clone().powf(2.0).sum())
+            .sqrt()
+            .into_scalar();
+        let max_norm = norm_expected / 4.0;
+
+        let norm = clip_grad_norm(&layer, &mut grads, max_norm);
+
+        assert!((norm - norm_expected).abs() < 1e-4);
+        let weight_clipped = grads.get::<TestBackend, 2>(&weight_id).unwrap();
+        let bias_clipped = grads.get::<TestBackend, 1>(&bias_id).unwrap();
+        weight_clipped
+            .to_data()
+            .assert_approx_eq(&weight.div_scalar(4.0).into_data(), 3);
+        bias_clipped
+            .to_data()
+            .assert_approx_eq(&bias.div_scalar(4.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_clip_grad_norm_keeps_gradients_below_max_norm() {
+        let layer = LinearConfig::new(20, 20).init::<TestADBackend>();
+        let loss = layer.forward(Tensor::random([2, 20], Distribution::Standard));
+        let mut grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let [weight_id, _bias_id]: [ParamId; 2] = list_param_ids(&layer).try_into().unwrap();
+        let weight = grads.get::<TestBackend, 2>(&weight_id).unwrap();
+
+        let norm = clip_grad_norm(&layer, &mut grads, f32::MAX);
+
+        assert!(norm > 0.0);
+        grads
+            .get::<TestBackend, 2>(&weight_id)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&weight.into_data(), 3);
+    }
+}
@@ -1,2 +1,5 @@
 mod base;
+mod global_norm;
+
 pub use base::*;
+pub use global_norm::*;
@@ -0,0 +1,211 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::conv3d;
+use burn_tensor::ops::conv::calculate_conv_padding;
+use burn_tensor::ops::ConvOptions;
+
+use libm::sqrt;
+
+/// Configuration to create an [3D convolution](Conv3d) layer.
+#[derive(Config)]
+pub struct Conv3dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1, 1]")]
+    pub stride: [usize; 3],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1, 1]")]
+    pub dilation: [usize; 3],
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "Conv3dPaddingConfig::Valid")]
+    pub padding: Conv3dPaddingConfig,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::UniformDefault")]
+    pub initializer: Initializer,
+}
+
+/// Padding configuration for 3D convolution [config](Conv3dConfig).
+#[derive(Module, Config, Debug)]
+pub enum Conv3dPaddingConfig {
+    /// Dynamicaly calculate the amount of padding necessary to ensure that the output size will be
+    /// the same as the input.
+    Same,
+    /// Same as no padding.
+    Valid,
+    /// Applies the specified amount of padding to all inputs.
+    Explicit(usize, usize, usize),
+}
+
+/// Applies a 3D convolution over input tensors.
+///
+/// # Params
+///
+/// - weight: Tensor of shape [channels_out, channels_in / groups, kernel_size_1, kernel_size_2, kernel_size_3]
+///   initialized from a uniform distribution `U(-k, k)` where
+///   `k = sqrt(groups / channels_in * kernel_size_1 * kernel_size_2 * kernel_size_3)`
+///
+/// - bias:   Tensor of shape [channels_out], initialized from a uniform distribution `U(-k, k)`
+///   where `k = sqrt(groups / channels_in * kernel_size_1 * kernel_size_2 * kernel_size_3)`
+#[derive(Module, Debug)]
+pub struct Conv3d<B: Backend> {
+    weight: Param<Tensor<B, 5>>,
+    bias: Option<Param<Tensor<B, 1>>>,
+    stride: [usize; 3],
+    kernel_size: [usize; 3],
+    dilation: [usize; 3],
+    groups: usize,
+    padding: Conv3dPaddingConfig,
+}
+
+impl Conv3dConfig {
+    /// Initialize a new [conv3d](Conv3d) module.
+    pub fn init<B: Backend>(&self) -> Conv3d<B> {
+        let k =
+            (self.channels[0] / self.groups * self.kernel_size.iter().product::<usize>()) as f64;
+        let k = sqrt(1.0 / k);
+
+        let initializer = if let Initializer::UniformDefault = self.initializer {
+            Initializer::Uniform(-k, k)
+        } else {
+            self.initializer.clone()
+        };
+
+        let weight = initializer.init([
+            self.channels[1],
+            self.channels[0] / self.groups,
+            self.kernel_size[0],
+            self.kernel_size[1],
+            self.kernel_size[2],
+        ]);
+
+        let bias = if self.bias {
+            Some(initializer.init([self.channels[1]]))
+        } else {
+            None
+        };
+
+        Conv3d {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            padding: self.padding.clone(),
+            groups: self.groups,
+        }
+    }
+
+    /// Initialize a new [conv3d](Conv3d) module with a [record](Conv3dRecord).
+    pub fn init_with<B: Backend>(&self, record: Conv3dRecord<B>) -> Conv3d<B> {
+        Conv3d {
+            weight: record.weight,
+            bias: record.bias,
+            stride: self.stride,
+            dilation: self.dilation,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+            groups: self.groups,
+        }
+    }
+}
+
+impl<B: Backend> Conv3d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, depth_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, depth_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
+        let padding = self.padding.calculate_padding_3d(
+            [depth_in, height_in, width_in],
+            &self.kernel_size,
+            &self.stride,
+        );
+        conv3d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
+        )
+    }
+}
+
+impl Conv3dPaddingConfig {
+    pub(crate) fn calculate_padding_3d(
+        &self,
+        size: [usize; 3],
+        kernel_size: &[usize; 3],
+        stride: &[usize; 3],
+    ) -> [usize; 3] {
+        match self {
+            Conv3dPaddingConfig::Same => [0, 1, 2]
+                .map(|i| calculate_conv_padding(kernel_size[i], stride[i], size[i], size[i])),
+            Conv3dPaddingConfig::Valid => [0, 0, 0],
+            Conv3dPaddingConfig::Explicit(v1, v2, v3) => [*v1, *v2, *v3],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::Data;
+
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = Conv3dConfig::new([5, 1], [3, 3, 3]);
+        let k = (config.channels[0] * config.kernel_size.iter().product::<usize>()) as f64;
+        let k = sqrt(1.0 / k) as f32;
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::UniformDefault);
+        conv.weight.to_data().assert_in_range(-k, k);
+    }
+
+    #[test]
+    fn initializer_zeros() {
+        TestBackend::seed(0);
+
+        let config = Conv3dConfig::new([5, 2], [3, 3, 3]).with_initializer(Initializer::Zeros);
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::Zeros);
+        conv.weight
+            .to_data()
+            .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
+    }
+
+    #[test]
+    fn output_shape_same_padding() {
+        let config = Conv3dConfig::new([4, 6], [3, 5, 3])
+            .with_groups(2)
+            .with_padding(Conv3dPaddingConfig::Same);
+        let conv = config.init::<TestBackend>();
+
+        let output = conv.forward(Tensor::zeros([2, 4, 4, 7, 5]));
+
+        assert_eq!(conv.weight.shape().dims, [6, 2, 3, 5, 3]);
+        assert_eq!(output.dims(), [2, 6, 4, 7, 5]);
+    }
+}
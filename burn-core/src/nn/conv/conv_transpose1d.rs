@@ -0,0 +1,188 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::conv_transpose1d;
+use burn_tensor::ops::ConvTransposeOptions;
+
+use libm::sqrt;
+
+/// Configuration to create an [1D transposed convolution](ConvTranspose1d) layer.
+#[derive(Config)]
+pub struct ConvTranspose1dConfig {
+    /// The number of input channels.
+    pub channels_in: usize,
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride of the convolution.
+    #[config(default = "1")]
+    pub stride: usize,
+    /// Spacing between kernel elements.
+    #[config(default = "1")]
+    pub dilation: usize,
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "0")]
+    pub padding: usize,
+    /// The additional size added to one side of the output shape.
+    #[config(default = "0")]
+    pub padding_out: usize,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::UniformDefault")]
+    pub initializer: Initializer,
+}
+
+/// Applies a 1D transposed convolution over input tensors.
+///
+/// # Params
+///
+/// - weight: Tensor of shape [channels_in, channels_out / groups, kernel_size] initialized from a
+///     uniform distribution `U(-k, k)` where `k = sqrt(groups / channels_out * kernel_size)`
+///
+/// - bias:   Tensor of shape [channels_out], initialized from a uniform distribution `U(-k, k)`
+///     where `k = sqrt(groups / channels_out * kernel_size)`
+#[derive(Module, Debug)]
+pub struct ConvTranspose1d<B: Backend> {
+    weight: Param<Tensor<B, 3>>,
+    bias: Option<Param<Tensor<B, 1>>>,
+    stride: usize,
+    kernel_size: usize,
+    dilation: usize,
+    groups: usize,
+    padding: usize,
+    padding_out: usize,
+}
+
+impl ConvTranspose1dConfig {
+    /// Initialize a new [conv transpose 1d](ConvTranspose1d) module.
+    pub fn init<B: Backend>(&self) -> ConvTranspose1d<B> {
+        let k = (self.channels_out / self.groups * self.kernel_size) as f64;
+        let k = sqrt(1.0 / k);
+
+        let initializer = if let Initializer::UniformDefault = self.initializer {
+            Initializer::Uniform(-k, k)
+        } else {
+            self.initializer.clone()
+        };
+
+        let weight = initializer.init([
+            self.channels_in,
+            self.channels_out / self.groups,
+            self.kernel_size,
+        ]);
+
+        let bias = if self.bias {
+            Some(initializer.init([self.channels_out]))
+        } else {
+            None
+        };
+
+        ConvTranspose1d {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding,
+            padding_out: self.padding_out,
+        }
+    }
+
+    /// Initialize a new [conv transpose 1d](ConvTranspose1d) module with a
+    /// [record](ConvTranspose1dRecord).
+    pub fn init_with<B: Backend>(&self, record: ConvTranspose1dRecord<B>) -> ConvTranspose1d<B> {
+        ConvTranspose1d {
+            weight: record.weight,
+            bias: record.bias,
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding,
+            padding_out: self.padding_out,
+        }
+    }
+}
+
+impl<B: Backend> ConvTranspose1d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, length_in],
+    /// - output: [batch_size, channels_out, length_out],
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        conv_transpose1d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvTransposeOptions::new(
+                [self.stride],
+                [self.padding],
+                [self.padding_out],
+                [self.dilation],
+                self.groups,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::Data;
+
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = ConvTranspose1dConfig::new(5, 1, 5);
+        let k = (config.channels_out * config.kernel_size) as f64;
+        let k = sqrt(1.0 / k) as f32;
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::UniformDefault);
+        conv.weight.to_data().assert_in_range(-k, k);
+    }
+
+    #[test]
+    fn initializer_zeros() {
+        TestBackend::seed(0);
+
+        let config = ConvTranspose1dConfig::new(5, 2, 5).with_initializer(Initializer::Zeros);
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::Zeros);
+        conv.weight
+            .to_data()
+            .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
+    }
+
+    #[test]
+    fn output_shape() {
+        let config = ConvTranspose1dConfig::new(2, 4, 3)
+            .with_stride(2)
+            .with_padding(1)
+            .with_padding_out(1)
+            .with_dilation(2);
+        let conv = config.init::<TestBackend>();
+
+        let output = conv.forward(Tensor::zeros([3, 2, 6]));
+
+        assert_eq!(output.dims(), [3, 4, 14]);
+    }
+}
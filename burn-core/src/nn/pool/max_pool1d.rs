@@ -0,0 +1,67 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::conv::Conv1dPaddingConfig;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::max_pool1d;
+use burn_tensor::ops::conv::calculate_conv_padding;
+
+/// Configuration to create a [1D max pooling](MaxPool1d) layer.
+#[derive(Config)]
+pub struct MaxPool1dConfig {
+    /// The number of channels.
+    pub channels: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride.
+    #[config(default = "1")]
+    pub stride: usize,
+    /// The padding configuration.
+    #[config(default = "MaxPool1dPaddingConfig::Valid")]
+    pub padding: MaxPool1dPaddingConfig,
+}
+
+/// Padding configuration for 1D max pooling [config](MaxPool1dConfig).
+pub type MaxPool1dPaddingConfig = Conv1dPaddingConfig;
+
+/// Applies a 1D max pooling over input tensors.
+#[derive(Module, Debug, Clone)]
+pub struct MaxPool1d {
+    stride: usize,
+    kernel_size: usize,
+    padding: MaxPool1dPaddingConfig,
+}
+
+impl MaxPool1dConfig {
+    /// Initialize a new [max pool 1d](MaxPool1d) module.
+    pub fn init(&self) -> MaxPool1d {
+        MaxPool1d {
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+        }
+    }
+}
+
+impl MaxPool1d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, length_in],
+    /// - output: [batch_size, channels, length_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let padding = match &self.padding {
+            MaxPool1dPaddingConfig::Valid => 0,
+            MaxPool1dPaddingConfig::Same => {
+                let [_batch_size, _channels, length] = input.dims();
+                calculate_conv_padding(self.kernel_size, self.stride, length, length)
+            }
+            MaxPool1dPaddingConfig::Explicit(value) => *value,
+        };
+
+        max_pool1d(input, self.kernel_size, self.stride, padding)
+    }
+}
@@ -1,5 +1,17 @@
+mod adaptive_avg_pool1d;
+mod adaptive_avg_pool2d;
+mod avg_pool1d;
 mod avg_pool2d;
+mod avg_pool3d;
+mod max_pool1d;
 mod max_pool2d;
+mod max_pool3d;
 
+pub use adaptive_avg_pool1d::*;
+pub use adaptive_avg_pool2d::*;
+pub use avg_pool1d::*;
 pub use avg_pool2d::*;
+pub use avg_pool3d::*;
+pub use max_pool1d::*;
 pub use max_pool2d::*;
+pub use max_pool3d::*;
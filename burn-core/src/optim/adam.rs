@@ -85,20 +85,27 @@ impl<B: Backend> SimpleOptimizer<B> for Adam<B> {
 
 impl AdamConfig {
     pub fn init<B: ADBackend, M: ADModule<B>>(&self) -> impl Optimizer<M, B> {
-        let optim = Adam {
+        let mut optim = OptimizerAdaptor::from(self.init_simple());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize the [simple optimizer](SimpleOptimizer), for instance to update the master
+    /// weights of a [mixed precision](super::MixedPrecision) optimizer.
+    ///
+    /// The gradient clipping is only applied by the optimizer returned by [init](AdamConfig::init),
+    /// the mixed precision optimizer has [its own](super::MixedPrecisionConfig).
+    pub fn init_simple<B: Backend>(&self) -> Adam<B> {
+        Adam {
             momentum: AdaptiveMomentum {
                 beta_1: self.beta_1,
                 beta_2: self.beta_2,
                 epsilon: self.epsilon,
             },
             weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
-        };
-
-        let mut optim = OptimizerAdaptor::from(optim);
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
@@ -109,10 +116,10 @@ pub struct AdaptiveMomentumState<B: Backend, const D: usize> {
     moment_2: Tensor<B, D>,
 }
 
-struct AdaptiveMomentum {
-    beta_1: f32,
-    beta_2: f32,
-    epsilon: f32,
+pub(crate) struct AdaptiveMomentum {
+    pub(crate) beta_1: f32,
+    pub(crate) beta_2: f32,
+    pub(crate) epsilon: f32,
 }
 
 impl AdaptiveMomentum {
@@ -0,0 +1,230 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    adam::{AdaptiveMomentum, AdaptiveMomentumState},
+    Optimizer, SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+#[derive(Config)]
+pub struct AdamWConfig {
+    /// Parameter for AdamW.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for AdamW.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-5)]
+    epsilon: f32,
+    /// Weight decay factor, applied directly to the parameters.
+    #[config(default = 1e-4)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// AdamW optimizer as described in the paper [Decoupled Weight Decay Regularization](https://arxiv.org/pdf/1711.05101.pdf).
+///
+/// Contrary to [Adam](super::Adam) with [weight decay](super::decay::WeightDecayConfig), the
+/// decay is not added to the gradient, but applied to the parameters independently of the
+/// adaptive learning rate.
+pub struct AdamW<B: Backend> {
+    momentum: AdaptiveMomentum,
+    weight_decay: f32,
+    _backend: core::marker::PhantomData<B>,
+}
+
+#[derive(Record, Clone, new)]
+pub struct AdamWState<B: Backend, const D: usize> {
+    momentum: AdaptiveMomentumState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for AdamW<B> {
+    type State<const D: usize> = AdamWState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let state_momentum = state.map(|state| state.momentum);
+        let (grad, state_momentum) = self.momentum.transform(grad, state_momentum);
+
+        let decay_rate = 1.0 - lr * self.weight_decay as f64;
+        let tensor = tensor.mul_scalar(decay_rate);
+        let delta = grad.mul_scalar(lr);
+
+        (tensor - delta, Some(AdamWState::new(state_momentum)))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl AdamWConfig {
+    pub fn init<B: ADBackend, M: ADModule<B>>(&self) -> impl Optimizer<M, B> {
+        let mut optim = OptimizerAdaptor::from(self.init_simple());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize the [simple optimizer](SimpleOptimizer), for instance to update the master
+    /// weights of a [mixed precision](super::MixedPrecision) optimizer.
+    ///
+    /// The gradient clipping is only applied by the optimizer returned by [init](AdamWConfig::init),
+    /// the mixed precision optimizer has [its own](super::MixedPrecisionConfig).
+    pub fn init_simple<B: Backend>(&self) -> AdamW<B> {
+        AdamW {
+            momentum: AdaptiveMomentum {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay,
+            _backend: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_adamw_optimizer_save_load_state() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let mut optimizer = create_adamw();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let bytes = recorder.record(optimizer.to_record(), ()).unwrap();
+        let optimizer = create_adamw();
+        let optimizer = optimizer.load_record(recorder.load(bytes).unwrap());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adamw_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            Data::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        let mut optimizer = AdamWConfig::new()
+            .with_epsilon(1e-8)
+            .with_beta_1(0.9)
+            .with_beta_2(0.999)
+            .with_weight_decay(0.5)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let state_expected = given_linear_record(
+            Data::from([
+                [-0.3373, 0.1161, 0.3804, 0.2969, 0.0652, 0.0465],
+                [0.0587, -0.0365, -0.3812, 0.2343, 0.1753, -0.3075],
+                [-0.0387, 0.0143, -0.3132, 0.2260, -0.2951, 0.2900],
+                [-0.3150, -0.2391, -0.3877, -0.3151, -0.0953, 0.1410],
+                [0.3069, -0.2358, 0.3482, -0.1910, 0.3560, -0.0499],
+                [-0.0356, -0.0318, 0.1046, 0.1703, 0.0091, 0.3596],
+            ]),
+            Data::from([-0.4066, 0.0676, -0.1160, 0.0965, 0.1153, -0.0071]),
+        );
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+        let (weight_expected, bias_expected) = (
+            state_expected.weight.to_data(),
+            state_expected.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, 2);
+        weight_updated.assert_approx_eq(&weight_expected, 2);
+    }
+
+    fn given_linear_layer(weight: Data<f32, 2>, bias: Data<f32, 1>) -> nn::Linear<TestADBackend> {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let record = given_linear_record(weight, bias);
+
+        linear.load_record(record)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn create_adamw(
+    ) -> OptimizerAdaptor<AdamW<TestBackend>, nn::Linear<TestADBackend>, TestADBackend> {
+        let config = AdamWConfig::new();
+        AdamW {
+            momentum: AdaptiveMomentum {
+                beta_1: config.beta_1,
+                beta_2: config.beta_2,
+                epsilon: config.epsilon,
+            },
+            weight_decay: config.weight_decay,
+            _backend: core::marker::PhantomData,
+        }
+        .into()
+    }
+}
@@ -17,6 +17,23 @@ where
     /// The updated module is returned.
     fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M;
 
+    /// The norm of all the gradients computed before clipping during the last step.
+    ///
+    /// Only reported when [global norm clipping](crate::grad_clipping::GlobalNormClipping) is
+    /// enabled.
+    fn grad_norm(&self) -> Option<f32> {
+        None
+    }
+
+    /// If the optimizer checks the gradients for infinite and NaN values, skipping the update
+    /// when there are any.
+    ///
+    /// This is the case of [mixed precision](crate::optim::MixedPrecision) training, where
+    /// non-finite gradients are expected when the loss scale is too large.
+    fn skips_non_finite_grads(&self) -> bool {
+        false
+    }
+
     /// Get the current state of the optimizer as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
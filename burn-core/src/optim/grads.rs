@@ -1,12 +1,18 @@
 use burn_tensor::{
     backend::{ADBackend, Backend},
     container::TensorContainer,
-    Tensor,
+    ElementConversion, Tensor,
 };
 
+use alloc::vec::Vec;
+
+use crate::grad_clipping::scale_grads;
 use crate::module::{ADModule, ParamId};
 
-use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
+use super::visitor::{
+    GradientsParamsChangeDevice, GradientsParamsConverter, GradientsParamsNonFinite,
+};
+use super::GradientsAccumulator;
 
 /// Data type that contains gradients for parameters.
 #[derive(Default)]
@@ -73,6 +79,44 @@ impl GradientsParams {
         self
     }
 
+    /// Average the gradients computed on multiple devices for the given [module](ADModule).
+    ///
+    /// Each gradients is moved to the given device before being summed, so the result can be
+    /// used by an optimizer to update the module on that device.
+    ///
+    /// Every gradients has the same weight, so the result is the gradients of the whole batch
+    /// only when the batches of all devices have the same size. With a smaller last batch, its
+    /// items weigh more than the others.
+    pub fn mean<B: ADBackend, M: ADModule<B>>(
+        grads: Vec<GradientsParams>,
+        device: &B::Device,
+        module: &M,
+    ) -> Self {
+        let num_grads = grads.len();
+        let mut accumulator = GradientsAccumulator::new();
+
+        for grads in grads {
+            accumulator.accumulate(module, grads.to_device(device, module));
+        }
+
+        let mut grads = accumulator.grads();
+        if num_grads > 1 {
+            scale_grads(module, &mut grads, 1.0 / num_grads as f32);
+        }
+
+        grads
+    }
+
+    /// The [parameter ids](ParamId) of the given [module](ADModule) whose gradients contain
+    /// infinite or NaN values.
+    pub fn non_finite_params<B: ADBackend, M: ADModule<B>>(&self, module: &M) -> Vec<ParamId> {
+        let mut params = Vec::new();
+        let mut visitor = GradientsParamsNonFinite::<M, B>::new(self, &mut params);
+
+        module.visit(&mut visitor);
+        params
+    }
+
     /// Extract each tensor gradients for the given [module](ADModule).
     pub fn from_grads<B: ADBackend, M: ADModule<B>>(grads: B::Gradients, module: &M) -> Self {
         let mut grads_params = GradientsParams::new();
@@ -83,13 +127,25 @@ impl GradientsParams {
     }
 }
 
+/// If all the values of the tensor are finite.
+///
+/// Each value is checked on its own, since a reduction such as the sum could overflow for large
+/// finite values in half precision.
+pub(crate) fn is_finite<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> bool {
+    tensor
+        .into_data()
+        .value
+        .into_iter()
+        .all(|value| value.elem::<f64>().is_finite())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         module::{list_param_ids, Module},
         nn::{Linear, LinearConfig},
-        TestADBackend,
+        TestADBackend, TestBackend,
     };
     use burn_tensor::{backend::Backend, Distribution};
 
@@ -111,6 +167,38 @@ mod tests {
         assert_eq!(grads_2.len(), param_ids_2.len());
     }
 
+    #[test]
+    fn test_is_finite_checks_each_value() {
+        // The sum of the values overflows.
+        let tensor = Tensor::<TestBackend, 1>::from_data([3e38, 3e38]);
+        assert!(is_finite(tensor));
+
+        let tensor = Tensor::<TestBackend, 1>::from_data([1.0, f32::NAN]);
+        assert!(!is_finite(tensor));
+    }
+
+    #[test]
+    fn test_mean_grads() {
+        let layer = layer();
+        let device = <TestADBackend as Backend>::Device::default();
+        let grads_1 =
+            GradientsParams::from_grads(layer.forward(random_tensor()).backward(), &layer);
+        let grads_2 =
+            GradientsParams::from_grads(layer.forward(random_tensor()).backward(), &layer);
+        // The first parameter is the weight.
+        let id = list_param_ids(&layer).remove(0);
+        let weight_1 = grads_1.get::<TestBackend, 2>(&id).unwrap();
+        let weight_2 = grads_2.get::<TestBackend, 2>(&id).unwrap();
+
+        let grads = GradientsParams::mean(vec![grads_1, grads_2], &device, &layer);
+
+        let weight = grads.get::<TestBackend, 2>(&id).unwrap();
+        let weight_expected = (weight_1 + weight_2).div_scalar(2.0);
+        weight
+            .into_data()
+            .assert_approx_eq(&weight_expected.into_data(), 5);
+    }
+
     fn layer() -> Linear<TestADBackend> {
         LinearConfig::new(20, 20).with_bias(true).init()
     }
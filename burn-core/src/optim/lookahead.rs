@@ -0,0 +1,290 @@
+use core::marker::PhantomData;
+
+use super::{GradientsParams, Optimizer};
+use crate::config::Config;
+use crate::module::{ADModule, ModuleMapper, ModuleVisitor, ParamId};
+use crate::record::{PrecisionSettings, Record};
+use crate::{self as burn, LearningRate};
+use burn_tensor::{backend::ADBackend, container::TensorContainer, Tensor};
+use serde::{Deserialize, Serialize};
+
+/// Configuration to create the [Lookahead](Lookahead) optimizer.
+#[derive(Config)]
+pub struct LookaheadConfig {
+    /// Number of steps of the inner optimizer between each synchronization of the slow weights.
+    #[config(default = 5)]
+    k: usize,
+    /// Step size used to move the slow weights towards the fast weights.
+    #[config(default = 0.5)]
+    alpha: f32,
+}
+
+/// Lookahead optimizer as described in the paper
+/// [Lookahead Optimizer: k steps forward, 1 step back](https://arxiv.org/abs/1907.08610).
+///
+/// The wrapped [optimizer](Optimizer) updates the fast weights. Every `k` steps, the slow weights
+/// are moved towards the fast weights, which are then reset to the new slow weights.
+pub struct Lookahead<M: ADModule<B>, B: ADBackend, O> {
+    optim: O,
+    k: usize,
+    alpha: f32,
+    step: usize,
+    slow_weights: SlowWeights<M, B>,
+}
+
+/// [Lookahead](Lookahead) record.
+#[derive(Clone, new)]
+pub struct LookaheadRecord<O: Record, W: Record> {
+    /// The record of the wrapped optimizer.
+    pub optim: O,
+    /// The slow weights, saved as a module record.
+    pub slow_weights: Option<W>,
+    /// The number of steps performed.
+    pub step: usize,
+}
+
+/// [Lookahead](Lookahead) record item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct LookaheadRecordItem<O: Record, W: Record, S: PrecisionSettings> {
+    optim: O::Item<S>,
+    slow_weights: Option<W::Item<S>>,
+    step: usize,
+}
+
+impl<O: Record, W: Record> Record for LookaheadRecord<O, W> {
+    type Item<S: PrecisionSettings> = LookaheadRecordItem<O, W, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        LookaheadRecordItem {
+            optim: self.optim.into_item(),
+            slow_weights: self.slow_weights.map(|record| record.into_item()),
+            step: self.step,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            optim: O::from_item(item.optim),
+            slow_weights: item.slow_weights.map(W::from_item),
+            step: item.step,
+        }
+    }
+}
+
+enum SlowWeights<M: ADModule<B>, B: ADBackend> {
+    Uninitialized,
+    Module(M),
+    Record(M::Record),
+}
+
+impl LookaheadConfig {
+    /// Initialize a [Lookahead](Lookahead) optimizer wrapping the given optimizer.
+    pub fn init<B, M, O>(&self, optim: O) -> Lookahead<M, B, O>
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+        O: Optimizer<M, B>,
+    {
+        Lookahead {
+            optim,
+            k: self.k,
+            alpha: self.alpha,
+            step: 0,
+            slow_weights: SlowWeights::Uninitialized,
+        }
+    }
+}
+
+impl<M, B, O> Optimizer<M, B> for Lookahead<M, B, O>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+    M::Record: Clone,
+    O: Optimizer<M, B>,
+{
+    type Record = LookaheadRecord<O::Record, M::Record>;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let slow_weights =
+            match core::mem::replace(&mut self.slow_weights, SlowWeights::Uninitialized) {
+                SlowWeights::Uninitialized => module.clone(),
+                SlowWeights::Module(module) => module,
+                SlowWeights::Record(record) => module.clone().load_record(record),
+            };
+
+        let mut module = self.optim.step(lr, module, grads);
+        self.step += 1;
+
+        if !self.step.is_multiple_of(self.k) {
+            self.slow_weights = SlowWeights::Module(slow_weights);
+            return module;
+        }
+
+        let mut params = TensorContainer::new();
+        let mut collector = ParamsCollector::<M>::new(&mut params);
+        slow_weights.visit(&mut collector);
+
+        let mut mapper = SlowWeightsMapper::<M>::new(&params, self.alpha);
+        module = module.map(&mut mapper);
+
+        self.slow_weights = SlowWeights::Module(module.clone());
+        module
+    }
+
+    fn grad_norm(&self) -> Option<f32> {
+        self.optim.grad_norm()
+    }
+
+    fn skips_non_finite_grads(&self) -> bool {
+        self.optim.skips_non_finite_grads()
+    }
+
+    fn to_record(&self) -> Self::Record {
+        let slow_weights = match &self.slow_weights {
+            SlowWeights::Uninitialized => None,
+            SlowWeights::Module(module) => Some(module.clone().into_record()),
+            SlowWeights::Record(record) => Some(record.clone()),
+        };
+
+        LookaheadRecord::new(self.optim.to_record(), slow_weights, self.step)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.optim = self.optim.load_record(record.optim);
+        self.step = record.step;
+        self.slow_weights = match record.slow_weights {
+            Some(record) => SlowWeights::Record(record),
+            None => SlowWeights::Uninitialized,
+        };
+        self
+    }
+}
+
+#[derive(new)]
+struct ParamsCollector<'a, M> {
+    params: &'a mut TensorContainer<ParamId>,
+    phantom: PhantomData<M>,
+}
+
+#[derive(new)]
+struct SlowWeightsMapper<'a, M> {
+    slow_weights: &'a TensorContainer<ParamId>,
+    alpha: f32,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B, M> ModuleVisitor<B> for ParamsCollector<'a, M>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+{
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params
+            .register::<B::InnerBackend, D>(id.clone(), tensor.clone().inner());
+    }
+}
+
+impl<'a, B, M> ModuleMapper<B> for SlowWeightsMapper<'a, M>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+{
+    fn map<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let slow = match self.slow_weights.get::<B::InnerBackend, D>(id) {
+            Some(slow) => slow,
+            None => return tensor,
+        };
+
+        let is_require_grad = tensor.is_require_grad();
+        let fast = tensor.inner();
+        let slow = slow.clone() + fast.sub(slow).mul_scalar(self.alpha);
+
+        let mut tensor = Tensor::from_inner(slow);
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        optim::SgdConfig,
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        tensor::{Distribution, Shape},
+        TestADBackend,
+    };
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn should_move_slow_weights_towards_fast_weights_every_k_steps() {
+        let layer_init = layer();
+        let mut layer = layer_init.clone();
+        let mut layer_fast = layer_init.clone();
+        let mut optim = LookaheadConfig::new()
+            .with_k(2)
+            .with_alpha(0.5)
+            .init(SgdConfig::new().init());
+        let mut optim_fast = SgdConfig::new().init();
+
+        for _ in 0..2 {
+            let input = random_tensor();
+            let grads = compute_grads(&layer, input.clone());
+            let grads_fast = compute_grads(&layer_fast, input);
+            layer = optim.step(LEARNING_RATE, layer, grads);
+            layer_fast = optim_fast.step(LEARNING_RATE, layer_fast, grads_fast);
+        }
+
+        let weights_init = layer_init.into_record().weight.val().inner();
+        let weights_fast = layer_fast.into_record().weight.val().inner();
+        let weights_expected = weights_init.clone() + (weights_fast - weights_init).mul_scalar(0.5);
+        let weights = layer.into_record().weight.val().inner();
+
+        weights
+            .into_data()
+            .assert_approx_eq(&weights_expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_save_and_load_record() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let layer = layer();
+        let mut optim = LookaheadConfig::new().init(SgdConfig::new().init());
+        let grads = compute_grads(&layer, random_tensor());
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        let record = optim.to_record();
+        assert_eq!(record.step, 1);
+        assert!(record.slow_weights.is_some());
+
+        let bytes = recorder.record(record, ()).unwrap();
+        let optim = LookaheadConfig::new().init(SgdConfig::new().init());
+        let mut optim = optim.load_record(recorder.load(bytes).unwrap());
+        let grads = compute_grads(&layer, random_tensor());
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        assert_eq!(optim.to_record().step, 2);
+    }
+
+    fn compute_grads(
+        layer: &Linear<TestADBackend>,
+        input: Tensor<TestADBackend, 2>,
+    ) -> GradientsParams {
+        let loss = layer.forward(input);
+        GradientsParams::from_grads(loss.backward(), layer)
+    }
+
+    fn random_tensor() -> Tensor<TestADBackend, 2> {
+        Tensor::<TestADBackend, 2>::random(Shape::new([2, 20]), Distribution::Standard)
+    }
+
+    fn layer() -> Linear<TestADBackend> {
+        LinearConfig::new(20, 20).with_bias(true).init()
+    }
+}
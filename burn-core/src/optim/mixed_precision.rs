@@ -0,0 +1,600 @@
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use super::record::AdaptorRecord;
+use super::{is_finite, GradientsParams, Optimizer, SimpleOptimizer};
+use crate::config::Config;
+use crate::grad_clipping::{ClippingStrategy, GradientClipping, GradientClippingConfig};
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::record::{PrecisionSettings, Record};
+use crate::{self as burn, LearningRate};
+use alloc::sync::Arc;
+use burn_tensor::backend::{ADBackend, Backend};
+use burn_tensor::Tensor;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// The full precision backend of the inner backend of an autodiff backend.
+type FullPrecision<B> = <<B as ADBackend>::InnerBackend as Backend>::FullPrecisionBackend;
+
+/// Configuration to create the [MixedPrecision](MixedPrecision) optimizer.
+#[derive(Config)]
+pub struct MixedPrecisionConfig {
+    /// The loss scale used for the first step.
+    #[config(default = 65536.0)]
+    init_scale: f32,
+    /// Factor by which the loss scale is multiplied after `growth_interval` consecutive steps
+    /// with finite gradients.
+    #[config(default = 2.0)]
+    growth_factor: f32,
+    /// Factor by which the loss scale is multiplied when a step is skipped.
+    #[config(default = 0.5)]
+    backoff_factor: f32,
+    /// Number of consecutive steps with finite gradients before the loss scale grows.
+    #[config(default = 2000)]
+    growth_interval: usize,
+    /// [Gradient Clipping](GradientClippingConfig) config, applied to the unscaled gradients.
+    gradient_clipping: Option<GradientClippingConfig>,
+}
+
+/// Mixed precision training with dynamic loss scaling.
+///
+/// The module is trained in half precision, while the wrapped [simple optimizer](SimpleOptimizer)
+/// updates a full precision copy of each parameter, the master weights. The parameters of the
+/// module are set to the master weights converted back to half precision after each step.
+///
+/// The loss must be multiplied by the [loss scale](LossScale) before computing the gradients,
+/// so that small gradients don't underflow in half precision. The gradients are unscaled in full
+/// precision before the update. When any of them is infinite or NaN, the step is skipped and the
+/// loss scale is reduced; it grows again after a number of steps without non-finite gradients.
+///
+/// The gradients are clipped once unscaled, since the clipping thresholds apply to the true
+/// gradients.
+pub struct MixedPrecision<O, M, B>
+where
+    O: SimpleOptimizer<FullPrecision<B>> + 'static,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    optim: MasterWeights<O>,
+    records: HashMap<ParamId, AdaptorRecord<MasterWeights<O>, FullPrecision<B>>>,
+    loss_scale: LossScale,
+    growth_factor: f32,
+    backoff_factor: f32,
+    growth_interval: usize,
+    num_finite_steps: usize,
+    grad_clipping: Option<ClippingStrategy>,
+    grad_norm: Option<f32>,
+    module: PhantomData<M>,
+}
+
+/// Shared handle to the loss scale of a [MixedPrecision](MixedPrecision) optimizer.
+///
+/// The handle can be stored in the module, so the loss can be scaled in the train step. All the
+/// clones of a handle share the same value, which is updated by the optimizer.
+#[derive(Clone, Debug)]
+pub struct LossScale {
+    bits: Arc<AtomicU32>,
+}
+
+impl LossScale {
+    fn new(value: f32) -> Self {
+        Self {
+            bits: Arc::new(AtomicU32::new(value.to_bits())),
+        }
+    }
+
+    /// The current loss scale.
+    pub fn value(&self) -> f32 {
+        f32::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+
+    /// Multiply the loss by the current loss scale.
+    pub fn scale<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.value())
+    }
+
+    fn set(&self, value: f32) {
+        self.bits.store(value.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl<B: Backend> Module<B> for LossScale {
+    type Record = ();
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {
+        // Nothing to do
+    }
+
+    fn map<M: ModuleMapper<B>>(self, _mapper: &mut M) -> Self {
+        self
+    }
+
+    fn load_record(self, _record: Self::Record) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {}
+}
+
+impl<B: ADBackend> ADModule<B> for LossScale {
+    type InnerModule = LossScale;
+
+    fn valid(&self) -> Self::InnerModule {
+        self.clone()
+    }
+}
+
+/// [Simple optimizer](SimpleOptimizer) keeping the master weights of a parameter in its state,
+/// used by the [MixedPrecision](MixedPrecision) optimizer.
+///
+/// The tensor given to the first step initializes the master weights, the following steps
+/// update the master weights with the wrapped optimizer.
+pub struct MasterWeights<O> {
+    optim: O,
+}
+
+/// [MasterWeights](MasterWeights) state.
+#[derive(new)]
+pub struct MasterWeightsState<O: SimpleOptimizer<B>, B: Backend, const D: usize> {
+    weights: Tensor<B, D>,
+    optim: Option<O::State<D>>,
+}
+
+/// [MasterWeights](MasterWeights) state item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct MasterWeightsStateItem<
+    O: SimpleOptimizer<B>,
+    B: Backend,
+    const D: usize,
+    S: PrecisionSettings,
+> {
+    weights: <Tensor<B, D> as Record>::Item<S>,
+    optim: Option<<O::State<D> as Record>::Item<S>>,
+}
+
+impl<O: SimpleOptimizer<B>, B: Backend, const D: usize> Record for MasterWeightsState<O, B, D> {
+    type Item<S: PrecisionSettings> = MasterWeightsStateItem<O, B, D, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        MasterWeightsStateItem {
+            weights: self.weights.into_item(),
+            optim: self.optim.map(|state| state.into_item()),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            weights: Record::from_item(item.weights),
+            optim: item.optim.map(O::State::<D>::from_item),
+        }
+    }
+}
+
+impl<O: SimpleOptimizer<B>, B: Backend, const D: usize> Clone for MasterWeightsState<O, B, D> {
+    fn clone(&self) -> Self {
+        Self {
+            weights: self.weights.clone(),
+            optim: self.optim.clone(),
+        }
+    }
+}
+
+impl<O, B> SimpleOptimizer<B> for MasterWeights<O>
+where
+    O: SimpleOptimizer<B> + 'static,
+    B: Backend,
+{
+    type State<const D: usize> = MasterWeightsState<O, B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (weights, state_optim) = match state {
+            Some(state) => (state.weights, state.optim),
+            None => (tensor, None),
+        };
+
+        let (weights, state_optim) = self.optim.step(lr, weights, grad, state_optim);
+        let state = MasterWeightsState::new(weights.clone(), state_optim);
+
+        (weights, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.weights = state.weights.to_device(device);
+        state.optim = state.optim.map(|state| O::to_device(state, device));
+        state
+    }
+}
+
+/// [MixedPrecision](MixedPrecision) record.
+#[derive(Clone, new)]
+pub struct MixedPrecisionRecord<O: Record> {
+    /// The master weights and the states of the wrapped optimizer.
+    pub optim: O,
+    /// The loss scale.
+    pub scale: f32,
+    /// The number of consecutive steps with finite gradients.
+    pub num_finite_steps: usize,
+}
+
+/// [MixedPrecision](MixedPrecision) record item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct MixedPrecisionRecordItem<O: Record, S: PrecisionSettings> {
+    optim: O::Item<S>,
+    scale: f32,
+    num_finite_steps: usize,
+}
+
+impl<O: Record> Record for MixedPrecisionRecord<O> {
+    type Item<S: PrecisionSettings> = MixedPrecisionRecordItem<O, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        MixedPrecisionRecordItem {
+            optim: self.optim.into_item(),
+            scale: self.scale,
+            num_finite_steps: self.num_finite_steps,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            optim: O::from_item(item.optim),
+            scale: item.scale,
+            num_finite_steps: item.num_finite_steps,
+        }
+    }
+}
+
+impl MixedPrecisionConfig {
+    /// Initialize a [MixedPrecision](MixedPrecision) optimizer wrapping the given simple
+    /// optimizer, which updates the master weights in full precision.
+    ///
+    /// The gradient clipping of the wrapped optimizer isn't applied, only the one of this config.
+    pub fn init<B, M, O>(&self, optim: O) -> MixedPrecision<O, M, B>
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+        O: SimpleOptimizer<FullPrecision<B>> + 'static,
+    {
+        MixedPrecision {
+            optim: MasterWeights { optim },
+            records: HashMap::new(),
+            loss_scale: LossScale::new(self.init_scale),
+            growth_factor: self.growth_factor,
+            backoff_factor: self.backoff_factor,
+            growth_interval: self.growth_interval,
+            num_finite_steps: 0,
+            grad_clipping: self.gradient_clipping.as_ref().map(|config| config.init()),
+            grad_norm: None,
+            module: PhantomData,
+        }
+    }
+}
+
+impl<O, M, B> MixedPrecision<O, M, B>
+where
+    O: SimpleOptimizer<FullPrecision<B>> + 'static,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    /// A handle to the loss scale, by which the loss must be multiplied before computing the
+    /// gradients.
+    pub fn loss_scale(&self) -> LossScale {
+        self.loss_scale.clone()
+    }
+
+    fn update_scale(&mut self, is_finite: bool) {
+        let scale = self.loss_scale.value();
+
+        if !is_finite {
+            self.num_finite_steps = 0;
+            self.loss_scale.set(scale * self.backoff_factor);
+            return;
+        }
+
+        self.num_finite_steps += 1;
+        if self.num_finite_steps == self.growth_interval {
+            self.num_finite_steps = 0;
+            self.loss_scale.set(scale * self.growth_factor);
+        }
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for MixedPrecision<O, M, B>
+where
+    O: SimpleOptimizer<FullPrecision<B>> + 'static,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    type Record =
+        MixedPrecisionRecord<HashMap<ParamId, AdaptorRecord<MasterWeights<O>, FullPrecision<B>>>>;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let mut grads_unscaled = GradientsParams::new();
+        let mut unscaler = GradientsUnscaler::<M, B>::new(
+            &grads,
+            &mut grads_unscaled,
+            1.0 / self.loss_scale.value(),
+            true,
+        );
+        module.visit(&mut unscaler);
+
+        let is_finite = unscaler.is_finite;
+        self.update_scale(is_finite);
+
+        if !is_finite {
+            return module;
+        }
+
+        let grad_clipping = match &self.grad_clipping {
+            Some(ClippingStrategy::GlobalNorm(clipping)) => {
+                self.grad_norm = Some(
+                    clipping
+                        .clip_gradients_of::<B, FullPrecision<B>, M>(&module, &mut grads_unscaled),
+                );
+                None
+            }
+            Some(ClippingStrategy::PerTensor(clipping)) => Some(clipping),
+            None => None,
+        };
+
+        let mut mapper = MasterWeightsMapper::<M, B, O>::new(
+            &self.optim,
+            &mut self.records,
+            &grads_unscaled,
+            lr,
+            grad_clipping,
+        );
+        module.map(&mut mapper)
+    }
+
+    fn grad_norm(&self) -> Option<f32> {
+        self.grad_norm
+    }
+
+    fn skips_non_finite_grads(&self) -> bool {
+        true
+    }
+
+    fn to_record(&self) -> Self::Record {
+        MixedPrecisionRecord::new(
+            self.records.clone(),
+            self.loss_scale.value(),
+            self.num_finite_steps,
+        )
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.records = record.optim;
+        self.loss_scale.set(record.scale);
+        self.num_finite_steps = record.num_finite_steps;
+        self
+    }
+}
+
+#[derive(new)]
+struct GradientsUnscaler<'a, M, B> {
+    grads: &'a GradientsParams,
+    grads_unscaled: &'a mut GradientsParams,
+    factor: f32,
+    is_finite: bool,
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<'a, M, B> ModuleVisitor<B> for GradientsUnscaler<'a, M, B>
+where
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let grad = match self.grads.get::<B::InnerBackend, D>(id) {
+            Some(grad) => grad.to_full_precision().mul_scalar(self.factor),
+            None => return,
+        };
+
+        self.is_finite = self.is_finite && is_finite(grad.clone());
+
+        self.grads_unscaled.register(id.clone(), grad);
+    }
+}
+
+#[derive(new)]
+struct MasterWeightsMapper<'a, M, B, O>
+where
+    M: ADModule<B>,
+    B: ADBackend,
+    O: SimpleOptimizer<FullPrecision<B>> + 'static,
+{
+    optim: &'a MasterWeights<O>,
+    records: &'a mut HashMap<ParamId, AdaptorRecord<MasterWeights<O>, FullPrecision<B>>>,
+    grads: &'a GradientsParams,
+    lr: LearningRate,
+    grad_clipping: Option<&'a GradientClipping>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, M, B, O> ModuleMapper<B> for MasterWeightsMapper<'a, M, B, O>
+where
+    M: ADModule<B>,
+    B: ADBackend,
+    O: SimpleOptimizer<FullPrecision<B>> + 'static,
+{
+    fn map<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let grad = match (
+            self.grads.get::<FullPrecision<B>, D>(id),
+            self.grad_clipping,
+        ) {
+            (Some(grad), Some(clipping)) => clipping.clip_gradient(grad),
+            (Some(grad), None) => grad,
+            (None, _) => return tensor,
+        };
+
+        let device = grad.device();
+        let is_require_grad = tensor.is_require_grad();
+        let (key, record) = self.records.remove_entry(id).unzip();
+
+        let (weights, state) = self.optim.step(
+            self.lr,
+            tensor.inner().to_full_precision(),
+            grad,
+            record.map(|record| MasterWeights::<O>::to_device(record.into_state(), &device)),
+        );
+
+        if let Some(state) = state {
+            self.records.insert(
+                key.unwrap_or_else(|| id.clone()),
+                AdaptorRecord::from_state(state),
+            );
+        }
+
+        let mut tensor = Tensor::from_inner(Tensor::from_full_precision(weights));
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::{TestADBackend, TestBackend};
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_mixed_precision_matches_full_precision_step() {
+        let layer = layer();
+        let mut optim_full = SgdConfig::new().init();
+        let mut optim_mixed = mixed_precision(MixedPrecisionConfig::new());
+
+        let x = random_tensor();
+        let grads = GradientsParams::from_grads(layer.forward(x.clone()).sum().backward(), &layer);
+        let scaled_loss = optim_mixed.loss_scale().scale(layer.forward(x).sum());
+        let grads_scaled = GradientsParams::from_grads(scaled_loss.backward(), &layer);
+
+        let layer_full = optim_full.step(0.1, layer.clone(), grads);
+        let layer_mixed = optim_mixed.step(0.1, layer, grads_scaled);
+
+        let record_full = layer_full.into_record();
+        let record_mixed = layer_mixed.into_record();
+        record_mixed
+            .weight
+            .to_data()
+            .assert_approx_eq(&record_full.weight.to_data(), 3);
+        record_mixed
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&record_full.bias.unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn test_mixed_precision_clips_unscaled_gradients() {
+        for clipping in [
+            GradientClippingConfig::Value(1e-3),
+            GradientClippingConfig::GlobalNorm(1e-3),
+        ] {
+            let layer = layer();
+            let mut optim_full = SgdConfig::new()
+                .with_gradient_clipping(Some(clipping.clone()))
+                .init();
+            let mut optim_mixed =
+                mixed_precision(MixedPrecisionConfig::new().with_gradient_clipping(Some(clipping)));
+
+            let x = random_tensor();
+            let loss = layer.forward(x.clone()).sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &layer);
+            let scaled_loss = optim_mixed.loss_scale().scale(layer.forward(x).sum());
+            let grads_scaled = GradientsParams::from_grads(scaled_loss.backward(), &layer);
+
+            let layer_full = optim_full.step(0.1, layer.clone(), grads);
+            let layer_mixed = optim_mixed.step(0.1, layer.clone(), grads_scaled);
+
+            assert_eq!(
+                optim_mixed.grad_norm().is_some(),
+                optim_full.grad_norm().is_some()
+            );
+            let weight = layer.into_record().weight.to_data();
+            let weight_full = layer_full.into_record().weight.to_data();
+            let weight_mixed = layer_mixed.into_record().weight.to_data();
+            weight_mixed.assert_approx_eq(&weight_full, 3);
+            let max_update = weight
+                .value
+                .iter()
+                .zip(weight_mixed.value.iter())
+                .map(|(before, after)| (before - after).abs())
+                .fold(0.0, f32::max);
+            assert!(max_update <= 0.1 * 1e-3 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_mixed_precision_skips_step_with_non_finite_grads() {
+        let layer = layer();
+        let mut optim = mixed_precision(MixedPrecisionConfig::new().with_init_scale(f32::MAX));
+        let loss_scale = optim.loss_scale();
+
+        let loss = loss_scale.scale(layer.forward(random_tensor()).sum());
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let layer_updated = optim.step(0.1, layer.clone(), grads);
+
+        assert_eq!(loss_scale.value(), f32::MAX * 0.5);
+        assert!(optim.to_record().optim.is_empty());
+        layer_updated
+            .into_record()
+            .weight
+            .to_data()
+            .assert_approx_eq(&layer.into_record().weight.to_data(), 3);
+    }
+
+    #[test]
+    fn test_mixed_precision_grows_loss_scale() {
+        let mut layer = layer();
+        let mut optim = mixed_precision(
+            MixedPrecisionConfig::new()
+                .with_init_scale(4.0)
+                .with_growth_interval(2),
+        );
+        let loss_scale = optim.loss_scale();
+        let mut scales = Vec::new();
+
+        for _ in 0..4 {
+            let loss = loss_scale.scale(layer.forward(random_tensor()).sum());
+            let grads = GradientsParams::from_grads(loss.backward(), &layer);
+            layer = optim.step(0.1, layer, grads);
+            scales.push(loss_scale.value());
+        }
+
+        assert_eq!(scales, vec![4.0, 8.0, 8.0, 16.0]);
+        let record = optim.to_record();
+        assert_eq!(record.scale, 16.0);
+        assert_eq!(record.num_finite_steps, 0);
+    }
+
+    fn mixed_precision(
+        config: MixedPrecisionConfig,
+    ) -> MixedPrecision<Sgd<TestBackend>, Linear<TestADBackend>, TestADBackend> {
+        config.init(SgdConfig::new().init_simple())
+    }
+
+    fn layer() -> Linear<TestADBackend> {
+        LinearConfig::new(4, 3).init()
+    }
+
+    fn random_tensor() -> Tensor<TestADBackend, 2> {
+        Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard)
+    }
+}
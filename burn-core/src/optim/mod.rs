@@ -2,16 +2,24 @@ pub mod decay;
 pub mod momentum;
 
 mod adam;
+mod adamw;
 mod base;
 mod grad_accum;
 mod grads;
+mod lookahead;
+mod mixed_precision;
+mod sam;
 mod sgd;
 mod simple;
 mod visitor;
 
 pub use adam::*;
+pub use adamw::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use lookahead::*;
+pub use mixed_precision::*;
+pub use sam::*;
 pub use sgd::*;
 pub use simple::*;
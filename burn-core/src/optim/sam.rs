@@ -0,0 +1,269 @@
+use core::marker::PhantomData;
+
+use super::{GradientsParams, Optimizer};
+use crate::config::Config;
+use crate::grad_clipping::{grads_norm, scale_grads};
+use crate::module::{ADModule, ModuleMapper, ParamId};
+use crate::{self as burn, LearningRate};
+use burn_tensor::{backend::ADBackend, Tensor};
+
+/// Configuration to create the [Sam](Sam) optimizer.
+#[derive(Config)]
+pub struct SamConfig {
+    /// Radius of the neighborhood in which the loss is maximized.
+    #[config(default = 0.05)]
+    rho: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-12)]
+    epsilon: f32,
+}
+
+/// Sharpness-Aware Minimization as described in the paper
+/// [Sharpness-Aware Minimization for Efficiently Improving Generalization](https://arxiv.org/abs/2010.01412).
+///
+/// Each update is done in two phases:
+///
+/// 1. [first_step](Sam::first_step) moves the module to the point of highest loss in its
+///    neighborhood, using the gradients of the current batch.
+/// 2. [second_step](Sam::second_step) restores the module and updates it with the wrapped
+///    [optimizer](Optimizer), using the gradients computed on the perturbed module.
+///
+/// When no perturbation is pending, [step](Optimizer::step) is a regular step of the wrapped
+/// optimizer, so the gradients can also be computed with [SamConfig::perturb] in a train step.
+pub struct Sam<M, B, O> {
+    optim: O,
+    rho: f32,
+    epsilon: f32,
+    perturbation: Option<GradientsParams>,
+    phantom: PhantomData<(M, B)>,
+}
+
+impl SamConfig {
+    /// Initialize a [Sam](Sam) optimizer wrapping the given optimizer.
+    pub fn init<B, M, O>(&self, optim: O) -> Sam<M, B, O>
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+        O: Optimizer<M, B>,
+    {
+        Sam {
+            optim,
+            rho: self.rho,
+            epsilon: self.epsilon,
+            perturbation: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Move the module to the point of highest loss in its neighborhood, following the given
+    /// gradients.
+    ///
+    /// The gradients computed on the returned module are the ones used by SAM to update the
+    /// original module.
+    pub fn perturb<B: ADBackend, M: ADModule<B>>(&self, module: M, grads: GradientsParams) -> M {
+        let perturbation = perturbation(&module, grads, self.rho, self.epsilon);
+        shift_params(module, &perturbation, 1.0)
+    }
+}
+
+impl<M, B, O> Sam<M, B, O>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+    O: Optimizer<M, B>,
+{
+    /// Perturb the module using the gradients computed on the current batch.
+    ///
+    /// The returned module should be used to compute the gradients passed to
+    /// [second_step](Sam::second_step).
+    pub fn first_step(&mut self, module: M, grads: GradientsParams) -> M {
+        let perturbation = perturbation(&module, grads, self.rho, self.epsilon);
+        let module = shift_params(module, &perturbation, 1.0);
+        self.perturbation = Some(perturbation);
+
+        module
+    }
+
+    /// Restore the perturbed module and update it with the wrapped optimizer using the
+    /// gradients computed on the perturbed module.
+    pub fn second_step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let module = match self.perturbation.take() {
+            Some(perturbation) => shift_params(module, &perturbation, -1.0),
+            None => module,
+        };
+
+        self.optim.step(lr, module, grads)
+    }
+}
+
+impl<M, B, O> Optimizer<M, B> for Sam<M, B, O>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+    O: Optimizer<M, B>,
+{
+    type Record = O::Record;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        self.second_step(lr, module, grads)
+    }
+
+    fn grad_norm(&self) -> Option<f32> {
+        self.optim.grad_norm()
+    }
+
+    fn skips_non_finite_grads(&self) -> bool {
+        self.optim.skips_non_finite_grads()
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.optim.to_record()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.optim = self.optim.load_record(record);
+        self
+    }
+}
+
+fn perturbation<B: ADBackend, M: ADModule<B>>(
+    module: &M,
+    mut grads: GradientsParams,
+    rho: f32,
+    epsilon: f32,
+) -> GradientsParams {
+    let norm = grads_norm(module, &grads);
+    scale_grads(module, &mut grads, rho / (norm + epsilon));
+
+    grads
+}
+
+fn shift_params<B: ADBackend, M: ADModule<B>>(
+    module: M,
+    shift: &GradientsParams,
+    direction: f32,
+) -> M {
+    let mut mapper = ParamsShift::<M>::new(shift, direction);
+    module.map(&mut mapper)
+}
+
+#[derive(new)]
+struct ParamsShift<'a, M> {
+    shift: &'a GradientsParams,
+    direction: f32,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B, M> ModuleMapper<B> for ParamsShift<'a, M>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+{
+    fn map<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shift = match self.shift.get::<B::InnerBackend, D>(id) {
+            Some(shift) => shift,
+            None => return tensor,
+        };
+
+        let is_require_grad = tensor.is_require_grad();
+        let device = tensor.device();
+        let shift = shift.mul_scalar(self.direction).to_device(&device);
+
+        let mut tensor = Tensor::from_inner(tensor.inner() + shift);
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        optim::SgdConfig,
+        tensor::{Distribution, Shape},
+        TestADBackend,
+    };
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn first_step_should_perturb_params_by_rho() {
+        let layer = layer();
+        let mut optim = SamConfig::new().with_rho(0.5).init(SgdConfig::new().init());
+        let grads = compute_grads(&layer, random_tensor());
+
+        let layer_perturbed = optim.first_step(layer.clone(), grads);
+
+        let record = layer.into_record();
+        let record_perturbed = layer_perturbed.into_record();
+        let delta_weight = record_perturbed.weight.val().inner() - record.weight.val().inner();
+        let delta_bias =
+            record_perturbed.bias.unwrap().val().inner() - record.bias.unwrap().val().inner();
+        let norm = (delta_weight.powf(2.0).sum() + delta_bias.powf(2.0).sum())
+            .sqrt()
+            .into_scalar();
+
+        assert!((norm - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn second_step_should_restore_params_before_updating() {
+        let layer = layer();
+        let input = random_tensor();
+        let mut optim = SamConfig::new().init(SgdConfig::new().init());
+        let mut optim_base = SgdConfig::new().init();
+        let grads = compute_grads(&layer, input.clone());
+
+        let layer_perturbed = optim.first_step(layer.clone(), grads);
+        let grads_perturbed = compute_grads(&layer_perturbed, input.clone());
+        let grads_expected = compute_grads(&layer_perturbed, input);
+        let layer_updated = optim.second_step(LEARNING_RATE, layer_perturbed, grads_perturbed);
+        let layer_expected = optim_base.step(LEARNING_RATE, layer, grads_expected);
+
+        layer_updated
+            .into_record()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_expected.into_record().weight.val().into_data(), 3);
+    }
+
+    #[test]
+    fn perturb_should_match_first_step() {
+        let layer = layer();
+        let input = random_tensor();
+        let config = SamConfig::new();
+        let mut optim = config.init(SgdConfig::new().init());
+
+        let layer_first_step =
+            optim.first_step(layer.clone(), compute_grads(&layer, input.clone()));
+        let layer_perturbed = config.perturb(layer.clone(), compute_grads(&layer, input));
+
+        layer_perturbed
+            .into_record()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_first_step.into_record().weight.val().into_data(), 3);
+    }
+
+    fn compute_grads(
+        layer: &Linear<TestADBackend>,
+        input: Tensor<TestADBackend, 2>,
+    ) -> GradientsParams {
+        let loss = layer.forward(input);
+        GradientsParams::from_grads(loss.backward(), layer)
+    }
+
+    fn random_tensor() -> Tensor<TestADBackend, 2> {
+        Tensor::<TestADBackend, 2>::random(Shape::new([2, 20]), Distribution::Standard)
+    }
+
+    fn layer() -> Linear<TestADBackend> {
+        LinearConfig::new(20, 20).with_bias(true).init()
+    }
+}
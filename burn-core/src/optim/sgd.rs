@@ -40,18 +40,24 @@ impl SgdConfig {
     pub fn init<B: ADBackend, M: ADModule<B>>(
         &self,
     ) -> OptimizerAdaptor<Sgd<B::InnerBackend>, M, B> {
-        let momentum = self.momentum.as_ref().map(Momentum::new);
-        let weight_decay = self.weight_decay.as_ref().map(WeightDecay::new);
-
-        let mut optim = OptimizerAdaptor::from(Sgd {
-            momentum,
-            weight_decay,
-        });
+        let mut optim = OptimizerAdaptor::from(self.init_simple());
         if let Some(config) = &self.gradient_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
         optim
     }
+
+    /// Initialize the [simple optimizer](SimpleOptimizer), for instance to update the master
+    /// weights of a [mixed precision](super::MixedPrecision) optimizer.
+    ///
+    /// The gradient clipping is only applied by the optimizer returned by [init](SgdConfig::init),
+    /// the mixed precision optimizer has [its own](super::MixedPrecisionConfig).
+    pub fn init_simple<B: Backend>(&self) -> Sgd<B> {
+        Sgd {
+            momentum: self.momentum.as_ref().map(Momentum::new),
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        }
+    }
 }
 
 impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
@@ -137,6 +143,21 @@ mod tests {
         assert!(optim.has_gradient_clipping());
     }
 
+    #[test]
+    fn global_norm_clipping_should_report_the_gradients_norm() {
+        let layer = layer();
+        let mut optim = SgdConfig::new()
+            .with_gradient_clipping(Some(GradientClippingConfig::GlobalNorm(1e-3)))
+            .init();
+        let loss = layer.forward(random_tensor());
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        assert_eq!(optim.grad_norm(), None);
+
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        assert!(optim.grad_norm().unwrap() > 1e-3);
+    }
+
     #[test]
     fn should_load_state() {
         let layer = layer();
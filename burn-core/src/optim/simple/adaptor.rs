@@ -1,6 +1,6 @@
 use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
-    grad_clipping::GradientClipping,
+    grad_clipping::{ClippingStrategy, GradientClipping},
     module::{ADModule, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
     LearningRate,
@@ -20,7 +20,8 @@ where
     optim: O,
     records: HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>,
     module: PhantomData<M>,
-    grad_clipping: Option<GradientClipping>,
+    grad_clipping: Option<ClippingStrategy>,
+    grad_norm: Option<f32>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -35,6 +36,7 @@ where
             records: HashMap::new(),
             module: PhantomData::default(),
             grad_clipping: None,
+            grad_norm: None,
         }
     }
 }
@@ -45,8 +47,8 @@ where
     M: ADModule<B>,
     B: ADBackend,
 {
-    pub fn with_grad_clipping(mut self, gradient_clipping: GradientClipping) -> Self {
-        self.grad_clipping = Some(gradient_clipping);
+    pub fn with_grad_clipping<C: Into<ClippingStrategy>>(mut self, gradient_clipping: C) -> Self {
+        self.grad_clipping = Some(gradient_clipping.into());
         self
     }
 
@@ -65,16 +67,29 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        let grad_clipping = match &self.grad_clipping {
+            Some(ClippingStrategy::GlobalNorm(clipping)) => {
+                self.grad_norm = Some(clipping.clip_gradients(&module, &mut grads));
+                None
+            }
+            Some(ClippingStrategy::PerTensor(clipping)) => Some(clipping),
+            None => None,
+        };
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
             &mut grads,
             lr,
-            self.grad_clipping.as_ref(),
+            grad_clipping,
         );
         module.map(&mut mapper)
     }
 
+    fn grad_norm(&self) -> Option<f32> {
+        self.grad_norm
+    }
+
     fn to_record(&self) -> Self::Record {
         self.records.clone()
     }
@@ -1,5 +1,6 @@
-use super::GradientsParams;
+use super::{is_finite, GradientsParams};
 use crate::module::{ADModule, ModuleVisitor, ParamId};
+use alloc::vec::Vec;
 use burn_tensor::{backend::ADBackend, Tensor};
 use core::marker::PhantomData;
 
@@ -10,6 +11,13 @@ pub struct GradientsParamsConverter<'a, M: ADModule<B>, B: ADBackend> {
     phatom: PhantomData<M>,
 }
 
+#[derive(new)]
+pub struct GradientsParamsNonFinite<'a, M: ADModule<B>, B: ADBackend> {
+    grads: &'a GradientsParams,
+    params: &'a mut Vec<ParamId>,
+    phatom: PhantomData<(M, B)>,
+}
+
 #[derive(new)]
 pub struct GradientsParamsChangeDevice<'a, M: ADModule<B>, B: ADBackend> {
     device: &'a B::Device,
@@ -30,6 +38,20 @@ where
     }
 }
 
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsNonFinite<'a, M, B>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+{
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            if !is_finite(grad) {
+                self.params.push(id.clone());
+            }
+        }
+    }
+}
+
 impl<'a, B, M> ModuleVisitor<B> for GradientsParamsChangeDevice<'a, M, B>
 where
     B: ADBackend,
@@ -0,0 +1,176 @@
+use super::row::RowDeserializer;
+use super::FileDatasetError;
+use arrow_array::RecordBatch;
+use arrow_schema::Schema;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// The number of decoded batches kept in memory, so that reading rows in a random order only
+/// decodes each batch once when they are spread over a few batches.
+const NUM_CACHED_BATCHES: usize = 8;
+
+/// Read the batches of rows of a columnar file, such as Arrow record batches or Parquet row
+/// groups.
+pub(crate) trait BatchReader: Send {
+    /// Read the batch at the given index, which might be split in many record batches.
+    fn read(&mut self, batch: usize) -> Result<Vec<RecordBatch>, FileDatasetError>;
+}
+
+/// Rows of a columnar file, decoded one batch at a time when they are accessed.
+///
+/// The last batches decoded are kept in memory, the least recently used being dropped first.
+/// The rows are deserialized straight from the arrays of the columns.
+pub(crate) struct BatchedRows<R> {
+    offsets: Vec<usize>,
+    state: Mutex<BatchesState<R>>,
+}
+
+struct BatchesState<R> {
+    reader: R,
+    /// The decoded batches with their index, the most recently used first.
+    cache: VecDeque<(usize, Arc<[RecordBatch]>)>,
+}
+
+impl<R: BatchReader> BatchedRows<R> {
+    /// Create the rows from the reader and the number of rows of each batch.
+    pub(crate) fn new<S>(reader: R, sizes: S) -> Self
+    where
+        S: IntoIterator<Item = usize>,
+    {
+        let mut offsets = vec![0];
+        for size in sizes {
+            offsets.push(offsets[offsets.len() - 1] + size);
+        }
+
+        Self {
+            offsets,
+            state: Mutex::new(BatchesState {
+                reader,
+                cache: VecDeque::with_capacity(NUM_CACHED_BATCHES),
+            }),
+        }
+    }
+
+    /// Deserialize the row at the given index into an item with its fields named after the
+    /// columns.
+    pub(crate) fn deserialize<I>(&self, index: usize) -> Option<Result<I, FileDatasetError>>
+    where
+        I: DeserializeOwned,
+    {
+        if index >= self.len() {
+            return None;
+        }
+
+        let invalid = |message| FileDatasetError::InvalidRecord { index, message };
+        let batch = self.offsets.partition_point(|offset| *offset <= index) - 1;
+        let records = match self.records(batch) {
+            Ok(records) => records,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let mut row = index - self.offsets[batch];
+        for record in records.iter() {
+            if row < record.num_rows() {
+                let item = I::deserialize(RowDeserializer::new(record, row))
+                    .map_err(|err| invalid(err.to_string()));
+                return Some(item);
+            }
+            row -= record.num_rows();
+        }
+
+        Some(Err(invalid("missing row".to_string())))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.offsets[self.offsets.len() - 1]
+    }
+
+    /// The record batches of the given batch, read from the file when they aren't cached.
+    fn records(&self, batch: usize) -> Result<Arc<[RecordBatch]>, FileDatasetError> {
+        let mut state = self.state.lock().unwrap();
+
+        let records = match state.cache.iter().position(|(index, _)| *index == batch) {
+            Some(position) => state.cache.remove(position).unwrap().1,
+            None => state.reader.read(batch)?.into(),
+        };
+
+        state.cache.truncate(NUM_CACHED_BATCHES - 1);
+        state.cache.push_front((batch, records.clone()));
+
+        Ok(records)
+    }
+}
+
+/// The indexes of the given columns in the schema.
+pub(crate) fn column_indexes(
+    schema: &Schema,
+    columns: &[String],
+) -> Result<Vec<usize>, FileDatasetError> {
+    columns
+        .iter()
+        .map(|column| {
+            schema
+                .index_of(column)
+                .map_err(|_| FileDatasetError::MissingColumn(column.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::UInt64Array;
+    use arrow_schema::{DataType, Field};
+
+    /// Batches of `size` rows with increasing ids, each split in two record batches.
+    struct Ids {
+        size: usize,
+        reads: Vec<usize>,
+    }
+
+    impl BatchReader for Ids {
+        fn read(&mut self, batch: usize) -> Result<Vec<RecordBatch>, FileDatasetError> {
+            self.reads.push(batch);
+            let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::UInt64, false)]));
+            let start = (batch * self.size) as u64;
+            let middle = start + self.size as u64 / 2;
+            let end = start + self.size as u64;
+
+            [start..middle, middle..end]
+                .into_iter()
+                .map(|ids| {
+                    let ids = Arc::new(UInt64Array::from_iter_values(ids));
+                    Ok(RecordBatch::try_new(schema.clone(), vec![ids])?)
+                })
+                .collect()
+        }
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Id {
+        id: usize,
+    }
+
+    #[test]
+    fn batched_rows_should_read_rows_out_of_order_across_batches() {
+        let num_batches = NUM_CACHED_BATCHES + 2;
+        let reader = Ids {
+            size: 5,
+            reads: Vec::new(),
+        };
+        let rows = BatchedRows::new(reader, vec![5; num_batches]);
+        let mut indexes: Vec<usize> = (0..rows.len()).rev().step_by(3).collect();
+        indexes.extend((0..rows.len()).step_by(7));
+
+        for index in indexes {
+            let item: Id = rows.deserialize(index).unwrap().unwrap();
+            assert_eq!(item, Id { id: index });
+        }
+
+        assert!(rows.deserialize::<Id>(rows.len()).is_none());
+        let reads = rows.state.lock().unwrap().reader.reads.clone();
+        // The two batches read first are evicted before being read again.
+        assert_eq!(reads.len(), num_batches + 2);
+    }
+}
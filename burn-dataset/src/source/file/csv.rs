@@ -0,0 +1,404 @@
+use super::records::FileRecords;
+use super::FileDatasetError;
+use crate::{Dataset, InMemDataset};
+use serde::de::value::{Error as DeError, MapDeserializer};
+use serde::de::{DeserializeOwned, Error as _, IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Deserializer};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/// Load a dataset from a CSV file, whose first record is the header with the column names.
+///
+/// Each record is deserialized into an item with its fields named after the columns. Empty
+/// fields are deserialized as `None` for optional values.
+pub struct CsvDatasetLoader {
+    path: PathBuf,
+    delimiter: u8,
+    columns: Option<Vec<String>>,
+}
+
+impl CsvDatasetLoader {
+    /// Create a CSV dataset loader for the given file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            delimiter: b',',
+            columns: None,
+        }
+    }
+
+    /// The byte separating the fields of a record, a comma by default.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Only deserialize the given columns, instead of all of them.
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = Some(columns.iter().map(|column| column.to_string()).collect());
+        self
+    }
+
+    /// Load the dataset, reading each record from the file when it is accessed.
+    pub fn load<I>(self) -> Result<CsvDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Send + Sync,
+    {
+        let mut quoting = Quoting::FieldStart;
+        let records = FileRecords::open(&self.path, |byte| {
+            match quoting.read(byte, self.delimiter) {
+                Token::Byte(b'\n') if quoting != Quoting::Quoted => {
+                    quoting = Quoting::FieldStart;
+                    true
+                }
+                _ => false,
+            }
+        })?;
+
+        if quoting == Quoting::Quoted {
+            return Err(FileDatasetError::InvalidRecord {
+                index: records.len().saturating_sub(1),
+                message: "unclosed quoted field".to_string(),
+            });
+        }
+
+        let header = match records.get(0) {
+            Some(header) => split_csv_record(&header, self.delimiter)
+                .map_err(|message| FileDatasetError::InvalidRecord { index: 0, message })?,
+            None => Vec::new(),
+        };
+
+        let columns = match self.columns {
+            Some(columns) => columns
+                .into_iter()
+                .map(
+                    |column| match header.iter().position(|name| *name == column) {
+                        Some(index) => Ok((index, column)),
+                        None => Err(FileDatasetError::MissingColumn(column)),
+                    },
+                )
+                .collect::<Result<_, _>>()?,
+            None => header.into_iter().enumerate().collect(),
+        };
+
+        Ok(CsvDataset {
+            records,
+            delimiter: self.delimiter,
+            columns,
+            input: PhantomData,
+        })
+    }
+
+    /// Load the dataset, deserializing all the records in memory.
+    pub fn load_in_memory<I>(self) -> Result<InMemDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Clone + Send + Sync,
+    {
+        let dataset = self.load()?;
+        let items = (0..dataset.len())
+            .map(|index| dataset.try_get(index).unwrap())
+            .collect::<Result<_, _>>()?;
+
+        Ok(InMemDataset::new(items))
+    }
+}
+
+/// Dataset reading the records of a CSV file when they are accessed.
+///
+/// # Panics
+///
+/// When accessing a record that can't be deserialized, use
+/// [load_in_memory](CsvDatasetLoader::load_in_memory) to validate all the records upfront.
+pub struct CsvDataset<I> {
+    records: FileRecords,
+    delimiter: u8,
+    columns: Vec<(usize, String)>,
+    input: PhantomData<I>,
+}
+
+impl<I: DeserializeOwned> CsvDataset<I> {
+    /// Deserialize the item at the given index, skipping the header.
+    fn try_get(&self, index: usize) -> Option<Result<I, FileDatasetError>> {
+        let record = self.records.get(index + 1)?;
+        let invalid = |message| FileDatasetError::InvalidRecord { index, message };
+
+        let fields = match split_csv_record(&record, self.delimiter) {
+            Ok(fields) => fields,
+            Err(message) => return Some(Err(invalid(message))),
+        };
+
+        let mut values = Vec::with_capacity(self.columns.len());
+        for (position, column) in self.columns.iter() {
+            match fields.get(*position) {
+                Some(field) => values.push((column.as_str(), FieldDeserializer(field))),
+                None => return Some(Err(invalid(format!("missing field `{column}`")))),
+            }
+        }
+
+        let deserializer = MapDeserializer::<_, DeError>::new(values.into_iter());
+        Some(I::deserialize(deserializer).map_err(|err| invalid(err.to_string())))
+    }
+}
+
+impl<I> Dataset<I> for CsvDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.try_get(index).map(|item| item.unwrap())
+    }
+
+    fn len(&self) -> usize {
+        self.records.len().saturating_sub(1)
+    }
+}
+
+/// The quoting state while reading the bytes of records, used both to find where the records
+/// end and to [split them into fields](split_csv_record).
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Quoting {
+    FieldStart,
+    Unquoted,
+    Quoted,
+    QuoteInQuoted,
+}
+
+/// How a byte read with the [quoting](Quoting) contributes to the fields.
+enum Token {
+    Byte(u8),
+    Delimiter,
+    Quote,
+}
+
+impl Quoting {
+    fn read(&mut self, byte: u8, delimiter: u8) -> Token {
+        let (quoting, token) = match (*self, byte) {
+            (Quoting::FieldStart, b'"') => (Quoting::Quoted, Token::Quote),
+            (Quoting::Quoted, b'"') => (Quoting::QuoteInQuoted, Token::Quote),
+            (Quoting::Quoted, byte) => (Quoting::Quoted, Token::Byte(byte)),
+            (Quoting::QuoteInQuoted, b'"') => (Quoting::Quoted, Token::Byte(b'"')),
+            (_, byte) if byte == delimiter => (Quoting::FieldStart, Token::Delimiter),
+            (_, byte) => (Quoting::Unquoted, Token::Byte(byte)),
+        };
+
+        *self = quoting;
+        token
+    }
+}
+
+/// Split a CSV record into its fields, unquoting them.
+///
+/// A field is quoted when it starts with a quote, in which case it ends at the next quote that
+/// isn't doubled and can contain the delimiter. A quote anywhere else is part of the field.
+pub fn split_csv_record(record: &[u8], delimiter: u8) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = Vec::new();
+    let mut quoting = Quoting::FieldStart;
+
+    for byte in record {
+        match quoting.read(*byte, delimiter) {
+            Token::Byte(byte) => field.push(byte),
+            Token::Delimiter => fields.push(std::mem::take(&mut field)),
+            Token::Quote => {}
+        }
+    }
+    fields.push(field);
+
+    if quoting == Quoting::Quoted {
+        return Err("unclosed quoted field".to_string());
+    }
+
+    fields
+        .into_iter()
+        .map(|field| String::from_utf8(field).map_err(|err| err.to_string()))
+        .collect()
+}
+
+/// Quote a CSV field when it contains the delimiter, a quote or a line break, so that
+/// [split_csv_record] reads it back unchanged.
+pub fn escape_csv_field(field: &str, delimiter: u8) -> String {
+    let needs_quotes = field
+        .bytes()
+        .any(|byte| matches!(byte, b'"' | b'\n' | b'\r') || byte == delimiter);
+
+    match needs_quotes {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// Deserialize the text of a field into the type expected by the item.
+struct FieldDeserializer<'a>(&'a str);
+
+impl<'a> FieldDeserializer<'a> {
+    fn parse<T: std::str::FromStr>(&self) -> Result<T, DeError> {
+        self.0
+            .trim()
+            .parse()
+            .map_err(|_| DeError::custom(format!("invalid value `{}`", self.0)))
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for FieldDeserializer<'a> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.0.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    deserialize_parsed!(
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64
+    );
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de, 'a> IntoDeserializer<'de, DeError> for FieldDeserializer<'a> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::file::tests::{fixture, Item};
+
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+    struct Text {
+        text: String,
+    }
+
+    #[test]
+    fn csv_dataset_should_deserialize_records() {
+        let dataset = CsvDatasetLoader::new(fixture("items.csv"))
+            .load::<Item>()
+            .unwrap();
+
+        let items: Vec<Item> = dataset.iter().collect();
+
+        assert_eq!(items, Item::fixtures());
+    }
+
+    #[test]
+    fn csv_dataset_should_only_deserialize_selected_columns() {
+        let dataset = CsvDatasetLoader::new(fixture("items.csv"))
+            .columns(&["text"])
+            .load_in_memory::<Text>()
+            .unwrap();
+
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(
+            dataset.get(2),
+            Some(Text {
+                text: "multi\nline \"quoted\"".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn csv_dataset_should_fail_with_missing_column() {
+        let result = CsvDatasetLoader::new(fixture("items.csv"))
+            .columns(&["unknown"])
+            .load::<Text>();
+
+        assert!(matches!(result, Err(FileDatasetError::MissingColumn(_))));
+    }
+
+    #[test]
+    fn csv_dataset_should_keep_quotes_inside_unquoted_fields() {
+        let dataset = CsvDatasetLoader::new(fixture("quotes.csv"))
+            .load::<Text>()
+            .unwrap();
+
+        let items: Vec<Text> = dataset.iter().collect();
+
+        assert_eq!(
+            items,
+            vec![
+                Text {
+                    text: "5\" screen".to_string()
+                },
+                Text {
+                    text: "quoted, \"twice\"".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn split_csv_record_should_split_quoted_fields() {
+        let fields = split_csv_record(b"a;\"b;c\";\"d \"\"e\"\"\";", b';').unwrap();
+
+        assert_eq!(fields, vec!["a", "b;c", "d \"e\"", ""]);
+    }
+
+    #[test]
+    fn split_csv_record_should_keep_quotes_inside_unquoted_fields() {
+        let fields = split_csv_record(b"a\"b,\"c\"d,\"\"", b',').unwrap();
+
+        assert_eq!(fields, vec!["a\"b", "cd", ""]);
+    }
+
+    #[test]
+    fn escape_csv_field_should_be_split_back_unchanged() {
+        let fields = [
+            "plain",
+            "a;b",
+            "\"quoted\"",
+            "5\" screen",
+            "multi\nline",
+            "",
+        ];
+
+        let record = fields
+            .iter()
+            .map(|field| escape_csv_field(field, b';'))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        assert_eq!(escape_csv_field("a,b", b';'), "a,b");
+        assert_eq!(split_csv_record(record.as_bytes(), b';').unwrap(), fields);
+    }
+}
@@ -0,0 +1,163 @@
+use super::batches::{column_indexes, BatchReader, BatchedRows};
+use super::FileDatasetError;
+use crate::{Dataset, InMemDataset};
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::FileReader;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/// Load a dataset from an Arrow IPC file, also known as a Feather file.
+///
+/// Each row is deserialized into an item with its fields named after the columns. Null values
+/// are deserialized as `None` for optional values.
+pub struct ArrowDatasetLoader {
+    path: PathBuf,
+    columns: Option<Vec<String>>,
+}
+
+impl ArrowDatasetLoader {
+    /// Create an Arrow dataset loader for the given file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            columns: None,
+        }
+    }
+
+    /// Only read the given columns, instead of all of them.
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = Some(columns.iter().map(|column| column.to_string()).collect());
+        self
+    }
+
+    /// Load the dataset, reading each record batch from the file when one of its rows is
+    /// accessed.
+    ///
+    /// The record batches are read without their columns to find their number of rows.
+    pub fn load<I>(self) -> Result<ArrowDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Send + Sync,
+    {
+        let sizes = FileReader::try_new(File::open(&self.path)?, Some(Vec::new()))?;
+        let projection = match &self.columns {
+            Some(columns) => Some(column_indexes(&sizes.schema(), columns)?),
+            None => None,
+        };
+        let sizes = sizes
+            .map(|batch| batch.map(|batch| batch.num_rows()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let reader = FileReader::try_new(File::open(&self.path)?, projection)?;
+
+        Ok(ArrowDataset {
+            rows: BatchedRows::new(reader, sizes),
+            input: PhantomData,
+        })
+    }
+
+    /// Load the dataset, deserializing all the rows in memory.
+    pub fn load_in_memory<I>(self) -> Result<InMemDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Clone + Send + Sync,
+    {
+        let dataset = self.load::<I>()?;
+        let items = (0..dataset.len())
+            .map(|index| dataset.rows.deserialize(index).unwrap())
+            .collect::<Result<_, _>>()?;
+
+        Ok(InMemDataset::new(items))
+    }
+}
+
+/// Dataset reading the rows of an Arrow IPC file when they are accessed.
+///
+/// # Panics
+///
+/// When accessing a row that can't be read or deserialized, use
+/// [load_in_memory](ArrowDatasetLoader::load_in_memory) to validate all the rows upfront.
+pub struct ArrowDataset<I> {
+    rows: BatchedRows<FileReader<File>>,
+    input: PhantomData<I>,
+}
+
+impl BatchReader for FileReader<File> {
+    fn read(&mut self, batch: usize) -> Result<Vec<RecordBatch>, FileDatasetError> {
+        self.set_index(batch)?;
+        Ok(self.next().transpose()?.into_iter().collect())
+    }
+}
+
+impl<I> Dataset<I> for ArrowDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.rows.deserialize(index).map(|item| item.unwrap())
+    }
+
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::file::tests::{fixture, Item};
+
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+    struct Label {
+        id: usize,
+        label: Option<u32>,
+    }
+
+    #[test]
+    fn arrow_dataset_should_deserialize_rows_of_all_batches() {
+        let dataset = ArrowDatasetLoader::new(fixture("items.arrow"))
+            .load::<Item>()
+            .unwrap();
+
+        let items: Vec<Item> = dataset.iter().collect();
+
+        assert_eq!(items, Item::fixtures());
+    }
+
+    #[test]
+    fn arrow_dataset_should_only_read_selected_columns() {
+        let dataset = ArrowDatasetLoader::new(fixture("items.arrow"))
+            .columns(&["label", "id"])
+            .load_in_memory::<serde_json::Value>()
+            .unwrap();
+
+        let items: Vec<Label> = dataset
+            .iter()
+            .map(|item| serde_json::from_value(item).unwrap())
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                Label { id: 1, label: None },
+                Label {
+                    id: 2,
+                    label: Some(3)
+                },
+                Label {
+                    id: 3,
+                    label: Some(7)
+                }
+            ]
+        );
+        assert_eq!(dataset.get(1).unwrap().as_object().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn arrow_dataset_should_fail_with_missing_column() {
+        let result = ArrowDatasetLoader::new(fixture("items.arrow"))
+            .columns(&["unknown"])
+            .load::<Label>();
+
+        assert!(matches!(result, Err(FileDatasetError::MissingColumn(_))));
+    }
+}
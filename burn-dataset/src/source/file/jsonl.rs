@@ -0,0 +1,153 @@
+use super::records::FileRecords;
+use super::FileDatasetError;
+use crate::{Dataset, InMemDataset};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/// Load a dataset from a JSON Lines file, where each non-empty line is a JSON object
+/// deserialized into an item.
+pub struct JsonlDatasetLoader {
+    path: PathBuf,
+    columns: Option<Vec<String>>,
+}
+
+impl JsonlDatasetLoader {
+    /// Create a JSON Lines dataset loader for the given file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            columns: None,
+        }
+    }
+
+    /// Only deserialize the given fields of the objects, instead of all of them.
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = Some(columns.iter().map(|column| column.to_string()).collect());
+        self
+    }
+
+    /// Load the dataset, reading each line from the file when it is accessed.
+    pub fn load<I>(self) -> Result<JsonlDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Send + Sync,
+    {
+        let records = FileRecords::open(&self.path, |byte| byte == b'\n')?;
+
+        Ok(JsonlDataset {
+            records,
+            columns: self.columns,
+            input: PhantomData,
+        })
+    }
+
+    /// Load the dataset, deserializing all the lines in memory.
+    pub fn load_in_memory<I>(self) -> Result<InMemDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Clone + Send + Sync,
+    {
+        let dataset = self.load()?;
+        let items = (0..dataset.len())
+            .map(|index| dataset.try_get(index).unwrap())
+            .collect::<Result<_, _>>()?;
+
+        Ok(InMemDataset::new(items))
+    }
+}
+
+/// Dataset reading the lines of a JSON Lines file when they are accessed.
+///
+/// # Panics
+///
+/// When accessing a line that can't be deserialized, use
+/// [load_in_memory](JsonlDatasetLoader::load_in_memory) to validate all the lines upfront.
+pub struct JsonlDataset<I> {
+    records: FileRecords,
+    columns: Option<Vec<String>>,
+    input: PhantomData<I>,
+}
+
+impl<I: DeserializeOwned> JsonlDataset<I> {
+    fn try_get(&self, index: usize) -> Option<Result<I, FileDatasetError>> {
+        let record = self.records.get(index)?;
+        let invalid = |err: serde_json::Error| FileDatasetError::InvalidRecord {
+            index,
+            message: err.to_string(),
+        };
+
+        let columns = match &self.columns {
+            Some(columns) => columns,
+            None => return Some(serde_json::from_slice(&record).map_err(invalid)),
+        };
+
+        let item = serde_json::from_slice::<Map<String, Value>>(&record).and_then(|mut object| {
+            let object: Map<String, Value> = columns
+                .iter()
+                .filter_map(|column| object.remove_entry(column))
+                .collect();
+            serde_json::from_value(Value::Object(object))
+        });
+
+        Some(item.map_err(invalid))
+    }
+}
+
+impl<I> Dataset<I> for JsonlDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.try_get(index).map(|item| item.unwrap())
+    }
+
+    fn len(&self) -> usize {
+        self.records.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::file::tests::{fixture, Item};
+
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+    struct Score {
+        id: usize,
+        score: f64,
+    }
+
+    #[test]
+    fn jsonl_dataset_should_deserialize_lines() {
+        let dataset = JsonlDatasetLoader::new(fixture("items.jsonl"))
+            .load::<Item>()
+            .unwrap();
+
+        let items: Vec<Item> = dataset.iter().collect();
+
+        assert_eq!(items, Item::fixtures());
+    }
+
+    #[test]
+    fn jsonl_dataset_should_only_deserialize_selected_columns() {
+        let dataset = JsonlDatasetLoader::new(fixture("items.jsonl"))
+            .columns(&["id", "score"])
+            .load_in_memory::<Value>()
+            .unwrap();
+
+        let items: Vec<Score> = dataset
+            .iter()
+            .map(|item| serde_json::from_value(item).unwrap())
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                Score { id: 1, score: 0.5 },
+                Score { id: 2, score: 1.5 },
+                Score { id: 3, score: -2.0 }
+            ]
+        );
+        assert_eq!(dataset.get(0).unwrap().as_object().unwrap().len(), 2);
+    }
+}
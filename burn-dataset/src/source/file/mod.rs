@@ -0,0 +1,74 @@
+mod csv;
+mod jsonl;
+mod records;
+
+#[cfg(feature = "arrow")]
+mod batches;
+#[cfg(feature = "arrow")]
+mod ipc;
+#[cfg(feature = "parquet")]
+mod parquet;
+#[cfg(feature = "arrow")]
+mod row;
+
+pub use self::csv::*;
+#[cfg(feature = "parquet")]
+pub use self::parquet::*;
+#[cfg(feature = "arrow")]
+pub use ipc::*;
+pub use jsonl::*;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FileDatasetError {
+    #[error("io: `{0}`")]
+    Io(#[from] std::io::Error),
+    #[error("invalid record {index}: `{message}`")]
+    InvalidRecord { index: usize, message: String },
+    #[error("missing column: `{0}`")]
+    MissingColumn(String),
+    #[cfg(feature = "arrow")]
+    #[error("arrow: `{0}`")]
+    Arrow(#[from] arrow_schema::ArrowError),
+    #[cfg(feature = "parquet")]
+    #[error("parquet: `{0}`")]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+    pub struct Item {
+        pub id: usize,
+        pub text: String,
+        pub score: f64,
+        pub label: Option<u32>,
+    }
+
+    impl Item {
+        /// The items of the fixture files.
+        pub fn fixtures() -> Vec<Item> {
+            let item = |id, text: &str, score, label| Item {
+                id,
+                text: text.to_string(),
+                score,
+                label,
+            };
+
+            vec![
+                item(1, "hello", 0.5, None),
+                item(2, "with, comma", 1.5, Some(3)),
+                item(3, "multi\nline \"quoted\"", -2.0, Some(7)),
+            ]
+        }
+    }
+
+    pub fn fixture(name: &str) -> PathBuf {
+        [env!("CARGO_MANIFEST_DIR"), "tests", "data", name]
+            .iter()
+            .collect()
+    }
+}
@@ -0,0 +1,180 @@
+use super::batches::{column_indexes, BatchReader, BatchedRows};
+use super::FileDatasetError;
+use crate::{Dataset, InMemDataset};
+use arrow_array::RecordBatch;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+};
+use parquet::arrow::ProjectionMask;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/// Load a dataset from a Parquet file.
+///
+/// Each row is deserialized into an item with its fields named after the columns. Null values
+/// are deserialized as `None` for optional values.
+///
+/// Compressed files require the feature of their codec, such as `parquet-snappy` or
+/// `parquet-zstd`.
+pub struct ParquetDatasetLoader {
+    path: PathBuf,
+    columns: Option<Vec<String>>,
+}
+
+impl ParquetDatasetLoader {
+    /// Create a Parquet dataset loader for the given file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            columns: None,
+        }
+    }
+
+    /// Only read the given columns, instead of all of them.
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = Some(columns.iter().map(|column| column.to_string()).collect());
+        self
+    }
+
+    /// Load the dataset, reading each row group from the file when one of its rows is
+    /// accessed.
+    pub fn load<I>(self) -> Result<ParquetDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Send + Sync,
+    {
+        let file = File::open(&self.path)?;
+        let metadata = ArrowReaderMetadata::load(&file, ArrowReaderOptions::default())?;
+        let projection = match &self.columns {
+            Some(columns) => ProjectionMask::roots(
+                metadata.parquet_schema(),
+                column_indexes(metadata.schema(), columns)?,
+            ),
+            None => ProjectionMask::all(),
+        };
+        let sizes: Vec<usize> = metadata
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.num_rows() as usize)
+            .collect();
+        let row_groups = RowGroups {
+            file,
+            metadata,
+            projection,
+        };
+
+        Ok(ParquetDataset {
+            rows: BatchedRows::new(row_groups, sizes),
+            input: PhantomData,
+        })
+    }
+
+    /// Load the dataset, deserializing all the rows in memory.
+    pub fn load_in_memory<I>(self) -> Result<InMemDataset<I>, FileDatasetError>
+    where
+        I: DeserializeOwned + Clone + Send + Sync,
+    {
+        let dataset = self.load::<I>()?;
+        let items = (0..dataset.len())
+            .map(|index| dataset.rows.deserialize(index).unwrap())
+            .collect::<Result<_, _>>()?;
+
+        Ok(InMemDataset::new(items))
+    }
+}
+
+/// Dataset reading the rows of a Parquet file when they are accessed.
+///
+/// # Panics
+///
+/// When accessing a row that can't be read or deserialized, use
+/// [load_in_memory](ParquetDatasetLoader::load_in_memory) to validate all the rows upfront.
+pub struct ParquetDataset<I> {
+    rows: BatchedRows<RowGroups>,
+    input: PhantomData<I>,
+}
+
+/// The row groups of a Parquet file, with only the selected columns.
+struct RowGroups {
+    file: File,
+    metadata: ArrowReaderMetadata,
+    projection: ProjectionMask,
+}
+
+impl BatchReader for RowGroups {
+    fn read(&mut self, batch: usize) -> Result<Vec<RecordBatch>, FileDatasetError> {
+        let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(
+            self.file.try_clone()?,
+            self.metadata.clone(),
+        )
+        .with_row_groups(vec![batch])
+        .with_projection(self.projection.clone())
+        .build()?;
+
+        Ok(reader.collect::<Result<_, _>>()?)
+    }
+}
+
+impl<I> Dataset<I> for ParquetDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.rows.deserialize(index).map(|item| item.unwrap())
+    }
+
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::file::tests::{fixture, Item};
+
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+    struct Text {
+        text: String,
+    }
+
+    #[test]
+    fn parquet_dataset_should_deserialize_rows_of_all_row_groups() {
+        let dataset = ParquetDatasetLoader::new(fixture("items.parquet"))
+            .load::<Item>()
+            .unwrap();
+
+        let items: Vec<Item> = dataset.iter().collect();
+
+        assert_eq!(items, Item::fixtures());
+        assert_eq!(dataset.get(0), Some(Item::fixtures()[0].clone()));
+    }
+
+    #[test]
+    fn parquet_dataset_should_only_read_selected_columns() {
+        let dataset = ParquetDatasetLoader::new(fixture("items.parquet"))
+            .columns(&["text"])
+            .load_in_memory::<serde_json::Value>()
+            .unwrap();
+
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(dataset.get(0).unwrap().as_object().unwrap().len(), 1);
+        assert_eq!(
+            serde_json::from_value::<Text>(dataset.get(2).unwrap()).unwrap(),
+            Text {
+                text: "multi\nline \"quoted\"".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parquet_dataset_should_fail_with_missing_column() {
+        let result = ParquetDatasetLoader::new(fixture("items.parquet"))
+            .columns(&["unknown"])
+            .load::<Text>();
+
+        assert!(matches!(result, Err(FileDatasetError::MissingColumn(_))));
+    }
+}
@@ -0,0 +1,74 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Records of a file read lazily from their positions, found when the file is opened.
+pub(crate) struct FileRecords {
+    file: Mutex<File>,
+    positions: Vec<(u64, u64)>,
+}
+
+impl FileRecords {
+    /// Open the file and find the positions of its records, where `splitter` tells if the
+    /// record being read ends with the given byte.
+    pub(crate) fn open<P, S>(path: P, mut splitter: S) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+        S: FnMut(u8) -> bool,
+    {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut positions = Vec::new();
+        let mut start = 0;
+        let mut position = 0;
+        let mut previous = 0;
+
+        loop {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() {
+                break;
+            }
+
+            for byte in buffer {
+                position += 1;
+                if splitter(*byte) {
+                    let end = match previous {
+                        b'\r' => position - 2,
+                        _ => position - 1,
+                    };
+                    positions.push((start, end));
+                    start = position;
+                }
+                previous = *byte;
+            }
+
+            let len = buffer.len();
+            reader.consume(len);
+        }
+        positions.push((start, position));
+
+        // Empty lines, including the one after the last line break, are not records.
+        positions.retain(|(start, end)| end > start);
+
+        Ok(Self {
+            file: Mutex::new(reader.into_inner()),
+            positions,
+        })
+    }
+
+    /// Read the bytes of a record, without its line break.
+    pub(crate) fn get(&self, index: usize) -> Option<Vec<u8>> {
+        let (start, end) = *self.positions.get(index)?;
+        let mut bytes = vec![0; (end - start) as usize];
+        let mut file = self.file.lock().unwrap();
+
+        file.seek(SeekFrom::Start(start)).unwrap();
+        file.read_exact(&mut bytes).unwrap();
+
+        Some(bytes)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.positions.len()
+    }
+}
@@ -0,0 +1,331 @@
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    ArrowDictionaryKeyType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Fields};
+use serde::de::value::{Error, StrDeserializer};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+/// Deserialize a row of a record batch as a map from the names of its columns to their values,
+/// straight from the arrays of the columns.
+///
+/// Null values are deserialized as `None`, lists as sequences and structs as maps. Boolean,
+/// numeric, string, binary and dictionary columns are supported.
+pub(crate) struct RowDeserializer<'a> {
+    columns: Columns<'a>,
+}
+
+impl<'a> RowDeserializer<'a> {
+    pub(crate) fn new(batch: &'a RecordBatch, row: usize) -> Self {
+        Self {
+            columns: Columns::new(batch.schema_ref().fields(), batch.columns(), row),
+        }
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for RowDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(self.columns)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+/// The values of a row in each column, in the order of the fields.
+struct Columns<'a> {
+    fields: &'a Fields,
+    columns: &'a [ArrayRef],
+    row: usize,
+    index: usize,
+}
+
+impl<'a> Columns<'a> {
+    fn new(fields: &'a Fields, columns: &'a [ArrayRef], row: usize) -> Self {
+        Self {
+            fields,
+            columns,
+            row,
+            index: 0,
+        }
+    }
+}
+
+impl<'de, 'a> MapAccess<'de> for Columns<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let field = match self.fields.get(self.index) {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        let name: StrDeserializer<Error> = field.name().as_str().into_deserializer();
+
+        seed.deserialize(name).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = ValueDeserializer::new(self.columns[self.index].as_ref(), self.row);
+        self.index += 1;
+
+        seed.deserialize(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.index)
+    }
+}
+
+/// The values of a list.
+struct Values {
+    array: ArrayRef,
+    index: usize,
+}
+
+impl<'de> SeqAccess<'de> for Values {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.index >= self.array.len() {
+            return Ok(None);
+        }
+
+        let value = ValueDeserializer::new(self.array.as_ref(), self.index);
+        self.index += 1;
+
+        seed.deserialize(value).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.array.len() - self.index)
+    }
+}
+
+/// Deserialize the value of an array at a given row.
+#[derive(new)]
+struct ValueDeserializer<'a> {
+    array: &'a dyn Array,
+    row: usize,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    /// Look up the value of a dictionary array in the array of its values.
+    fn resolve(self) -> Result<Self, Error> {
+        let key = match self.array.data_type() {
+            DataType::Dictionary(key, _) if self.array.is_valid(self.row) => key,
+            _ => return Ok(self),
+        };
+        let (array, row) = (self.array, self.row);
+        let (values, row) = match key.as_ref() {
+            DataType::Int8 => dictionary_value::<Int8Type>(array, row),
+            DataType::Int16 => dictionary_value::<Int16Type>(array, row),
+            DataType::Int32 => dictionary_value::<Int32Type>(array, row),
+            DataType::Int64 => dictionary_value::<Int64Type>(array, row),
+            DataType::UInt8 => dictionary_value::<UInt8Type>(array, row),
+            DataType::UInt16 => dictionary_value::<UInt16Type>(array, row),
+            DataType::UInt32 => dictionary_value::<UInt32Type>(array, row),
+            DataType::UInt64 => dictionary_value::<UInt64Type>(array, row),
+            data_type => return Err(unsupported(data_type)),
+        };
+
+        Ok(Self::new(values.as_ref(), row))
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for ValueDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let ValueDeserializer { array, row } = self.resolve()?;
+
+        if array.is_null(row) {
+            return visitor.visit_none();
+        }
+
+        match array.data_type() {
+            DataType::Null => visitor.visit_none(),
+            DataType::Boolean => visitor.visit_bool(array.as_boolean().value(row)),
+            DataType::Int8 => visitor.visit_i8(array.as_primitive::<Int8Type>().value(row)),
+            DataType::Int16 => visitor.visit_i16(array.as_primitive::<Int16Type>().value(row)),
+            DataType::Int32 => visitor.visit_i32(array.as_primitive::<Int32Type>().value(row)),
+            DataType::Int64 => visitor.visit_i64(array.as_primitive::<Int64Type>().value(row)),
+            DataType::UInt8 => visitor.visit_u8(array.as_primitive::<UInt8Type>().value(row)),
+            DataType::UInt16 => visitor.visit_u16(array.as_primitive::<UInt16Type>().value(row)),
+            DataType::UInt32 => visitor.visit_u32(array.as_primitive::<UInt32Type>().value(row)),
+            DataType::UInt64 => visitor.visit_u64(array.as_primitive::<UInt64Type>().value(row)),
+            DataType::Float32 => visitor.visit_f32(array.as_primitive::<Float32Type>().value(row)),
+            DataType::Float64 => visitor.visit_f64(array.as_primitive::<Float64Type>().value(row)),
+            DataType::Utf8 => visitor.visit_str(array.as_string::<i32>().value(row)),
+            DataType::LargeUtf8 => visitor.visit_str(array.as_string::<i64>().value(row)),
+            DataType::Binary => visitor.visit_bytes(array.as_binary::<i32>().value(row)),
+            DataType::LargeBinary => visitor.visit_bytes(array.as_binary::<i64>().value(row)),
+            DataType::List(_) => visitor.visit_seq(Values {
+                array: array.as_list::<i32>().value(row),
+                index: 0,
+            }),
+            DataType::LargeList(_) => visitor.visit_seq(Values {
+                array: array.as_list::<i64>().value(row),
+                index: 0,
+            }),
+            DataType::FixedSizeList(_, _) => visitor.visit_seq(Values {
+                array: array.as_fixed_size_list().value(row),
+                index: 0,
+            }),
+            DataType::Struct(fields) => {
+                visitor.visit_map(Columns::new(fields, array.as_struct().columns(), row))
+            }
+            data_type => Err(unsupported(data_type)),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.array.is_null(self.row) {
+            return visitor.visit_none();
+        }
+
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let value = self.resolve()?;
+        let (array, row) = (value.array, value.row);
+        let variant = match array.data_type() {
+            DataType::Utf8 if array.is_valid(row) => array.as_string::<i32>().value(row),
+            DataType::LargeUtf8 if array.is_valid(row) => array.as_string::<i64>().value(row),
+            _ => return value.deserialize_any(visitor),
+        };
+        let variant: StrDeserializer<Error> = variant.into_deserializer();
+
+        visitor.visit_enum(variant)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// The values of a dictionary array with the index of the value at the given row.
+fn dictionary_value<K: ArrowDictionaryKeyType>(
+    array: &dyn Array,
+    row: usize,
+) -> (&ArrayRef, usize) {
+    let array = array.as_dictionary::<K>();
+
+    (array.values(), array.key(row).unwrap_or_default())
+}
+
+fn unsupported(data_type: &DataType) -> Error {
+    de::Error::custom(format!("unsupported data type `{data_type}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, StringDictionaryBuilder, UInt32Builder};
+    use arrow_array::{BooleanArray, StructArray};
+    use arrow_schema::{Field, Schema};
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Split {
+        Train,
+        Valid,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Point {
+        x: u32,
+        visible: Option<bool>,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        tokens: Option<Vec<u32>>,
+        split: Split,
+        point: Point,
+    }
+
+    #[test]
+    fn row_deserializer_should_deserialize_nested_columns() {
+        let mut tokens = ListBuilder::new(UInt32Builder::new());
+        tokens.append_value([Some(1), Some(2)]);
+        tokens.append_null();
+        let mut split = StringDictionaryBuilder::<Int8Type>::new();
+        split.append_value("valid");
+        split.append_value("train");
+        let point = StructArray::from(vec![
+            (
+                Arc::new(Field::new("x", DataType::UInt32, false)),
+                Arc::new(arrow_array::UInt32Array::from(vec![3, 4])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("visible", DataType::Boolean, true)),
+                Arc::new(BooleanArray::from(vec![Some(true), None])) as ArrayRef,
+            ),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(tokens.finish()),
+            Arc::new(split.finish()),
+            Arc::new(point),
+        ];
+        let schema = Schema::new(vec![
+            Field::new("tokens", columns[0].data_type().clone(), true),
+            Field::new("split", columns[1].data_type().clone(), false),
+            Field::new("point", columns[2].data_type().clone(), false),
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(schema), columns).unwrap();
+
+        let items = (0..batch.num_rows())
+            .map(|row| Item::deserialize(RowDeserializer::new(&batch, row)).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            items,
+            vec![
+                Item {
+                    tokens: Some(vec![1, 2]),
+                    split: Split::Valid,
+                    point: Point {
+                        x: 3,
+                        visible: Some(true)
+                    },
+                },
+                Item {
+                    tokens: None,
+                    split: Split::Train,
+                    point: Point {
+                        x: 4,
+                        visible: None
+                    },
+                },
+            ]
+        );
+    }
+}
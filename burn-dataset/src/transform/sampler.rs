@@ -1,5 +1,8 @@
 use crate::Dataset;
-use rand::{distributions::Uniform, rngs::StdRng, Rng, SeedableRng};
+use rand::distributions::{Uniform, WeightedIndex};
+use rand::seq::{index::sample_weighted, SliceRandom};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashMap;
 use std::{marker::PhantomData, sync::Mutex};
 
 /// Sample items from a dataset with replacement.
@@ -47,3 +50,277 @@ where
         self.size
     }
 }
+
+/// Select the indexes of the items of a dataset to iterate over during an epoch.
+pub trait Sampler: Send + Sync {
+    /// The indexes of the items, in order, for a dataset of size `len` at the given epoch.
+    fn indexes(&self, len: usize, epoch: usize) -> Vec<usize>;
+}
+
+/// The random number generator of an epoch, so that each epoch has different samples that are
+/// still reproducible from the seed.
+fn epoch_rng(seed: u64, epoch: usize) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (epoch as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Iterate over the items in order.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SequentialSampler;
+
+impl Sampler for SequentialSampler {
+    fn indexes(&self, len: usize, _epoch: usize) -> Vec<usize> {
+        (0..len).collect()
+    }
+}
+
+/// Iterate over the items in a random order, shuffled differently at each epoch.
+#[derive(new, Clone, Copy, Debug)]
+pub struct RandomSampler {
+    seed: u64,
+}
+
+impl Sampler for RandomSampler {
+    fn indexes(&self, len: usize, epoch: usize) -> Vec<usize> {
+        let mut indexes: Vec<usize> = (0..len).collect();
+        indexes.shuffle(&mut epoch_rng(self.seed, epoch));
+        indexes
+    }
+}
+
+/// Sample the items randomly with probabilities proportional to their weights.
+#[derive(Clone, Debug)]
+pub struct WeightedRandomSampler {
+    weights: Vec<f64>,
+    num_samples: usize,
+    replacement: bool,
+    seed: u64,
+}
+
+impl WeightedRandomSampler {
+    /// Create a sampler drawing `num_samples` items at each epoch, with one weight per item.
+    ///
+    /// # Panics
+    ///
+    /// When a weight is negative, infinite or NaN, when no weight is positive, or when sampling
+    /// without replacement more items than there are positive weights.
+    pub fn new(weights: Vec<f64>, num_samples: usize, replacement: bool, seed: u64) -> Self {
+        assert!(
+            weights
+                .iter()
+                .all(|weight| weight.is_finite() && *weight >= 0.0),
+            "The weights should be finite and non-negative"
+        );
+        let num_positive = weights.iter().filter(|weight| **weight > 0.0).count();
+        assert!(num_positive > 0, "At least one weight should be positive");
+        if !replacement && num_samples > num_positive {
+            panic!(
+                "Can't sample {num_samples} items without replacement among {num_positive} items \
+                 with a positive weight"
+            );
+        }
+
+        Self {
+            weights,
+            num_samples,
+            replacement,
+            seed,
+        }
+    }
+
+    /// Create a sampler drawing `num_samples` items with replacement, so that each class is
+    /// sampled with the same probability whatever its number of items.
+    ///
+    /// The labels are the classes of the items of the dataset, in order.
+    ///
+    /// # Panics
+    ///
+    /// When there are no labels.
+    pub fn class_balanced(labels: &[usize], num_samples: usize, seed: u64) -> Self {
+        let mut counts = HashMap::new();
+        for label in labels {
+            *counts.entry(*label).or_insert(0usize) += 1;
+        }
+
+        let weights = labels
+            .iter()
+            .map(|label| 1.0 / counts[label] as f64)
+            .collect();
+
+        Self::new(weights, num_samples, true, seed)
+    }
+}
+
+impl Sampler for WeightedRandomSampler {
+    fn indexes(&self, len: usize, epoch: usize) -> Vec<usize> {
+        assert_eq!(
+            self.weights.len(),
+            len,
+            "The number of weights should match the number of items"
+        );
+        let mut rng = epoch_rng(self.seed, epoch);
+
+        if self.replacement {
+            let distribution = WeightedIndex::new(&self.weights).unwrap();
+            return (0..self.num_samples)
+                .map(|_| rng.sample(&distribution))
+                .collect();
+        }
+
+        sample_weighted(&mut rng, len, |i| self.weights[i], self.num_samples)
+            .unwrap()
+            .into_vec()
+    }
+}
+
+/// Keep only the shard of a data-parallel process from the indexes of another sampler.
+///
+/// The indexes are split in `world_size` contiguous shards of the same size, the remaining
+/// ones being dropped, so that all processes iterate over the same number of items. The
+/// inner sampler must produce the same indexes in all processes, e.g. with the same seed.
+pub struct DistributedSampler {
+    sampler: Box<dyn Sampler>,
+    rank: usize,
+    world_size: usize,
+}
+
+impl DistributedSampler {
+    /// Create the sampler of the process `rank`, between `0` and `world_size` excluded.
+    pub fn new<S: Sampler + 'static>(sampler: S, rank: usize, world_size: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank {rank} should be lower than the world size {world_size}"
+        );
+
+        Self {
+            sampler: Box::new(sampler),
+            rank,
+            world_size,
+        }
+    }
+}
+
+impl Sampler for DistributedSampler {
+    fn indexes(&self, len: usize, epoch: usize) -> Vec<usize> {
+        let mut indexes = self.sampler.indexes(len, epoch);
+        let shard_size = indexes.len() / self.world_size;
+        let start = self.rank * shard_size;
+
+        indexes.truncate(start + shard_size);
+        indexes.drain(..start);
+        indexes
+    }
+}
+
+/// Dataset of the items selected by a [sampler](Sampler) at a given epoch.
+pub struct IndexSampledDataset<D, I> {
+    dataset: D,
+    indexes: Vec<usize>,
+    input: PhantomData<I>,
+}
+
+impl<D, I> IndexSampledDataset<D, I>
+where
+    D: Dataset<I>,
+{
+    pub fn new<S: Sampler + ?Sized>(dataset: D, sampler: &S, epoch: usize) -> Self {
+        let indexes = sampler.indexes(dataset.len(), epoch);
+
+        Self {
+            dataset,
+            indexes,
+            input: PhantomData,
+        }
+    }
+}
+
+impl<D, I> Dataset<I> for IndexSampledDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        let index = self.indexes.get(index)?;
+        self.dataset.get(*index)
+    }
+
+    fn len(&self) -> usize {
+        self.indexes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn random_sampler_should_reshuffle_at_each_epoch() {
+        let sampler = RandomSampler::new(42);
+
+        let epoch_1 = sampler.indexes(100, 1);
+        let epoch_2 = sampler.indexes(100, 2);
+
+        assert_eq!(epoch_1, sampler.indexes(100, 1));
+        assert_ne!(epoch_1, epoch_2);
+        assert_eq!(
+            epoch_1.iter().collect::<HashSet<_>>(),
+            epoch_2.iter().collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn weighted_sampler_without_replacement_should_not_repeat_items() {
+        let sampler = WeightedRandomSampler::new(vec![1.0, 0.0, 5.0, 2.0, 1.0], 4, false, 42);
+
+        let indexes = sampler.indexes(5, 0);
+
+        assert_eq!(indexes.len(), 4);
+        assert_eq!(indexes.iter().collect::<HashSet<_>>().len(), 4);
+    }
+
+    #[test]
+    fn weighted_sampler_should_never_sample_items_with_zero_weight() {
+        let sampler = WeightedRandomSampler::new(vec![0.0, 1.0, 0.0, 1.0], 100, true, 42);
+
+        let indexes = sampler.indexes(4, 0);
+
+        assert!(indexes.iter().all(|index| *index == 1 || *index == 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one weight should be positive")]
+    fn weighted_sampler_should_panic_when_all_weights_are_zero() {
+        WeightedRandomSampler::new(vec![0.0; 4], 2, true, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one weight should be positive")]
+    fn class_balanced_sampler_should_panic_without_labels() {
+        WeightedRandomSampler::class_balanced(&[], 2, 42);
+    }
+
+    #[test]
+    fn class_balanced_sampler_should_sample_classes_uniformly() {
+        let mut labels = vec![0; 90];
+        labels.extend([1; 10]);
+        let sampler = WeightedRandomSampler::class_balanced(&labels, 10_000, 42);
+
+        let indexes = sampler.indexes(labels.len(), 0);
+        let num_minority = indexes.iter().filter(|i| labels[**i] == 1).count();
+
+        assert!((4_500..5_500).contains(&num_minority), "{num_minority}");
+    }
+
+    #[test]
+    fn distributed_sampler_should_split_indexes_in_disjoint_shards() {
+        let shards: Vec<Vec<usize>> = (0..3)
+            .map(|rank| DistributedSampler::new(RandomSampler::new(42), rank, 3).indexes(10, 0))
+            .collect();
+
+        let items: HashSet<_> = shards.iter().flatten().collect();
+
+        assert!(shards.iter().all(|shard| shard.len() == 3));
+        assert_eq!(items.len(), 9);
+        assert_eq!(shards.concat(), RandomSampler::new(42).indexes(10, 0)[..9]);
+    }
+}
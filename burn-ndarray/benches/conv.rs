@@ -0,0 +1,141 @@
+//! Benchmarks of the convolution kernels.
+//!
+//! Run with `cargo bench -p burn-ndarray --bench conv --features bench`, the 2D convolutions are
+//! timed with both the direct loops and im2col regardless of the kernel selected by default.
+
+use std::time::{Duration, Instant};
+
+use burn_ndarray::{
+    bench::{conv2d_with_kernel, conv_transpose2d_with_kernel, ConvKernel},
+    NdArrayBackend,
+};
+use burn_tensor::{
+    backend::Backend,
+    ops::{ConvOptions, ConvTransposeOptions},
+    Distribution, Tensor,
+};
+
+type B = NdArrayBackend<f32>;
+
+const WARMUP: usize = 2;
+const ITERATIONS: usize = 10;
+
+fn bench<F: FnMut(ConvKernel)>(name: &str, mut func: F) {
+    for kernel in [ConvKernel::Direct, ConvKernel::Im2col] {
+        for _ in 0..WARMUP {
+            func(kernel);
+        }
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            func(kernel);
+        }
+        let elapsed: Duration = start.elapsed() / ITERATIONS as u32;
+
+        let name = format!("{name} ({kernel:?})");
+        println!("{name:<64} {:>10.3} ms", elapsed.as_secs_f64() * 1000.0);
+    }
+}
+
+fn random<const D: usize>(shape: [usize; D]) -> Tensor<B, D> {
+    Tensor::random(shape, Distribution::Standard)
+}
+
+fn bench_conv2d(name: &str, x: [usize; 4], weight: [usize; 4], options: ConvOptions<2>) {
+    let bias = random([weight[0]]).into_primitive();
+    let x = random(x).into_primitive();
+    let weight = random(weight).into_primitive();
+
+    bench(name, |kernel| {
+        conv2d_with_kernel(
+            x.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+            Some(kernel),
+        );
+    });
+}
+
+fn bench_conv_transpose2d(
+    name: &str,
+    x: [usize; 4],
+    weight: [usize; 4],
+    options: ConvTransposeOptions<2>,
+) {
+    let bias = random([weight[1] * options.groups]).into_primitive();
+    let x = random(x).into_primitive();
+    let weight = random(weight).into_primitive();
+
+    bench(name, |kernel| {
+        conv_transpose2d_with_kernel(
+            x.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+            Some(kernel),
+        );
+    });
+}
+
+fn main() {
+    B::seed(0);
+
+    bench_conv2d(
+        "conv2d 3x3 [8, 64, 56, 56] -> 64",
+        [8, 64, 56, 56],
+        [64, 64, 3, 3],
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+    );
+    bench_conv2d(
+        "conv2d 7x7 stride 2 [8, 3, 224, 224] -> 64",
+        [8, 3, 224, 224],
+        [64, 3, 7, 7],
+        ConvOptions::new([2, 2], [3, 3], [1, 1], 1),
+    );
+    bench_conv2d(
+        "conv2d 1x1 [8, 256, 28, 28] -> 128",
+        [8, 256, 28, 28],
+        [128, 256, 1, 1],
+        ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+    );
+    bench_conv2d(
+        "conv2d 3x3 groups 4 [8, 128, 28, 28] -> 128",
+        [8, 128, 28, 28],
+        [128, 32, 3, 3],
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 4),
+    );
+    bench_conv2d(
+        "conv2d 3x3 depthwise [8, 128, 28, 28]",
+        [8, 128, 28, 28],
+        [128, 1, 3, 3],
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 128),
+    );
+    bench_conv2d(
+        "conv2d 3x3 tiny [1, 2, 4, 4] -> 2",
+        [1, 2, 4, 4],
+        [2, 2, 3, 3],
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+    );
+
+    // The 1D convolutions are computed as 2D convolutions with a height of one.
+    bench_conv2d(
+        "conv1d k5 [8, 64, 1024] -> 128",
+        [8, 64, 1, 1024],
+        [128, 64, 1, 5],
+        ConvOptions::new([1, 1], [0, 2], [1, 1], 1),
+    );
+
+    bench_conv_transpose2d(
+        "conv_transpose2d 4x4 stride 2 [8, 128, 16, 16] -> 64",
+        [8, 128, 16, 16],
+        [128, 64, 4, 4],
+        ConvTransposeOptions::new([2, 2], [1, 1], [0, 0], [1, 1], 1),
+    );
+    bench_conv_transpose2d(
+        "conv_transpose2d 3x3 [8, 64, 28, 28] -> 64",
+        [8, 64, 28, 28],
+        [64, 64, 3, 3],
+        ConvTransposeOptions::new([1, 1], [1, 1], [0, 0], [1, 1], 1),
+    );
+}
@@ -18,6 +18,12 @@ mod sharing;
 mod tensor;
 
 pub use backend::*;
+/// The convolution kernels, called directly by the benchmarks comparing them.
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench {
+    pub use crate::ops::conv::{conv2d_with_kernel, conv_transpose2d_with_kernel, ConvKernel};
+}
 pub(crate) use sharing::*;
 pub(crate) use tensor::*;
 
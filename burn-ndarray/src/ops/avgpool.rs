@@ -1,10 +1,14 @@
 use crate::{
-    element::FloatNdArrayElement, iter_par, ops::padding::apply_padding_4d, run_par,
-    sharing::UnsafeSharedRef, tensor::NdArrayTensor,
+    element::FloatNdArrayElement,
+    iter_par,
+    ops::padding::{apply_padding_4d, pad},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
 };
 
 use burn_tensor::ElementConversion;
-use ndarray::Array4;
+use ndarray::{Array4, Array5, Ix5};
 
 pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
@@ -108,3 +112,141 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
+
+pub(crate) fn avg_pool3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> NdArrayTensor<E, 5> {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims;
+
+    let out_depth = ((x_depth + 2 * padding_depth - kernel_depth) / stride_depth) + 1;
+    let out_height = ((x_height + 2 * padding_height - kernel_height) / stride_height) + 1;
+    let out_width = ((x_width + 2 * padding_width - kernel_width) / stride_width) + 1;
+    let kernel_numel: E = ((kernel_depth * kernel_height * kernel_width) as i32).elem();
+
+    let padding = [
+        padding_width,
+        padding_width,
+        padding_height,
+        padding_height,
+        padding_depth,
+        padding_depth,
+    ];
+    let x = pad(x, &padding, "constant", None).array;
+    let x = x.into_dimensionality::<Ix5>().unwrap();
+
+    let mut output = Array5::from_elem(
+        (batch_size, channels, out_depth, out_height, out_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let mut sum_val: E = 0.elem();
+
+                        for kd in 0..kernel_depth {
+                            let id = od * stride_depth + kd;
+
+                            for kh in 0..kernel_height {
+                                let ih = oh * stride_height + kh;
+
+                                for kw in 0..kernel_width {
+                                    let iw = ow * stride_width + kw;
+
+                                    sum_val += x[[b, c, id, ih, iw]];
+                                }
+                            }
+                        }
+
+                        output[[b, c, od, oh, ow]] = sum_val / kernel_numel;
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn avg_pool3d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    grad: NdArrayTensor<E, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> NdArrayTensor<E, 5> {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims;
+    let [_batch_size, _channels, out_depth, out_height, out_width] = grad.shape().dims;
+    let kernel_numel: E = ((kernel_depth * kernel_height * kernel_width) as i32).elem();
+
+    let grad = grad.array.into_dimensionality::<Ix5>().unwrap();
+
+    let mut output_grad =
+        Array5::from_elem((batch_size, channels, x_depth, x_height, x_width), 0.elem());
+    let unsafe_shared_grad = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_grad.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let grad = grad[[b, c, od, oh, ow]] / kernel_numel;
+
+                        for kd in 0..kernel_depth {
+                            let id = od * stride_depth + kd;
+                            if id < padding_depth || id >= x_depth + padding_depth {
+                                continue;
+                            }
+
+                            for kh in 0..kernel_height {
+                                let ih = oh * stride_height + kh;
+                                if ih < padding_height || ih >= x_height + padding_height {
+                                    continue;
+                                }
+
+                                for kw in 0..kernel_width {
+                                    let iw = ow * stride_width + kw;
+                                    if iw < padding_width || iw >= x_width + padding_width {
+                                        continue;
+                                    }
+
+                                    output_grad[[
+                                        b,
+                                        c,
+                                        id - padding_depth,
+                                        ih - padding_height,
+                                        iw - padding_width,
+                                    ]] += grad;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
@@ -1,3 +1,4 @@
+use alloc::vec;
 use alloc::vec::Vec;
 use burn_tensor::Data;
 use burn_tensor::ElementConversion;
@@ -5,6 +6,7 @@ use core::cmp::Ordering;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
 use ndarray::Array2;
+use ndarray::{Array, Slice, Zip};
 
 use burn_tensor::Shape;
 use ndarray::Axis;
@@ -76,6 +78,176 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn narrow<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array;
+        array.slice_axis_inplace(Axis(dim), Slice::from(start..start + length));
+
+        NdArrayTensor { array }
+    }
+
+    pub fn expand<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        size: Vec<usize>,
+    ) -> NdArrayTensor<E, D> {
+        let shape = tensor.array.shape();
+        if size.len() != shape.len() {
+            panic!(
+                "Invalid expand: the number of sizes ({}) must match the number of dimensions ({})",
+                size.len(),
+                shape.len()
+            );
+        }
+
+        // `usize::MAX` plays the role of `-1` in torch, keeping the current size.
+        let size: Vec<usize> = size
+            .iter()
+            .zip(shape.iter())
+            .map(|(size, current)| match *size {
+                usize::MAX => *current,
+                size => size,
+            })
+            .collect();
+        let array = match tensor.array.broadcast(IxDyn(&size)) {
+            Some(array) => array.to_owned().into_shared(),
+            None => panic!("Can't expand tensor of shape {shape:?} to {size:?}"),
+        };
+
+        NdArrayTensor { array }
+    }
+
+    pub fn select<const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: i64,
+        index: i64,
+    ) -> NdArrayTensor<E, D2> {
+        let dim = wrap_index(dim, D);
+        let index = wrap_index(index, tensor.array.shape()[dim]);
+        let array = tensor.array.index_axis_move(Axis(dim), index);
+
+        NdArrayTensor { array }
+    }
+
+    pub fn flip<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dims: Vec<usize>,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array;
+        for dim in dims {
+            array.invert_axis(Axis(dim));
+        }
+
+        NdArrayTensor { array }
+    }
+
+    pub fn where_self<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        condition: NdArrayTensor<bool, D>,
+        other: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        let shape = broadcast_shape(&[
+            tensor.array.shape(),
+            condition.array.shape(),
+            other.array.shape(),
+        ]);
+        let shape = IxDyn(&shape);
+        let array = Zip::from(condition.array.broadcast(shape.clone()).unwrap())
+            .and(tensor.array.broadcast(shape.clone()).unwrap())
+            .and(other.array.broadcast(shape).unwrap())
+            .map_collect(|condition, a, b| if *condition { *a } else { *b })
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn index_tch<const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+        indices: Vec<NdArrayTensor<i64, D>>,
+    ) -> NdArrayTensor<E, D2> {
+        let shape_tensor = tensor.array.shape();
+        if indices.is_empty() || indices.len() > shape_tensor.len() {
+            panic!(
+                "Invalid number of index tensors ({}) for a tensor of shape {shape_tensor:?}",
+                indices.len()
+            );
+        }
+
+        // All index tensors are broadcasted together, the remaining dimensions are kept as is.
+        let shape_indices = broadcast_shape(
+            &indices
+                .iter()
+                .map(|index| index.array.shape())
+                .collect::<Vec<_>>(),
+        );
+        let indices: Vec<Vec<usize>> = indices
+            .iter()
+            .enumerate()
+            .map(|(dim, index)| {
+                index
+                    .array
+                    .broadcast(IxDyn(&shape_indices))
+                    .unwrap()
+                    .iter()
+                    .map(|index| wrap_index(*index, shape_tensor[dim]))
+                    .collect()
+            })
+            .collect();
+
+        let mut shape_output = shape_indices.clone();
+        shape_output.extend_from_slice(&shape_tensor[indices.len()..]);
+
+        let num_positions = shape_indices.iter().product::<usize>();
+        let mut values = Vec::with_capacity(shape_output.iter().product());
+        for position in 0..num_positions {
+            let mut view = tensor.array.view();
+            for index in indices.iter() {
+                view = view.index_axis_move(Axis(0), index[position]);
+            }
+            values.extend(view.iter().copied());
+        }
+
+        let array = Array::from_shape_vec(IxDyn(&shape_output), values)
+            .unwrap()
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn repeat_interleave<const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+        repeats: usize,
+        dim: Option<usize>,
+        output_size: Option<usize>,
+    ) -> NdArrayTensor<E, D2> {
+        let (array, dim) = match dim {
+            Some(dim) => (tensor.array, dim),
+            None => {
+                let values: Vec<E> = tensor.array.iter().copied().collect();
+                let array = Array::from_shape_vec(IxDyn(&[values.len()]), values).unwrap();
+                (array.into_shared(), 0)
+            }
+        };
+        let size = array.shape()[dim] * repeats;
+        if let Some(output_size) = output_size {
+            if output_size != size {
+                panic!("Invalid output size {output_size}, expected {size}");
+            }
+        }
+
+        let indexes: Vec<usize> = (0..size).map(|i| i / repeats).collect();
+        let array = array.select(Axis(dim), &indexes).into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn copy_<const D: usize>(tensor: &mut NdArrayTensor<E, D>, src: NdArrayTensor<E, D>) {
+        tensor.array.assign(&src.array);
+    }
+
     fn to_slice_args<const D1: usize, const D2: usize>(
         indexes: [Range<usize>; D2],
     ) -> [SliceInfoElem; D1] {
@@ -350,7 +522,7 @@ where
 
         for (index_value, index) in indexes.array.into_iter().enumerate() {
             let mut view = output_array.index_axis_mut(Axis(dim), index as usize);
-            let value = value.array.index_axis(Axis(0), index_value);
+            let value = value.array.index_axis(Axis(dim), index_value);
 
             view.zip_mut_with(&value, |a, b| *a += *b);
         }
@@ -429,3 +601,36 @@ fn cmp_min(a: &f64, b: &f64) -> Ordering {
     }
     Ordering::Equal
 }
+
+/// Computes the shape resulting from broadcasting all the given shapes together.
+pub(crate) fn broadcast_shape(shapes: &[&[usize]]) -> Vec<usize> {
+    let num_dims = shapes.iter().map(|shape| shape.len()).max().unwrap_or(0);
+    let mut output = vec![1; num_dims];
+
+    for shape in shapes {
+        let offset = num_dims - shape.len();
+        for (i, size) in shape.iter().enumerate() {
+            let current = &mut output[offset + i];
+            if *current == 1 {
+                *current = *size;
+            } else if *size != 1 && *size != *current {
+                panic!("Shapes {shapes:?} can't be broadcasted together");
+            }
+        }
+    }
+
+    output
+}
+
+/// Converts a possibly negative index into a positive one, torch style.
+pub(crate) fn wrap_index(index: i64, size: usize) -> usize {
+    let wrapped = match index < 0 {
+        true => index + size as i64,
+        false => index,
+    };
+    if wrapped < 0 || wrapped as usize >= size {
+        panic!("Index {index} is out of bounds for dimension of size {size}");
+    }
+
+    wrapped as usize
+}
@@ -128,7 +128,7 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
         dims: Vec<usize>,
     ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
-        unimplemented!()
+        NdArrayOps::flip(tensor, dims)
     }
     fn bool_upsample_bilinear2d<const D: usize, const D2: usize>(
         tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
@@ -140,10 +140,10 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         unimplemented!()
     }
     fn bool_select<const D: usize, const D2: usize>(
-            tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
-            dim: i64,
-            index: i64,
-        ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D2> {
-        unimplemented!()
+        tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: i64,
+        index: i64,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D2> {
+        NdArrayOps::select(tensor, dim, index)
     }
 }
@@ -2,18 +2,64 @@ use burn_tensor::{
     ops::{conv::calculate_conv_output_size, ConvOptions, ConvTransposeOptions},
     ElementConversion,
 };
-use ndarray::{Array4, Dim};
+use ndarray::{s, Array, Array2, Array4, Array5, ArrayView4, Axis, Dim, Ix4, Ix5, RemoveAxis};
 
 use crate::{
-    element::FloatNdArrayElement, iter_par, ops::padding::apply_padding_4d, run_par,
-    sharing::UnsafeSharedRef, tensor::NdArrayTensor,
+    element::FloatNdArrayElement,
+    iter_par,
+    ops::padding::{apply_padding_4d, pad},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
 };
 
+/// Kernels computing the 2D convolutions, selected from the size of the convolution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvKernel {
+    /// Loop over every output (or input for transposed convolutions) element.
+    Direct,
+    /// Multiply the weights with a matrix of patches unfolded from the input (or with the input
+    /// before accumulating the columns into the output for transposed convolutions).
+    Im2col,
+}
+
+/// Below this number of multiply-adds per group, the im2col copy costs more than it saves and
+/// the direct loops are used instead.
+const DIRECT_CONV_THRESHOLD: usize = 4096;
+
+/// Returns if the convolution should use the direct loops instead of im2col and GEMM.
+///
+/// The GEMM only pays off when each group has enough channels, so depthwise convolutions (and
+/// more generally convolutions with a large number of groups) as well as tiny inputs are better
+/// served by the direct loops.
+fn use_direct_conv(
+    in_channels_per_group: usize,
+    out_channels_per_group: usize,
+    kernel_size: usize,
+    spatial_size: usize,
+) -> bool {
+    let matmul_size = in_channels_per_group * out_channels_per_group * kernel_size * spatial_size;
+
+    in_channels_per_group * out_channels_per_group < 8 || matmul_size < DIRECT_CONV_THRESHOLD
+}
+
 pub(crate) fn conv2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
     weight: NdArrayTensor<E, 4>,
     bias: Option<NdArrayTensor<E, 1>>,
     options: ConvOptions<2>,
+) -> NdArrayTensor<E, 4> {
+    conv2d_with_kernel(x, weight, bias, options, None)
+}
+
+/// Computes the 2D convolution with the given kernel, or with the one selected from the size
+/// of the convolution when none is given.
+pub fn conv2d_with_kernel<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    weight: NdArrayTensor<E, 4>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvOptions<2>,
+    kernel: Option<ConvKernel>,
 ) -> NdArrayTensor<E, 4> {
     let [dilatation_height, dilatation_width] = options.dilation;
     let [padding_height, padding_width] = options.padding;
@@ -37,16 +83,55 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
     );
 
     let x = apply_padding_4d(x, options.padding, 0i32.elem()).array;
+    let x = x.view().into_dimensionality::<Ix4>().unwrap();
+    let weight = weight.array.view().into_dimensionality::<Ix4>().unwrap();
 
     let mut output = Array4::zeros(Dim([batch_size, out_channels, out_height, out_width]));
 
-    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let kernel = kernel.unwrap_or_else(|| {
+        match use_direct_conv(
+            in_channels,
+            out_channels / options.groups,
+            kernel_height * kernel_width,
+            out_height * out_width,
+        ) {
+            true => ConvKernel::Direct,
+            false => ConvKernel::Im2col,
+        }
+    });
+
+    match kernel {
+        ConvKernel::Direct => conv2d_direct(x, weight, &mut output, &options),
+        ConvKernel::Im2col => conv2d_im2col(x, weight, &mut output, &options),
+    }
+
+    if let Some(bias) = bias {
+        add_bias(&mut output, &bias);
+    }
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Computes the convolution of the padded input with a loop over every output element.
+fn conv2d_direct<E: FloatNdArrayElement>(
+    x: ArrayView4<E>,
+    weight: ArrayView4<E>,
+    output: &mut Array4<E>,
+    options: &ConvOptions<2>,
+) {
+    let [dilatation_height, dilatation_width] = options.dilation;
+    let [stride_height, stride_width] = options.stride;
+    let [batch_size, out_channels, out_height, out_width] = output.shape().try_into().unwrap();
+    let [_, in_channels, kernel_height, kernel_width] = weight.shape().try_into().unwrap();
+    let out_channels_per_group = out_channels / options.groups;
+
+    let unsafe_shared_out = UnsafeSharedRef::new(output);
 
     run_par!(|| {
         iter_par!(0, batch_size * out_channels).for_each(|k| unsafe {
             let b = k / out_channels;
             let oc = k % out_channels;
-            let g = k % options.groups;
+            let g = oc / out_channels_per_group;
 
             let output = unsafe_shared_out.get();
 
@@ -60,24 +145,82 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
 
                                 let weight_ic = ic - (g * in_channels);
                                 output[[b, oc, oh, ow]] +=
-                                    x[[b, ic, ih, iw]] * weight.array[[oc, weight_ic, kh, kw]];
+                                    x[[b, ic, ih, iw]] * weight[[oc, weight_ic, kh, kw]];
                             }
                         }
                     }
                 }
             }
+        });
+    });
+}
+
+/// Computes the convolution of the padded input by unfolding each group of the input into a
+/// matrix of patches (im2col), which is then multiplied with the weight matrix of the group.
+fn conv2d_im2col<E: FloatNdArrayElement>(
+    x: ArrayView4<E>,
+    weight: ArrayView4<E>,
+    output: &mut Array4<E>,
+    options: &ConvOptions<2>,
+) {
+    let [dilatation_height, dilatation_width] = options.dilation;
+    let [stride_height, stride_width] = options.stride;
+    let [batch_size, out_channels, out_height, out_width] = output.shape().try_into().unwrap();
+    let [_, in_channels, kernel_height, kernel_width] = weight.shape().try_into().unwrap();
+    let groups = options.groups;
+    let out_channels_per_group = out_channels / groups;
+    let patch_size = in_channels * kernel_height * kernel_width;
+
+    let weight = weight
+        .as_standard_layout()
+        .into_shape((out_channels, patch_size))
+        .unwrap();
+    let unsafe_shared_out = UnsafeSharedRef::new(output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * groups).for_each(|k| unsafe {
+            let b = k / groups;
+            let g = k % groups;
+            let oc_start = g * out_channels_per_group;
+            let oc_end = oc_start + out_channels_per_group;
 
-            if let Some(bias) = &bias {
-                for oh in 0..out_height {
-                    for ow in 0..out_width {
-                        output[[b, oc, oh, ow]] += bias.array[oc];
+            let mut columns = Array2::zeros((patch_size, out_height * out_width));
+
+            for ic in 0..in_channels {
+                let x = x.slice(s![b, g * in_channels + ic, .., ..]);
+
+                for kh in 0..kernel_height {
+                    for kw in 0..kernel_width {
+                        let row = (ic * kernel_height + kh) * kernel_width + kw;
+                        let mut column = columns.row_mut(row);
+
+                        for oh in 0..out_height {
+                            let ih = oh * stride_height + kh * dilatation_height;
+
+                            for ow in 0..out_width {
+                                let iw = ow * stride_width + kw * dilatation_width;
+                                column[oh * out_width + ow] = x[[ih, iw]];
+                            }
+                        }
                     }
                 }
             }
+
+            let mut output = unsafe_shared_out
+                .get()
+                .slice_mut(s![b, oc_start..oc_end, .., ..])
+                .into_shape((out_channels_per_group, out_height * out_width))
+                .unwrap();
+
+            ndarray::linalg::general_mat_mul(
+                1.0.elem(),
+                &weight.slice(s![oc_start..oc_end, ..]),
+                &columns,
+                0.0.elem(),
+                &mut output,
+            );
         });
     });
-
-    NdArrayTensor::new(output.into_dyn().into_shared())
 }
 
 pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
@@ -85,6 +228,18 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
     weight: NdArrayTensor<E, 4>,
     bias: Option<NdArrayTensor<E, 1>>,
     options: ConvTransposeOptions<2>,
+) -> NdArrayTensor<E, 4> {
+    conv_transpose2d_with_kernel(x, weight, bias, options, None)
+}
+
+/// Computes the 2D transposed convolution with the given kernel, or with the one selected from
+/// the size of the convolution when none is given.
+pub fn conv_transpose2d_with_kernel<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    weight: NdArrayTensor<E, 4>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvTransposeOptions<2>,
+    kernel: Option<ConvKernel>,
 ) -> NdArrayTensor<E, 4> {
     let [dilation_height, dilation_width] = options.dilation;
     let [padding_height, padding_width] = options.padding;
@@ -103,7 +258,9 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
             - 2 * padding_width
             + 1;
 
-    let x = x.array;
+    let x = x.array.view().into_dimensionality::<Ix4>().unwrap();
+    let weight = weight.array.view().into_dimensionality::<Ix4>().unwrap();
+
     let mut output = Array4::zeros(Dim([
         batch_size,
         out_channels * options.groups,
@@ -111,19 +268,59 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
         out_width,
     ]));
 
-    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let kernel = kernel.unwrap_or_else(|| {
+        match use_direct_conv(
+            in_channels / options.groups,
+            out_channels,
+            kernel_height * kernel_width,
+            in_height * in_width,
+        ) {
+            true => ConvKernel::Direct,
+            false => ConvKernel::Im2col,
+        }
+    });
+
+    match kernel {
+        ConvKernel::Direct => conv_transpose2d_direct(x, weight, &mut output, &options),
+        ConvKernel::Im2col => conv_transpose2d_col2im(x, weight, &mut output, &options),
+    }
+
+    if let Some(bias) = bias {
+        add_bias(&mut output, &bias);
+    }
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Computes the transposed convolution with a loop over every input element.
+fn conv_transpose2d_direct<E: FloatNdArrayElement>(
+    x: ArrayView4<E>,
+    weight: ArrayView4<E>,
+    output: &mut Array4<E>,
+    options: &ConvTransposeOptions<2>,
+) {
+    let [dilation_height, dilation_width] = options.dilation;
+    let [padding_height, padding_width] = options.padding;
+    let [stride_height, stride_width] = options.stride;
+    let [_, _, in_height, in_width] = x.shape().try_into().unwrap();
+    let [in_channels, out_channels, kernel_height, kernel_width] =
+        weight.shape().try_into().unwrap();
+    let [batch_size, _, out_height, out_width] = output.shape().try_into().unwrap();
+    let groups = options.groups;
+
+    let unsafe_shared_out = UnsafeSharedRef::new(output);
 
     run_par!(|| {
-        iter_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
-            let b = k / (out_channels * options.groups);
-            let oc = k % out_channels;
-            let g = k % options.groups;
+        iter_par!(0, batch_size * out_channels * groups).for_each(|k| unsafe {
+            let b = k / (out_channels * groups);
+            let oc_out = k % (out_channels * groups);
+            let oc = oc_out % out_channels;
+            let g = oc_out / out_channels;
 
             let output = unsafe_shared_out.get();
 
-            let oc_out = oc + (out_channels * g);
-            let ic_start = g * (in_channels / options.groups);
-            let ic_end = ic_start + in_channels / options.groups;
+            let ic_start = g * (in_channels / groups);
+            let ic_end = ic_start + in_channels / groups;
 
             for ic in ic_start..ic_end {
                 for ih in 0..in_height {
@@ -145,22 +342,407 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
                                 let ow = ow - padding_width;
 
                                 output[[b, oc_out, oh, ow]] +=
-                                    x[[b, ic, ih, iw]] * weight.array[[ic, oc, kh, kw]];
+                                    x[[b, ic, ih, iw]] * weight[[ic, oc, kh, kw]];
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    });
+}
+
+/// Computes the transposed convolution by multiplying the transposed weight matrix of each group
+/// with its input, which gives the contribution of every input element to each kernel position.
+/// Those columns are then accumulated into the output (col2im).
+fn conv_transpose2d_col2im<E: FloatNdArrayElement>(
+    x: ArrayView4<E>,
+    weight: ArrayView4<E>,
+    output: &mut Array4<E>,
+    options: &ConvTransposeOptions<2>,
+) {
+    let [dilation_height, dilation_width] = options.dilation;
+    let [padding_height, padding_width] = options.padding;
+    let [stride_height, stride_width] = options.stride;
+    let [_, _, in_height, in_width] = x.shape().try_into().unwrap();
+    let [in_channels, out_channels, kernel_height, kernel_width] =
+        weight.shape().try_into().unwrap();
+    let [batch_size, _, out_height, out_width] = output.shape().try_into().unwrap();
+    let groups = options.groups;
+    let in_channels_per_group = in_channels / groups;
+    let patch_size = out_channels * kernel_height * kernel_width;
+
+    let weight = weight
+        .as_standard_layout()
+        .into_shape((in_channels, patch_size))
+        .unwrap();
+    let x = x.as_standard_layout();
+    let x = x
+        .view()
+        .into_shape((batch_size, in_channels, in_height * in_width))
+        .unwrap();
+    let unsafe_shared_out = UnsafeSharedRef::new(output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * groups).for_each(|k| unsafe {
+            let b = k / groups;
+            let g = k % groups;
+            let ic_start = g * in_channels_per_group;
+            let ic_end = ic_start + in_channels_per_group;
+
+            let mut columns = Array2::zeros((patch_size, in_height * in_width));
+
+            ndarray::linalg::general_mat_mul(
+                1.0.elem(),
+                &weight.slice(s![ic_start..ic_end, ..]).t(),
+                &x.slice(s![b, ic_start..ic_end, ..]),
+                0.0.elem(),
+                &mut columns,
+            );
+
+            let output = unsafe_shared_out.get();
+
+            for oc in 0..out_channels {
+                let mut output = output.slice_mut(s![b, g * out_channels + oc, .., ..]);
+
+                for kh in 0..kernel_height {
+                    for kw in 0..kernel_width {
+                        let row = (oc * kernel_height + kh) * kernel_width + kw;
+                        let column = columns.row(row);
+
+                        for ih in 0..in_height {
+                            let oh = ih * stride_height + kh * dilation_height;
+                            if oh < padding_height || oh >= out_height + padding_height {
+                                continue;
+                            }
+
+                            for iw in 0..in_width {
+                                let ow = iw * stride_width + kw * dilation_width;
+                                if ow < padding_width || ow >= out_width + padding_width {
+                                    continue;
+                                }
+
+                                output[[oh - padding_height, ow - padding_width]] +=
+                                    column[ih * in_width + iw];
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    });
+}
+
+pub(crate) fn conv3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [out_channels, in_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+    let groups = options.groups;
+    let out_channels_per_group = out_channels / groups;
+
+    let out_depth = calculate_conv_output_size(
+        kernel_depth,
+        stride_depth,
+        padding_depth,
+        dilation_depth,
+        in_depth,
+    );
+    let out_height = calculate_conv_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        in_width,
+    );
+    let out_size = out_depth * out_height * out_width;
+    let patch_size = in_channels * kernel_depth * kernel_height * kernel_width;
+
+    let padding = [
+        padding_width,
+        padding_width,
+        padding_height,
+        padding_height,
+        padding_depth,
+        padding_depth,
+    ];
+    let x = pad(x, &padding, "constant", None).array;
+    let x = x.view().into_dimensionality::<Ix5>().unwrap();
+    let weight = weight
+        .array
+        .as_standard_layout()
+        .into_shape((out_channels, patch_size))
+        .unwrap();
+
+    let mut output = Array5::zeros(Dim([
+        batch_size,
+        out_channels,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * groups).for_each(|k| unsafe {
+            let b = k / groups;
+            let g = k % groups;
+            let oc_start = g * out_channels_per_group;
+            let oc_end = oc_start + out_channels_per_group;
+
+            let mut columns = Array2::zeros((patch_size, out_size));
+
+            for ic in 0..in_channels {
+                let x = x.slice(s![b, g * in_channels + ic, .., .., ..]);
+
+                for kd in 0..kernel_depth {
+                    for kh in 0..kernel_height {
+                        for kw in 0..kernel_width {
+                            let row =
+                                ((ic * kernel_depth + kd) * kernel_height + kh) * kernel_width + kw;
+                            let mut column = columns.row_mut(row);
+
+                            for od in 0..out_depth {
+                                let id = od * stride_depth + kd * dilation_depth;
+
+                                for oh in 0..out_height {
+                                    let ih = oh * stride_height + kh * dilation_height;
+
+                                    for ow in 0..out_width {
+                                        let iw = ow * stride_width + kw * dilation_width;
+                                        column[(od * out_height + oh) * out_width + ow] =
+                                            x[[id, ih, iw]];
+                                    }
+                                }
                             }
                         }
                     }
                 }
             }
 
-            if let Some(bias) = &bias {
-                for oh in 0..out_height {
-                    for ow in 0..out_width {
-                        output[[b, oc_out, oh, ow]] += bias.array[oc_out];
+            let mut output = unsafe_shared_out
+                .get()
+                .slice_mut(s![b, oc_start..oc_end, .., .., ..])
+                .into_shape((out_channels_per_group, out_size))
+                .unwrap();
+
+            ndarray::linalg::general_mat_mul(
+                1.0.elem(),
+                &weight.slice(s![oc_start..oc_end, ..]),
+                &columns,
+                0.0.elem(),
+                &mut output,
+            );
+        });
+    });
+
+    if let Some(bias) = bias {
+        add_bias(&mut output, &bias);
+    }
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn conv_transpose3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [out_padding_depth, out_padding_height, out_padding_width] = options.padding_out;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [in_channels, out_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+    let groups = options.groups;
+    let in_channels_per_group = in_channels / groups;
+
+    let out_depth =
+        (in_depth - 1) * stride_depth + dilation_depth * (kernel_depth - 1) + out_padding_depth
+            - 2 * padding_depth
+            + 1;
+    let out_height = (in_height - 1) * stride_height
+        + dilation_height * (kernel_height - 1)
+        + out_padding_height
+        - 2 * padding_height
+        + 1;
+    let out_width =
+        (in_width - 1) * stride_width + dilation_width * (kernel_width - 1) + out_padding_width
+            - 2 * padding_width
+            + 1;
+    let in_size = in_depth * in_height * in_width;
+    let patch_size = out_channels * kernel_depth * kernel_height * kernel_width;
+
+    let x = x
+        .array
+        .as_standard_layout()
+        .into_shape((batch_size, in_channels, in_size))
+        .unwrap();
+    let weight = weight
+        .array
+        .as_standard_layout()
+        .into_shape((in_channels, patch_size))
+        .unwrap();
+
+    let mut output = Array5::zeros(Dim([
+        batch_size,
+        out_channels * groups,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * groups).for_each(|k| unsafe {
+            let b = k / groups;
+            let g = k % groups;
+            let ic_start = g * in_channels_per_group;
+            let ic_end = ic_start + in_channels_per_group;
+
+            let mut columns = Array2::zeros((patch_size, in_size));
+
+            ndarray::linalg::general_mat_mul(
+                1.0.elem(),
+                &weight.slice(s![ic_start..ic_end, ..]).t(),
+                &x.slice(s![b, ic_start..ic_end, ..]),
+                0.0.elem(),
+                &mut columns,
+            );
+
+            let output = unsafe_shared_out.get();
+
+            for oc in 0..out_channels {
+                let mut output = output.slice_mut(s![b, g * out_channels + oc, .., .., ..]);
+
+                for kd in 0..kernel_depth {
+                    for kh in 0..kernel_height {
+                        for kw in 0..kernel_width {
+                            let row =
+                                ((oc * kernel_depth + kd) * kernel_height + kh) * kernel_width + kw;
+                            let column = columns.row(row);
+
+                            for id in 0..in_depth {
+                                let od = id * stride_depth + kd * dilation_depth;
+                                if od < padding_depth || od >= out_depth + padding_depth {
+                                    continue;
+                                }
+
+                                for ih in 0..in_height {
+                                    let oh = ih * stride_height + kh * dilation_height;
+                                    if oh < padding_height || oh >= out_height + padding_height {
+                                        continue;
+                                    }
+
+                                    for iw in 0..in_width {
+                                        let ow = iw * stride_width + kw * dilation_width;
+                                        if ow < padding_width || ow >= out_width + padding_width {
+                                            continue;
+                                        }
+
+                                        output[[
+                                            od - padding_depth,
+                                            oh - padding_height,
+                                            ow - padding_width,
+                                        ]] += column[(id * in_height + ih) * in_width + iw];
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
             }
         });
     });
 
+    if let Some(bias) = bias {
+        add_bias(&mut output, &bias);
+    }
+
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
+
+fn add_bias<E: FloatNdArrayElement, D: RemoveAxis>(
+    output: &mut Array<E, D>,
+    bias: &NdArrayTensor<E, 1>,
+) {
+    for (oc, bias) in bias.array.iter().enumerate() {
+        output
+            .index_axis_mut(Axis(1), oc)
+            .mapv_inplace(|value| value + *bias);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_common::rand::get_seeded_rng;
+    use burn_tensor::{Data, Distribution, Shape};
+
+    fn random<const D: usize>(shape: [usize; D]) -> NdArrayTensor<f32, D> {
+        let data = Data::random(
+            Shape::new(shape),
+            Distribution::Standard,
+            &mut get_seeded_rng(),
+        );
+        NdArrayTensor::from_data(data)
+    }
+
+    fn assert_approx_eq(lhs: &Array4<f32>, rhs: &Array4<f32>) {
+        assert_eq!(lhs.shape(), rhs.shape());
+        for (lhs, rhs) in lhs.iter().zip(rhs.iter()) {
+            assert!((lhs - rhs).abs() < 1e-4, "{lhs} != {rhs}");
+        }
+    }
+
+    #[test]
+    fn conv2d_im2col_should_match_direct() {
+        let options = ConvOptions::new([2, 1], [1, 2], [1, 2], 2);
+        let x = random([2, 4, 9, 8]);
+        let weight = random([6, 2, 3, 2]);
+
+        let x = apply_padding_4d(x, options.padding, 0.0).array;
+        let x = x.view().into_dimensionality::<Ix4>().unwrap();
+        let weight = weight.array.view().into_dimensionality::<Ix4>().unwrap();
+        let mut output_direct = Array4::zeros(Dim([2, 6, 5, 10]));
+        let mut output_im2col = Array4::zeros(Dim([2, 6, 5, 10]));
+
+        conv2d_direct(x, weight, &mut output_direct, &options);
+        conv2d_im2col(x, weight, &mut output_im2col, &options);
+
+        assert_approx_eq(&output_direct, &output_im2col);
+    }
+
+    #[test]
+    fn conv_transpose2d_col2im_should_match_direct() {
+        let options = ConvTransposeOptions::new([2, 1], [1, 2], [1, 0], [1, 2], 2);
+        let x = random([2, 4, 5, 6]);
+        let weight = random([4, 3, 3, 2]);
+
+        let x = x.array.view().into_dimensionality::<Ix4>().unwrap();
+        let weight = weight.array.view().into_dimensionality::<Ix4>().unwrap();
+        let mut output_direct = Array4::zeros(Dim([2, 6, 10, 4]));
+        let mut output_col2im = Array4::zeros(Dim([2, 6, 10, 4]));
+
+        conv_transpose2d_direct(x, weight, &mut output_direct, &options);
+        conv_transpose2d_col2im(x, weight, &mut output_col2im, &options);
+
+        assert_approx_eq(&output_direct, &output_col2im);
+    }
+}
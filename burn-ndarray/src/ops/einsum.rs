@@ -0,0 +1,342 @@
+use crate::{element::FloatNdArrayElement, ops::matmul::matmul, tensor::NdArrayTensor};
+use alloc::vec::Vec;
+use ndarray::{ArcArray, Axis, IxDyn};
+
+/// Number of labels that can be used explicitly in an equation (`A-Z` and `a-z`).
+const NUM_LETTERS: usize = 52;
+
+/// Computes the einstein summation of two tensors, following torch's `einsum` semantics.
+///
+/// The equation supports explicit (`ij,jk->ik`) and implicit (`ij,jk`) outputs, ellipsis to
+/// cover the broadcasted dimensions, repeated labels in the same operand (diagonals) as well as
+/// dimensions of size one that are broadcasted to the size of the same label in the other operand.
+///
+/// The contraction is computed by permuting and reshaping both operands into batched matrices,
+/// so that a single batched matrix multiplication does all the work.
+pub(crate) fn einsum<E: FloatNdArrayElement, const D: usize, const D2: usize, const D3: usize>(
+    equation: &str,
+    lhs: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D2>,
+) -> NdArrayTensor<E, D3> {
+    let equation = EinsumEquation::parse(equation, [lhs.array.ndim(), rhs.array.ndim()]);
+    let [labels_lhs, labels_rhs] = &equation.inputs;
+    let output = &equation.output;
+
+    if output.len() != D3 && !(output.is_empty() && D3 == 1) {
+        panic!(
+            "Invalid einsum output: the equation produces {} dimensions, expected {D3}",
+            output.len()
+        );
+    }
+
+    let (lhs, labels_lhs) = reduce_operand(lhs.array, labels_lhs, labels_rhs, output);
+    let (rhs, labels_rhs) = reduce_operand(rhs.array, labels_rhs, &labels_lhs, output);
+    let (lhs, rhs) = broadcast_operands(lhs, &labels_lhs, rhs, &labels_rhs);
+
+    let (shape_lhs, shape_rhs) = (lhs.shape().to_vec(), rhs.shape().to_vec());
+    let size_of = |label: &usize| match labels_lhs.iter().position(|l| l == label) {
+        Some(axis) => shape_lhs[axis],
+        None => shape_rhs[labels_rhs.iter().position(|l| l == label).unwrap()],
+    };
+    let in_lhs = |label: &usize| labels_lhs.contains(label);
+    let in_rhs = |label: &usize| labels_rhs.contains(label);
+
+    let batch: Vec<usize> = output
+        .iter()
+        .filter(|l| in_lhs(l) && in_rhs(l))
+        .copied()
+        .collect();
+    let contracted: Vec<usize> = labels_lhs
+        .iter()
+        .filter(|l| in_rhs(l) && !output.contains(l))
+        .copied()
+        .collect();
+    let only_lhs: Vec<usize> = output
+        .iter()
+        .filter(|l| in_lhs(l) && !in_rhs(l))
+        .copied()
+        .collect();
+    let only_rhs: Vec<usize> = output
+        .iter()
+        .filter(|l| in_rhs(l) && !in_lhs(l))
+        .copied()
+        .collect();
+
+    let size = |labels: &[usize]| labels.iter().map(size_of).product::<usize>();
+    let (size_batch, size_contracted) = (size(&batch), size(&contracted));
+
+    let lhs = to_matrices(
+        lhs,
+        &labels_lhs,
+        [&batch, &only_lhs, &contracted],
+        [size_batch, size(&only_lhs), size_contracted],
+    );
+    let rhs = to_matrices(
+        rhs,
+        &labels_rhs,
+        [&batch, &contracted, &only_rhs],
+        [size_batch, size_contracted, size(&only_rhs)],
+    );
+    let result = matmul(lhs, rhs).array;
+
+    let labels_result: Vec<usize> = [batch, only_lhs, only_rhs].concat();
+    let shape_result: Vec<usize> = labels_result.iter().map(size_of).collect();
+    let result = result
+        .as_standard_layout()
+        .into_owned()
+        .into_shape(IxDyn(&shape_result))
+        .unwrap();
+
+    let array = match output.is_empty() {
+        true => result.into_shape(IxDyn(&[1])).unwrap(),
+        false => {
+            let axes: Vec<usize> = output
+                .iter()
+                .map(|label| labels_result.iter().position(|l| l == label).unwrap())
+                .collect();
+            result.permuted_axes(axes)
+        }
+    };
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+/// Parsed einsum equation where each label is identified by an index.
+///
+/// Letters use the indexes `0..52` in ascii order, the dimensions covered by ellipsis use the
+/// following indexes, aligned from the right like broadcasting does.
+struct EinsumEquation {
+    inputs: [Vec<usize>; 2],
+    output: Vec<usize>,
+}
+
+enum Subscript {
+    Label(usize),
+    Ellipsis,
+}
+
+impl EinsumEquation {
+    fn parse(equation: &str, num_dims: [usize; 2]) -> Self {
+        let equation: alloc::string::String =
+            equation.chars().filter(|c| !c.is_whitespace()).collect();
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+
+        let inputs: Vec<Vec<Subscript>> = inputs.split(',').map(Self::parse_term).collect();
+        if inputs.len() != 2 {
+            panic!(
+                "Invalid einsum equation '{equation}': expected 2 operands, got {}",
+                inputs.len()
+            );
+        }
+
+        let num_ellipsis_dims: Vec<usize> = inputs
+            .iter()
+            .zip(num_dims)
+            .map(|(term, num_dims)| {
+                let num_labels = term
+                    .iter()
+                    .filter(|s| matches!(s, Subscript::Label(_)))
+                    .count();
+                let has_ellipsis = term.len() != num_labels;
+
+                match num_dims.checked_sub(num_labels) {
+                    Some(num_ellipsis_dims) if has_ellipsis => num_ellipsis_dims,
+                    Some(0) => 0,
+                    _ => panic!(
+                        "Invalid einsum equation '{equation}': the number of subscripts doesn't match the {num_dims} dimensions of the operand"
+                    ),
+                }
+            })
+            .collect();
+        let max_ellipsis_dims = num_ellipsis_dims.iter().copied().max().unwrap();
+
+        let expand = |term: &[Subscript], num_ellipsis_dims: usize| {
+            let mut labels = Vec::new();
+            for subscript in term {
+                match subscript {
+                    Subscript::Label(label) => labels.push(*label),
+                    Subscript::Ellipsis => labels.extend(
+                        (max_ellipsis_dims - num_ellipsis_dims..max_ellipsis_dims)
+                            .map(|i| NUM_LETTERS + i),
+                    ),
+                }
+            }
+            labels
+        };
+        let inputs = [
+            expand(&inputs[0], num_ellipsis_dims[0]),
+            expand(&inputs[1], num_ellipsis_dims[1]),
+        ];
+
+        let output = match output {
+            Some(output) => expand(&Self::parse_term(output), max_ellipsis_dims),
+            None => {
+                // Implicit output: ellipsis dimensions followed by the labels appearing only once,
+                // sorted.
+                let count = |label: usize| inputs.iter().flatten().filter(|l| **l == label).count();
+                let mut output: Vec<usize> =
+                    (NUM_LETTERS..NUM_LETTERS + max_ellipsis_dims).collect();
+                output.extend((0..NUM_LETTERS).filter(|label| count(*label) == 1));
+                output
+            }
+        };
+
+        for (i, label) in output.iter().enumerate() {
+            if output[..i].contains(label) {
+                panic!("Invalid einsum equation '{equation}': repeated output subscript");
+            }
+            if !inputs.iter().flatten().any(|l| l == label) {
+                panic!(
+                    "Invalid einsum equation '{equation}': output subscript doesn't appear in the operands"
+                );
+            }
+        }
+
+        Self { inputs, output }
+    }
+
+    fn parse_term(term: &str) -> Vec<Subscript> {
+        let mut subscripts = Vec::new();
+        let mut chars = term.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let subscript = match c {
+                'A'..='Z' => Subscript::Label(c as usize - 'A' as usize),
+                'a'..='z' => Subscript::Label(c as usize - 'a' as usize + 26),
+                '.' if chars.next() == Some('.') && chars.next() == Some('.') => {
+                    Subscript::Ellipsis
+                }
+                _ => panic!("Invalid einsum subscripts '{term}'"),
+            };
+            if matches!(subscript, Subscript::Ellipsis)
+                && subscripts.iter().any(|s| matches!(s, Subscript::Ellipsis))
+            {
+                panic!("Invalid einsum subscripts '{term}': only one ellipsis is allowed");
+            }
+            subscripts.push(subscript);
+        }
+
+        subscripts
+    }
+}
+
+/// Takes the diagonals of repeated labels and sums the dimensions that are neither part of the
+/// output nor of the other operand.
+fn reduce_operand<E: FloatNdArrayElement>(
+    mut array: ArcArray<E, IxDyn>,
+    labels: &[usize],
+    labels_other: &[usize],
+    output: &[usize],
+) -> (ArcArray<E, IxDyn>, Vec<usize>) {
+    let mut labels = labels.to_vec();
+
+    let mut axis = 0;
+    while axis < labels.len() {
+        match labels[axis + 1..].iter().position(|l| *l == labels[axis]) {
+            Some(offset) => {
+                array = diagonal(array, axis, axis + 1 + offset);
+                labels.remove(axis + 1 + offset);
+            }
+            None => axis += 1,
+        }
+    }
+
+    for axis in (0..labels.len()).rev() {
+        if !output.contains(&labels[axis]) && !labels_other.contains(&labels[axis]) {
+            array = array.sum_axis(Axis(axis)).into_shared();
+            labels.remove(axis);
+        }
+    }
+
+    (array, labels)
+}
+
+fn diagonal<E: FloatNdArrayElement>(
+    array: ArcArray<E, IxDyn>,
+    axis1: usize,
+    axis2: usize,
+) -> ArcArray<E, IxDyn> {
+    let size = array.shape()[axis1];
+    if array.shape()[axis2] != size {
+        panic!(
+            "Invalid einsum operand: repeated subscripts must have the same size, got {} and {}",
+            size,
+            array.shape()[axis2]
+        );
+    }
+
+    let views: Vec<_> = (0..size)
+        .map(|i| {
+            array
+                .view()
+                .index_axis_move(Axis(axis2), i)
+                .index_axis_move(Axis(axis1), i)
+        })
+        .collect();
+
+    ndarray::stack(Axis(axis1), &views).unwrap().into_shared()
+}
+
+/// Broadcasts the dimensions of size one to the size of the same label in the other operand.
+fn broadcast_operands<E: FloatNdArrayElement>(
+    lhs: ArcArray<E, IxDyn>,
+    labels_lhs: &[usize],
+    rhs: ArcArray<E, IxDyn>,
+    labels_rhs: &[usize],
+) -> (ArcArray<E, IxDyn>, ArcArray<E, IxDyn>) {
+    let mut shape_lhs = lhs.shape().to_vec();
+    let mut shape_rhs = rhs.shape().to_vec();
+
+    for (axis_lhs, label) in labels_lhs.iter().enumerate() {
+        if let Some(axis_rhs) = labels_rhs.iter().position(|l| l == label) {
+            let (size_lhs, size_rhs) = (shape_lhs[axis_lhs], shape_rhs[axis_rhs]);
+
+            if size_lhs == 1 {
+                shape_lhs[axis_lhs] = size_rhs;
+            } else if size_rhs == 1 {
+                shape_rhs[axis_rhs] = size_lhs;
+            } else if size_lhs != size_rhs {
+                panic!(
+                    "Invalid einsum operands: subscript with sizes {size_lhs} and {size_rhs} can't be broadcasted"
+                );
+            }
+        }
+    }
+
+    let broadcast = |array: ArcArray<E, IxDyn>, shape: Vec<usize>| match array.shape() == shape {
+        true => array,
+        false => array
+            .broadcast(IxDyn(&shape))
+            .unwrap()
+            .to_owned()
+            .into_shared(),
+    };
+
+    (broadcast(lhs, shape_lhs), broadcast(rhs, shape_rhs))
+}
+
+/// Permutes the operand following the three groups of labels and reshapes it into a batch of
+/// matrices.
+fn to_matrices<E: FloatNdArrayElement>(
+    array: ArcArray<E, IxDyn>,
+    labels: &[usize],
+    groups: [&[usize]; 3],
+    shape: [usize; 3],
+) -> NdArrayTensor<E, 3> {
+    let axes: Vec<usize> = groups
+        .iter()
+        .flat_map(|group| group.iter())
+        .map(|label| labels.iter().position(|l| l == label).unwrap())
+        .collect();
+    let array = array
+        .permuted_axes(axes)
+        .as_standard_layout()
+        .into_owned()
+        .into_shape(IxDyn(&shape))
+        .unwrap();
+
+    NdArrayTensor::new(array.into_shared())
+}
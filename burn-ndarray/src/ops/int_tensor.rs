@@ -335,7 +335,7 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         tensor: <NdArrayBackend<E> as Backend>::IntTensorPrimitive<D>,
         dims: Vec<usize>,
     ) -> <NdArrayBackend<E> as Backend>::IntTensorPrimitive<D> {
-        unimplemented!()
+        NdArrayOps::flip(tensor, dims)
     }
     fn int_upsample_bilinear2d<const D: usize, const D2: usize>(
         tensor: <NdArrayBackend<E> as Backend>::IntTensorPrimitive<D>,
@@ -351,6 +351,6 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         dim: i64,
         index: i64,
     ) -> <NdArrayBackend<E> as Backend>::IntTensorPrimitive<D2> {
-        unimplemented!()
+        NdArrayOps::select(tensor, dim, index)
     }
 }
@@ -0,0 +1,82 @@
+use crate::{
+    element::FloatNdArrayElement, iter_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+
+use alloc::vec::Vec;
+use burn_tensor::ElementConversion;
+use ndarray::Array4;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Bilinear upsampling of a `[batch_size, channels, height, width]` tensor, matching torch's
+/// `upsample_bilinear2d`.
+pub(crate) fn upsample_bilinear2d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+    align_corners: bool,
+    scales: [Option<f64>; 2],
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, height_in, width_in] = x.shape().dims;
+    let [height_out, width_out] = output_size;
+
+    let coefs_height = interpolation_coefs(height_in, height_out, align_corners, scales[0]);
+    let coefs_width = interpolation_coefs(width_in, width_out, align_corners, scales[1]);
+
+    let x = x.array;
+    let mut output = Array4::zeros((batch_size, channels, height_out, width_out));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+            let output = unsafe_shared_out.get();
+
+            for (oh, (h0, h1, lambda_h)) in coefs_height.iter().enumerate() {
+                for (ow, (w0, w1, lambda_w)) in coefs_width.iter().enumerate() {
+                    let value = |h: usize, w: usize| x[[b, c, h, w]].elem::<f64>();
+
+                    let top = value(*h0, *w0) * (1.0 - lambda_w) + value(*h0, *w1) * lambda_w;
+                    let bottom = value(*h1, *w0) * (1.0 - lambda_w) + value(*h1, *w1) * lambda_w;
+
+                    output[[b, c, oh, ow]] = (top * (1.0 - lambda_h) + bottom * lambda_h).elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Computes, for each output position, the two input positions to interpolate with and the weight
+/// of the second one.
+fn interpolation_coefs(
+    size_in: usize,
+    size_out: usize,
+    align_corners: bool,
+    scale: Option<f64>,
+) -> Vec<(usize, usize, f64)> {
+    let ratio = match align_corners {
+        true if size_out > 1 => (size_in - 1) as f64 / (size_out - 1) as f64,
+        true => 0.0,
+        false => match scale {
+            Some(scale) if scale > 0.0 => 1.0 / scale,
+            _ => size_in as f64 / size_out as f64,
+        },
+    };
+
+    (0..size_out)
+        .map(|i| {
+            let source = match align_corners {
+                true => ratio * i as f64,
+                false => f64::max(ratio * (i as f64 + 0.5) - 0.5, 0.0),
+            };
+            let index = usize::min(source.floor() as usize, size_in - 1);
+            let index_next = usize::min(index + 1, size_in - 1);
+
+            (index, index_next, source - index as f64)
+        })
+        .collect()
+}
@@ -5,8 +5,11 @@ mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod einsum;
+pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
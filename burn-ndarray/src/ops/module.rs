@@ -5,9 +5,13 @@ use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend,
 use burn_tensor::{ops::*, Shape};
 
 use super::{
-    avgpool::{avg_pool2d, avg_pool2d_backward},
-    conv::{conv2d, conv_transpose2d},
-    maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indexes},
+    adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
+    avgpool::{avg_pool2d, avg_pool2d_backward, avg_pool3d, avg_pool3d_backward},
+    conv::{conv2d, conv3d, conv_transpose2d, conv_transpose3d},
+    maxpool::{
+        max_pool2d, max_pool2d_backward, max_pool2d_with_indexes, max_pool3d, max_pool3d_backward,
+        max_pool3d_with_indexes,
+    },
 };
 
 impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E> {
@@ -88,6 +92,24 @@ impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E>
         conv_transpose2d(x, weight, bias, options)
     }
 
+    fn conv3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv3d(x, weight, bias, options)
+    }
+
+    fn conv_transpose3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv_transpose3d(x, weight, bias, options)
+    }
+
     fn avg_pool2d(
         x: NdArrayTensor<E, 4>,
         kernel_size: [usize; 2],
@@ -144,4 +166,72 @@ impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E>
             indexes,
         ))
     }
+
+    fn avg_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d(x, kernel_size, stride, padding)
+    }
+
+    fn avg_pool3d_backward(
+        x: NdArrayTensor<E, 5>,
+        grad: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d_backward(x, grad, kernel_size, stride, padding)
+    }
+
+    fn max_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        max_pool3d(x, kernel_size, stride, padding)
+    }
+
+    fn max_pool3d_with_indexes(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<NdArrayBackend<E>> {
+        let (output, indexes) = max_pool3d_with_indexes(x, kernel_size, stride, padding);
+
+        MaxPool3dWithIndexes::new(output, indexes)
+    }
+
+    fn max_pool3d_with_indexes_backward(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        output_grad: NdArrayTensor<E, 5>,
+        indexes: NdArrayTensor<i64, 5>,
+    ) -> MaxPool3dBackward<NdArrayBackend<E>> {
+        MaxPool3dBackward::new(max_pool3d_backward(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            output_grad,
+            indexes,
+        ))
+    }
+
+    fn adaptive_avg_pool2d(x: NdArrayTensor<E, 4>, output_size: [usize; 2]) -> NdArrayTensor<E, 4> {
+        adaptive_avg_pool2d(x, output_size)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+    ) -> NdArrayTensor<E, 4> {
+        adaptive_avg_pool2d_backward(x, grad)
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
-use burn_tensor::ops::TensorOps;
-use ndarray::Array4;
+use alloc::vec::Vec;
+use burn_tensor::{ops::TensorOps, ElementConversion};
+use ndarray::{Array, Array4, Axis, IxDyn, Slice};
 
 pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
@@ -31,3 +32,64 @@ pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
 
     x_new
 }
+
+/// Pads the tensor following torch's `pad` semantics.
+///
+/// The padding sizes are given in pairs `(left, right)` starting from the last dimension, and the
+/// supported modes are `constant`, `reflect`, `replicate` and `circular`.
+pub(crate) fn pad<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    pad: &[usize],
+    mode: &str,
+    value: Option<f64>,
+) -> NdArrayTensor<E, D> {
+    if !pad.len().is_multiple_of(2) || pad.len() / 2 > D {
+        panic!("Invalid padding {pad:?} for a tensor with {D} dimensions");
+    }
+
+    let mut array = tensor.array;
+
+    for (i, padding) in pad.chunks(2).enumerate() {
+        let dim = D - 1 - i;
+        let (left, right) = (padding[0], padding[1]);
+        let size = array.shape()[dim];
+
+        array = match mode {
+            "constant" => {
+                let mut shape = array.shape().to_vec();
+                shape[dim] = left + size + right;
+                let elem = value.unwrap_or(0.0).elem();
+
+                let mut output = Array::from_elem(IxDyn(&shape), elem);
+                output
+                    .slice_axis_mut(Axis(dim), Slice::from(left..left + size))
+                    .assign(&array);
+                output.into_shared()
+            }
+            "reflect" | "replicate" | "circular" => {
+                if mode == "reflect" && (left >= size || right >= size) {
+                    panic!("Reflect padding ({left}, {right}) must be smaller than the dimension size {size}");
+                }
+                let indexes: Vec<usize> = (0..left + size + right)
+                    .map(|i| {
+                        let i = i as i64 - left as i64;
+                        let size = size as i64;
+                        let index = match mode {
+                            "reflect" if i < 0 => -i,
+                            "reflect" if i >= size => 2 * (size - 1) - i,
+                            "replicate" => i.clamp(0, size - 1),
+                            "circular" => i.rem_euclid(size),
+                            _ => i,
+                        };
+                        index as usize
+                    })
+                    .collect();
+
+                array.select(Axis(dim), &indexes).into_shared()
+            }
+            _ => panic!("Unsupported padding mode {mode}"),
+        };
+    }
+
+    NdArrayTensor::new(array)
+}
@@ -5,7 +5,10 @@ use core::ops::Range;
 use ndarray::{Array, Axis};
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{
+    einsum::einsum, interpolate::upsample_bilinear2d, matmul::matmul, padding::pad, NdArrayMathOps,
+    NdArrayOps,
+};
 use crate::element::FloatNdArrayElement;
 use crate::{tensor::NdArrayTensor, NdArrayBackend};
 use crate::{NdArrayDevice, SEED};
@@ -425,15 +428,16 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
     ) -> Vec<NdArrayTensor<E, D2>> {
         let array: Vec<_> = tensor
             .array
-            .axis_chunks_iter(Axis(dim), 1)
-            .map(|a| NdArrayTensor::new(a.to_owned().into()))
+            .axis_iter(Axis(dim))
+            .map(|a| NdArrayTensor::new(a.to_owned().into_shared()))
             .collect();
         array
     }
     fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
-        let mut array = tensor.array.to_owned();
+        let mut array = tensor.array.into_owned();
         array.accumulate_axis_inplace(Axis(dim), |x, sum| *sum += *x);
-        tensor
+
+        NdArrayTensor::new(array.into_shared())
     }
     fn stack<const D: usize, const D2: usize>(
         tensors: Vec<NdArrayTensor<E, D>>,
@@ -448,7 +452,7 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         start: usize,
         length: usize,
     ) -> NdArrayTensor<E, D> {
-        unimplemented!()
+        NdArrayOps::narrow(tensor, dim, start, length)
     }
     fn upsample_linear1d<const D: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D>,
@@ -486,18 +490,18 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
     }
     fn pad<const D: usize>(
         tensor: NdArrayTensor<E, D>,
-        pad: &[usize],
+        pad_size: &[usize],
         mode: &str,
         value: impl Into<Option<f64>>,
     ) -> NdArrayTensor<E, D> {
-        unimplemented!()
+        pad(tensor, pad_size, mode, value.into())
     }
     fn expand<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         size: Vec<usize>,
-        implicit: bool,
+        _implicit: bool,
     ) -> NdArrayTensor<E, D> {
-        unimplemented!()
+        NdArrayOps::expand(tensor, size)
     }
     fn upsample_bilinear2d<const D: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D>,
@@ -506,17 +510,28 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         scales_h: impl Into<Option<f64>>,
         scales_w: impl Into<Option<f64>>,
     ) -> NdArrayTensor<E, D2> {
-        unimplemented!()
+        if output_size.len() != 2 {
+            panic!("Invalid output size {output_size:?}, expected [height, width]");
+        }
+
+        let output = upsample_bilinear2d(
+            NdArrayTensor::new(tensor.array),
+            [output_size[0], output_size[1]],
+            align_corners,
+            [scales_h.into(), scales_w.into()],
+        );
+
+        NdArrayTensor::new(output.array)
     }
     fn select<const D: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: i64,
         index: i64,
     ) -> NdArrayTensor<E, D2> {
-        unimplemented!()
+        NdArrayOps::select(tensor, dim, index)
     }
     fn flip<const D: usize>(tensor: NdArrayTensor<E, D>, dims: Vec<usize>) -> NdArrayTensor<E, D> {
-        NdArrayTensor::new(tensor.array.reversed_axes())
+        NdArrayOps::flip(tensor, dims)
     }
     fn permute<const D: usize>(
         tensor: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
@@ -530,13 +545,13 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         tensor1: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         tensor2: <NdArrayBackend<E> as Backend>::TensorPrimitive<D2>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D3> {
-        unimplemented!()
+        einsum(equation, tensor1, tensor2)
     }
     fn index_tch<const D: usize, const D2: usize>(
         tensor: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         indices: Vec<<NdArrayBackend<E> as Backend>::IntTensorPrimitive<D>>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D2> {
-        unimplemented!()
+        NdArrayOps::index_tch(tensor, indices)
     }
     fn repeat_interleave_self_int<const D: usize, const D2: usize>(
         tensor: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
@@ -544,20 +559,20 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         dim: Option<usize>,
         output_size: Option<usize>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D2> {
-        unimplemented!()
+        NdArrayOps::repeat_interleave(tensor, repeats, dim, output_size)
     }
     fn where_self<const D: usize>(
         tensor: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         condition: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
         other: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        unimplemented!()
+        NdArrayOps::where_self(tensor, condition, other)
     }
     fn copy_<const D: usize>(
         tensor: &mut <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         src: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) {
-        unimplemented!()
+        NdArrayOps::copy_(tensor, src)
     }
 }
 
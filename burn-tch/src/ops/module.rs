@@ -1,6 +1,7 @@
 use crate::{element::TchElement, TchBackend, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndexes, ModuleOps,
+    ConvOptions, ConvTransposeOptions, MaxPool1dWithIndexes, MaxPool2dBackward,
+    MaxPool2dWithIndexes, MaxPool3dBackward, MaxPool3dWithIndexes, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
@@ -66,6 +67,25 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn conv3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.dilation.map(|i| i as i64),
+            options.groups as i64,
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn conv_transpose2d(
         x: TchTensor<E, 4>,
         weight: TchTensor<E, 4>,
@@ -86,6 +106,26 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn conv_transpose3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv_transpose3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.padding_out.map(|i| i as i64),
+            options.groups as i64,
+            options.dilation.map(|i| i as i64),
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn conv_transpose1d(
         x: TchTensor<E, 3>,
         weight: TchTensor<E, 3>,
@@ -106,6 +146,60 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn avg_pool1d(
+        x: TchTensor<E, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> TchTensor<E, 3> {
+        let tensor = tch::Tensor::avg_pool1d(
+            &x.tensor,
+            [kernel_size as i64],
+            [stride as i64],
+            [padding as i64],
+            false,
+            true,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool1d(
+        x: TchTensor<E, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> TchTensor<E, 3> {
+        let tensor = tch::Tensor::max_pool1d(
+            &x.tensor,
+            [kernel_size as i64],
+            [stride as i64],
+            [padding as i64],
+            [1],
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool1d_with_indexes(
+        x: TchTensor<E, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> MaxPool1dWithIndexes<TchBackend<E>> {
+        let (tensor, indexes) = tch::Tensor::max_pool1d_with_indices(
+            &x.tensor,
+            [kernel_size as i64],
+            [stride as i64],
+            [padding as i64],
+            [1],
+            false,
+        );
+
+        MaxPool1dWithIndexes::new(TchTensor::new(tensor), TchTensor::new(indexes))
+    }
+
     fn avg_pool2d(
         x: TchTensor<E, 4>,
         kernel_size: [usize; 2],
@@ -203,4 +297,120 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
 
         MaxPool2dBackward::new(TchTensor::new(grad))
     }
+
+    fn avg_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            false,
+            true,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d_backward(
+        x: TchTensor<E, 5>,
+        grad: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d_backward(
+            &x.tensor,
+            &grad.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            false,
+            true,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::max_pool3d(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            [1, 1, 1],
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d_with_indexes(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<TchBackend<E>> {
+        let (tensor, indexes) = tch::Tensor::max_pool3d_with_indices(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            [1, 1, 1],
+            false,
+        );
+
+        MaxPool3dWithIndexes::new(TchTensor::new(tensor), TchTensor::new(indexes))
+    }
+
+    fn max_pool3d_with_indexes_backward(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        output_grad: TchTensor<E, 5>,
+        indexes: TchTensor<i64, 5>,
+    ) -> MaxPool3dBackward<TchBackend<E>> {
+        let grad = tch::Tensor::max_pool3d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            [1, 1, 1],
+            false,
+            &indexes.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
+    fn adaptive_avg_pool1d(x: TchTensor<E, 3>, output_size: usize) -> TchTensor<E, 3> {
+        let tensor = tch::Tensor::adaptive_avg_pool1d(&x.tensor, [output_size as i64]);
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool2d(x: TchTensor<E, 4>, output_size: [usize; 2]) -> TchTensor<E, 4> {
+        let tensor = tch::Tensor::adaptive_avg_pool2d(&x.tensor, output_size.map(|i| i as i64));
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool2d_backward(x: TchTensor<E, 4>, grad: TchTensor<E, 4>) -> TchTensor<E, 4> {
+        let tensor = tch::Tensor::internal_adaptive_avg_pool2d_backward(&x.tensor, &grad.tensor);
+
+        TchTensor::new(tensor)
+    }
 }
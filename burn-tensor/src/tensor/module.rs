@@ -48,6 +48,24 @@ where
     ))
 }
 
+/// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
+pub fn conv3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d).
 pub fn conv_transpose1d<B>(
     x: Tensor<B, 3>,
@@ -84,6 +102,65 @@ where
     ))
 }
 
+/// Applies a [3D transposed convolution](crate::ops::ModuleOps::conv_transpose3d).
+pub fn conv_transpose3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv_transpose3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
+/// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
+pub fn max_pool1d<B>(
+    x: Tensor<B, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(B::max_pool1d(x.primitive, kernel_size, stride, padding))
+}
+
+/// Applies a [1D avg pooling](crate::ops::ModuleOps::avg_pool1d).
+pub fn avg_pool1d<B>(
+    x: Tensor<B, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(B::avg_pool1d(x.primitive, kernel_size, stride, padding))
+}
+
+/// Applies a [1D max pooling with indexes](crate::ops::ModuleOps::max_pool1d_with_indexes).
+pub fn max_pool1d_with_indexes<B>(
+    x: Tensor<B, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> (Tensor<B, 3>, Tensor<B, 3, Int>)
+where
+    B: Backend,
+{
+    let output = B::max_pool1d_with_indexes(x.primitive, kernel_size, stride, padding);
+
+    (Tensor::new(output.output), Tensor::new(output.indexes))
+}
+
 /// Applies a [2D max pooling](crate::ops::ModuleOps::max_pool2d).
 pub fn max_pool2d<B>(
     x: Tensor<B, 4>,
@@ -124,3 +201,60 @@ where
 
     (Tensor::new(output.output), Tensor::new(output.indexes))
 }
+
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::max_pool3d(x.primitive, kernel_size, stride, padding))
+}
+
+/// Applies a [3D avg pooling](crate::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::avg_pool3d(x.primitive, kernel_size, stride, padding))
+}
+
+/// Applies a [3D max pooling with indexes](crate::ops::ModuleOps::max_pool3d_with_indexes).
+pub fn max_pool3d_with_indexes<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> (Tensor<B, 5>, Tensor<B, 5, Int>)
+where
+    B: Backend,
+{
+    let output = B::max_pool3d_with_indexes(x.primitive, kernel_size, stride, padding);
+
+    (Tensor::new(output.output), Tensor::new(output.indexes))
+}
+
+/// Applies a [1D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool1d).
+pub fn adaptive_avg_pool1d<B>(x: Tensor<B, 3>, output_size: usize) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
+}
+
+/// Applies a [2D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool2d).
+pub fn adaptive_avg_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_avg_pool2d(x.primitive, output_size))
+}
@@ -1,4 +1,4 @@
-use super::conv;
+use super::{conv, pool};
 use crate::backend::Backend;
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -30,6 +30,64 @@ pub struct Conv1dBackward<B: Backend> {
     pub bias_grad: Option<B::TensorPrimitive<1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [conv_transpose2d](ModuleOps::conv_transpose2d).
+#[derive(new)]
+pub struct ConvTranspose2dBackward<B: Backend> {
+    pub x_grad: B::TensorPrimitive<4>,
+    pub weights_grad: B::TensorPrimitive<4>,
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [conv_transpose1d](ModuleOps::conv_transpose1d).
+#[derive(new)]
+pub struct ConvTranspose1dBackward<B: Backend> {
+    pub x_grad: B::TensorPrimitive<3>,
+    pub weights_grad: B::TensorPrimitive<3>,
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [conv3d](ModuleOps::conv3d).
+#[derive(new)]
+pub struct Conv3dBackward<B: Backend> {
+    pub x_grad: B::TensorPrimitive<5>,
+    pub weights_grad: B::TensorPrimitive<5>,
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [conv_transpose3d](ModuleOps::conv_transpose3d).
+#[derive(new)]
+pub struct ConvTranspose3dBackward<B: Backend> {
+    pub x_grad: B::TensorPrimitive<5>,
+    pub weights_grad: B::TensorPrimitive<5>,
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    pub x_grad: B::TensorPrimitive<5>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indexes).
+#[derive(new)]
+pub struct MaxPool3dWithIndexes<B: Backend> {
+    pub output: B::TensorPrimitive<5>,
+    pub indexes: B::IntTensorPrimitive<5>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
+#[derive(new)]
+pub struct MaxPool1dBackward<B: Backend> {
+    pub x_grad: B::TensorPrimitive<3>,
+}
+
+/// Results from [max_pool1d](ModuleOps::max_pool1d_with_indexes).
+#[derive(new)]
+pub struct MaxPool1dWithIndexes<B: Backend> {
+    pub output: B::TensorPrimitive<3>,
+    pub indexes: B::IntTensorPrimitive<3>,
+}
+
 /// Convolution options.
 #[derive(new, Debug, Clone)]
 pub struct ConvOptions<const N: usize> {
@@ -96,6 +154,16 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv2dBackward<B> {
         conv::conv2d_backward(x, weight, bias, output_grad, options)
     }
+    /// Backward pass for the [conv transpose 2d](ModuleOps::conv_transpose2d) operation.
+    fn conv_transpose2d_backward(
+        x: B::TensorPrimitive<4>,
+        weight: B::TensorPrimitive<4>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<4>,
+        options: ConvTransposeOptions<2>,
+    ) -> ConvTranspose2dBackward<B> {
+        conv::conv_transpose2d_backward(x, weight, bias, output_grad, options)
+    }
     /// One dimensional convolution.
     ///
     /// # Shapes
@@ -136,6 +204,132 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv1dBackward<B> {
         conv::conv1d_backward(x, weight, bias, output_grad, options)
     }
+    /// Backward pass for the [conv transpose 1d](ModuleOps::conv_transpose1d) operation.
+    fn conv_transpose1d_backward(
+        x: B::TensorPrimitive<3>,
+        weight: B::TensorPrimitive<3>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<3>,
+        options: ConvTransposeOptions<1>,
+    ) -> ConvTranspose1dBackward<B> {
+        conv::conv_transpose1d_backward(x, weight, bias, output_grad, options)
+    }
+    /// Three dimensional convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      [batch_size, channels_in, depth, height, width],
+    /// weight: [channels_out, channels_in, kernel_size_1, kernel_size_2, kernel_size_3],
+    /// bias:   [channels_out],
+    fn conv3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvOptions<3>,
+    ) -> B::TensorPrimitive<5>;
+    /// Three dimensional transposed convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      [batch_size, channels_in, depth, height, width],
+    /// weight: [channels_in, channels_out, kernel_size_1, kernel_size_2, kernel_size_3],
+    /// bias:   [channels_out],
+    fn conv_transpose3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> B::TensorPrimitive<5>;
+    /// Backward pass for the [conv3d](ModuleOps::conv3d) operation.
+    fn conv3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv3d_backward(x, weight, bias, output_grad, options)
+    }
+    /// Backward pass for the [conv transpose 3d](ModuleOps::conv_transpose3d) operation.
+    fn conv_transpose3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvTransposeOptions<3>,
+    ) -> ConvTranspose3dBackward<B> {
+        conv::conv_transpose3d_backward(x, weight, bias, output_grad, options)
+    }
+    /// One dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn avg_pool1d(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> B::TensorPrimitive<3> {
+        pool::avg_pool1d_from_2d::<B>(x, kernel_size, stride, padding)
+    }
+    /// Backward pass for the [avg pooling 1d](ModuleOps::avg_pool1d) operation.
+    fn avg_pool1d_backward(
+        x: B::TensorPrimitive<3>,
+        grad: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> B::TensorPrimitive<3> {
+        pool::avg_pool1d_backward_from_2d::<B>(x, grad, kernel_size, stride, padding)
+    }
+
+    /// One dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn max_pool1d(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> B::TensorPrimitive<3> {
+        pool::max_pool1d_from_2d::<B>(x, kernel_size, stride, padding)
+    }
+
+    /// One dimensional max pooling with indexes.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn max_pool1d_with_indexes(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> MaxPool1dWithIndexes<B> {
+        pool::max_pool1d_with_indexes_from_2d::<B>(x, kernel_size, stride, padding)
+    }
+    /// Backward pass for the [max pooling 1d](ModuleOps::max_pool1d_with_indexes) operation.
+    fn max_pool1d_with_indexes_backward(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        output_grad: B::TensorPrimitive<3>,
+        indexes: B::IntTensorPrimitive<3>,
+    ) -> MaxPool1dBackward<B> {
+        pool::max_pool1d_with_indexes_backward_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            output_grad,
+            indexes,
+        )
+    }
+
     /// Two dimensional avg pooling.
     ///
     /// # Shapes
@@ -188,4 +382,94 @@ pub trait ModuleOps<B: Backend> {
         output_grad: B::TensorPrimitive<4>,
         indexes: B::IntTensorPrimitive<4>,
     ) -> MaxPool2dBackward<B>;
+
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> B::TensorPrimitive<5>;
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: B::TensorPrimitive<5>,
+        grad: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> B::TensorPrimitive<5>;
+
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> B::TensorPrimitive<5>;
+
+    /// Three dimensional max pooling with indexes.
+    ///
+    /// The indexes point into the flattened depth, height and width dimensions of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indexes(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<B>;
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indexes) operation.
+    fn max_pool3d_with_indexes_backward(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        output_grad: B::TensorPrimitive<5>,
+        indexes: B::IntTensorPrimitive<5>,
+    ) -> MaxPool3dBackward<B>;
+
+    /// One dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_avg_pool1d(x: B::TensorPrimitive<3>, output_size: usize) -> B::TensorPrimitive<3> {
+        pool::adaptive_avg_pool1d_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 1d](ModuleOps::adaptive_avg_pool1d) operation.
+    fn adaptive_avg_pool1d_backward(
+        x: B::TensorPrimitive<3>,
+        grad: B::TensorPrimitive<3>,
+    ) -> B::TensorPrimitive<3> {
+        pool::adaptive_avg_pool1d_backward_from_2d::<B>(x, grad)
+    }
+
+    /// Two dimensional adaptive avg pooling.
+    ///
+    /// Each output element is the average of the input region
+    /// `[floor(i * size_in / size_out), ceil((i + 1) * size_in / size_out))`
+    /// along every spatial dimension.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_avg_pool2d(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> B::TensorPrimitive<4>;
+    /// Backward pass for the [adaptive avg pooling 2d](ModuleOps::adaptive_avg_pool2d) operation.
+    fn adaptive_avg_pool2d_backward(
+        x: B::TensorPrimitive<4>,
+        grad: B::TensorPrimitive<4>,
+    ) -> B::TensorPrimitive<4>;
 }
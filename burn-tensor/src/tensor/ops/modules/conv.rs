@@ -1,4 +1,7 @@
-use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
+use super::{
+    Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTranspose1dBackward,
+    ConvTranspose2dBackward, ConvTranspose3dBackward, ConvTransposeOptions,
+};
 use crate::{backend::Backend, Shape};
 use libm::ceilf;
 
@@ -162,6 +165,258 @@ pub(crate) fn conv2d_backward<B: Backend>(
     )
 }
 
+/// Calculate the [1D convolution transpose](crate::ops::ModuleOps::conv_transpose1d) backward pass using convolutions.
+pub(crate) fn conv_transpose1d_backward<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    weight: B::TensorPrimitive<3>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<3>,
+    options: ConvTransposeOptions<1>,
+) -> ConvTranspose1dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, channels_in, length_in] = B::shape(&x).dims;
+    let [_batch_size, channels_out, length_out] = B::shape(&output_grad).dims;
+
+    let mut x_grad = B::conv1d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+    if B::shape(&x_grad).dims[2] != length_in {
+        x_grad = B::index(x_grad, [0..batch_size, 0..channels_in, 0..length_in]);
+    }
+
+    let weight_grad = conv_transpose1d_weight_grad::<B>(
+        x,
+        B::zeros(weight_shape, &weight_device),
+        output_grad.clone(),
+        options,
+    );
+
+    ConvTranspose1dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(grad, Shape::new([channels_out, batch_size * length_out]));
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
+/// Calculate the [2D convolution transpose](crate::ops::ModuleOps::conv_transpose2d) backward pass using convolutions.
+pub(crate) fn conv_transpose2d_backward<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    weight: B::TensorPrimitive<4>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<4>,
+    options: ConvTransposeOptions<2>,
+) -> ConvTranspose2dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, channels_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, channels_out, height_out, width_out] = B::shape(&output_grad).dims;
+
+    let mut x_grad = B::conv2d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+    let [_, _, height, width] = B::shape(&x_grad).dims;
+    if height != height_in || width != width_in {
+        x_grad = B::index(
+            x_grad,
+            [0..batch_size, 0..channels_in, 0..height_in, 0..width_in],
+        );
+    }
+
+    let weight_grad = conv_transpose2d_weight_grad::<B>(
+        x,
+        B::zeros(weight_shape, &weight_device),
+        output_grad.clone(),
+        options,
+    );
+
+    ConvTranspose2dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([channels_out, batch_size * height_out * width_out]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
+/// Calculate the [3D convolution](crate::ops::ModuleOps::conv3d) backward pass using convolutions.
+pub(crate) fn conv3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> Conv3dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+    let [channels_out, _, kernel_size_1, kernel_size_2, kernel_size_3] = weight_shape.dims;
+
+    let padding_1_out = calculate_padding_out(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+        depth_out,
+    );
+    let padding_2_out = calculate_padding_out(
+        kernel_size_2,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        height_in,
+        height_out,
+    );
+    let padding_3_out = calculate_padding_out(
+        kernel_size_3,
+        options.stride[2],
+        options.padding[2],
+        options.dilation[2],
+        width_in,
+        width_out,
+    );
+
+    let x_grad = B::conv_transpose3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            [padding_1_out, padding_2_out, padding_3_out],
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    let weight_grad = match options.groups == 1 {
+        true => conv3d_weight_grad_no_groups::<B>(x, output_grad.clone(), weight_shape, options),
+        false => conv3d_weight_grad_groups::<B>(
+            x,
+            B::zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    Conv3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
+/// Calculate the [3D convolution transpose](crate::ops::ModuleOps::conv_transpose3d) backward pass using convolutions.
+pub(crate) fn conv_transpose3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvTransposeOptions<3>,
+) -> ConvTranspose3dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, channels_out, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+
+    let mut x_grad = B::conv3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+    let [_, _, depth, height, width] = B::shape(&x_grad).dims;
+    if depth != depth_in || height != height_in || width != width_in {
+        x_grad = B::index(
+            x_grad,
+            [
+                0..batch_size,
+                0..channels_in,
+                0..depth_in,
+                0..height_in,
+                0..width_in,
+            ],
+        );
+    }
+
+    let weight_grad = conv_transpose3d_weight_grad::<B>(
+        x,
+        B::zeros(weight_shape, &weight_device),
+        output_grad.clone(),
+        options,
+    );
+
+    ConvTranspose3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
 /// Execute a 1D convolution using a 2D convolution.
 pub(crate) fn conv1d_from_conv2d<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -304,6 +559,204 @@ fn conv2d_weight_grad_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    mut weight_grad: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_out, increment_ci, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight_grad).dims;
+    let increment_co = channels_out / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let range_ci = g * increment_ci..(g + 1) * increment_ci;
+        let range_co = g * increment_co..(g + 1) * increment_co;
+
+        let x = B::index(x_swapped.clone(), [range_ci]);
+        let grad = B::index(output_grad_swapped.clone(), [range_co.clone()]);
+        let mut weight_grad_tmp = B::conv3d(
+            x,
+            grad,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [
+                0..increment_co,
+                0..increment_ci,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [
+                range_co,
+                0..increment_ci,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+/// The weight gradient of a transposed convolution is a convolution of the output gradient by
+/// the input, where the roles of the stride and the dilation are swapped.
+fn conv_transpose1d_weight_grad<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    mut weight_grad: B::TensorPrimitive<3>,
+    output_grad: B::TensorPrimitive<3>,
+    options: ConvTransposeOptions<1>,
+) -> B::TensorPrimitive<3> {
+    let [channels_in, increment_co, kernel_size] = B::shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let range_ci = g * increment_ci..(g + 1) * increment_ci;
+        let range_co = g * increment_co..(g + 1) * increment_co;
+
+        let x = B::index(x_swapped.clone(), [range_ci.clone()]);
+        let grad = B::index(output_grad_swapped.clone(), [range_co]);
+        let mut weight_grad_tmp = B::conv1d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [0..increment_ci, 0..increment_co, 0..kernel_size],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [range_ci, 0..increment_co, 0..kernel_size],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+/// The weight gradient of a transposed convolution is a convolution of the output gradient by
+/// the input, where the roles of the stride and the dilation are swapped.
+fn conv_transpose2d_weight_grad<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    mut weight_grad: B::TensorPrimitive<4>,
+    output_grad: B::TensorPrimitive<4>,
+    options: ConvTransposeOptions<2>,
+) -> B::TensorPrimitive<4> {
+    let [channels_in, increment_co, kernel_size_1, kernel_size_2] = B::shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let range_ci = g * increment_ci..(g + 1) * increment_ci;
+        let range_co = g * increment_co..(g + 1) * increment_co;
+
+        let x = B::index(x_swapped.clone(), [range_ci.clone()]);
+        let grad = B::index(output_grad_swapped.clone(), [range_co]);
+        let mut weight_grad_tmp = B::conv2d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [
+                0..increment_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+            ],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [
+                range_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+/// The weight gradient of a transposed convolution is a convolution of the output gradient by
+/// the input, where the roles of the stride and the dilation are swapped.
+fn conv_transpose3d_weight_grad<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    mut weight_grad: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_in, increment_co, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let range_ci = g * increment_ci..(g + 1) * increment_ci;
+        let range_co = g * increment_co..(g + 1) * increment_co;
+
+        let x = B::index(x_swapped.clone(), [range_ci.clone()]);
+        let grad = B::index(output_grad_swapped.clone(), [range_co]);
+        let mut weight_grad_tmp = B::conv3d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [
+                0..increment_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [
+                range_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
 fn conv1d_weight_grad_no_groups<B: Backend>(
     x: B::TensorPrimitive<3>,
     output_grad: B::TensorPrimitive<3>,
@@ -363,6 +816,37 @@ fn conv2d_weight_grad_no_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_no_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    weight_shape: Shape<5>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        x_swapped,
+        output_grad_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::shape(&weight_grad) != weight_shape {
+        weight_grad = B::index(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
 fn calculate_padding_out(
     kernel_size: usize,
     stride: usize,
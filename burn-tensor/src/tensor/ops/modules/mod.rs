@@ -1,4 +1,5 @@
 pub mod conv;
+pub mod pool;
 
 mod base;
 
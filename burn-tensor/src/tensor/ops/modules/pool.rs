@@ -0,0 +1,140 @@
+use super::{MaxPool1dBackward, MaxPool1dWithIndexes};
+use crate::{backend::Backend, Shape};
+
+/// Execute a 1D avg pooling using a 2D avg pooling.
+pub(crate) fn avg_pool1d_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::avg_pool2d(x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    let [batch_size, channels, length, _] = B::shape(&x).dims;
+
+    B::reshape(x, Shape::from([batch_size, channels, length]))
+}
+
+/// Execute the backward pass of a 1D avg pooling using a 2D avg pooling.
+pub(crate) fn avg_pool1d_backward_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    grad: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length_in] = B::shape(&x).dims;
+    let [_, _, length_out] = B::shape(&grad).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length_in, 1]));
+    let grad_x = B::reshape(grad, Shape::from([batch_size, channels, length_out, 1]));
+
+    let grad_x = B::avg_pool2d_backward(x, grad_x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    B::reshape(grad_x, Shape::from([batch_size, channels, length_in]))
+}
+
+/// Execute a 1D max pooling using a 2D max pooling.
+pub(crate) fn max_pool1d_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::max_pool2d(x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    let [batch_size, channels, length, _] = B::shape(&x).dims;
+
+    B::reshape(x, Shape::from([batch_size, channels, length]))
+}
+
+/// Execute a 1D max pooling with indexes using a 2D max pooling with indexes.
+pub(crate) fn max_pool1d_with_indexes_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> MaxPool1dWithIndexes<B> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let output = B::max_pool2d_with_indexes(x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    let [batch_size, channels, length, _] = B::shape(&output.output).dims;
+
+    let output_reshaped = B::reshape(output.output, Shape::from([batch_size, channels, length]));
+    let indexes_reshaped =
+        B::int_reshape(output.indexes, Shape::from([batch_size, channels, length]));
+
+    MaxPool1dWithIndexes::new(output_reshaped, indexes_reshaped)
+}
+
+/// Execute the backward pass of a 1D max pooling using a 2D max pooling.
+pub(crate) fn max_pool1d_with_indexes_backward_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    output_grad: B::TensorPrimitive<3>,
+    indexes: B::IntTensorPrimitive<3>,
+) -> MaxPool1dBackward<B> {
+    let [batch_size, channels, length_in] = B::shape(&x).dims;
+    let [_, _, length_out] = B::shape(&output_grad).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length_in, 1]));
+    let grad_x = B::reshape(
+        output_grad,
+        Shape::from([batch_size, channels, length_out, 1]),
+    );
+    let indexes = B::int_reshape(indexes, Shape::from([batch_size, channels, length_out, 1]));
+
+    let grad_x = B::max_pool2d_with_indexes_backward(
+        x,
+        [kernel_size, 1],
+        [stride, 1],
+        [padding, 0],
+        grad_x,
+        indexes,
+    )
+    .x_grad;
+
+    MaxPool1dBackward::new(B::reshape(
+        grad_x,
+        Shape::from([batch_size, channels, length_in]),
+    ))
+}
+
+/// Execute a 1D adaptive avg pooling using a 2D adaptive avg pooling.
+pub(crate) fn adaptive_avg_pool1d_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    output_size: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::adaptive_avg_pool2d(x, [output_size, 1]);
+
+    B::reshape(x, Shape::from([batch_size, channels, output_size]))
+}
+
+/// Execute the backward pass of a 1D adaptive avg pooling using a 2D adaptive avg pooling.
+pub(crate) fn adaptive_avg_pool1d_backward_from_2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    grad: B::TensorPrimitive<3>,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length_in] = B::shape(&x).dims;
+    let [_, _, length_out] = B::shape(&grad).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length_in, 1]));
+    let grad_x = B::reshape(grad, Shape::from([batch_size, channels, length_out, 1]));
+
+    let grad_x = B::adaptive_avg_pool2d_backward(x, grad_x);
+
+    B::reshape(grad_x, Shape::from([batch_size, channels, length_in]))
+}
@@ -20,6 +20,14 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_conv_transpose2d!();
         burn_tensor::testgen_module_max_pool2d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_conv3d!();
+        burn_tensor::testgen_module_conv_transpose3d!();
+        burn_tensor::testgen_module_max_pool3d!();
+        burn_tensor::testgen_module_avg_pool3d!();
+        burn_tensor::testgen_module_max_pool1d!();
+        burn_tensor::testgen_module_avg_pool1d!();
+        burn_tensor::testgen_module_adaptive_avg_pool1d!();
+        burn_tensor::testgen_module_adaptive_avg_pool2d!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -48,6 +56,18 @@ macro_rules! testgen_all {
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_transpose!();
+        burn_tensor::testgen_narrow!();
+        burn_tensor::testgen_pad!();
+        burn_tensor::testgen_expand!();
+        burn_tensor::testgen_upsample_bilinear2d!();
+        burn_tensor::testgen_select!();
+        burn_tensor::testgen_flip!();
+        burn_tensor::testgen_cumsum!();
+        burn_tensor::testgen_einsum!();
+        burn_tensor::testgen_index_tch!();
+        burn_tensor::testgen_repeat_interleave!();
+        burn_tensor::testgen_where_self!();
+        burn_tensor::testgen_copy!();
 
         // test stats
         burn_tensor::testgen_stats!();
@@ -0,0 +1,89 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool2d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool2d_simple() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 4,
+            width: 6,
+            height_out: 2,
+            width_out: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[3.5, 5.5, 7.5], [15.5, 17.5, 19.5]],
+            [[27.5, 29.5, 31.5], [39.5, 41.5, 43.5]],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_dyn_filter_size() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 5,
+            width: 7,
+            height_out: 3,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [4.0, 5.5, 7.5, 9.0],
+                [14.5, 16.0, 18.0, 19.5],
+                [25.0, 26.5, 28.5, 30.0],
+            ],
+            [
+                [39.0, 40.5, 42.5, 44.0],
+                [49.5, 51.0, 53.0, 54.5],
+                [60.0, 61.5, 63.5, 65.0],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_global() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 2,
+            channels: 2,
+            height: 3,
+            width: 4,
+            height_out: 1,
+            width_out: 1,
+        };
+
+        test.assert_output(TestTensor::from_floats([
+            [[[5.5]], [[17.5]]],
+            [[[29.5]], [[41.5]]],
+        ]));
+    }
+
+    struct AdaptiveAvgPool2dTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl AdaptiveAvgPool2dTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = adaptive_avg_pool2d(x, [self.height_out, self.width_out]);
+
+            y.into_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
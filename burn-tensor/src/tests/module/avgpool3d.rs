@@ -0,0 +1,109 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_avg_pool3d_simple() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [[6.5000, 7.5000], [9.5000, 10.5000]],
+            [[15.5000, 16.5000], [18.5000, 19.5000]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            depth: 3,
+            height: 4,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [
+                [0.8333, 1.5000],
+                [2.2500, 3.2500],
+                [4.2500, 5.2500],
+                [3.5000, 4.1667],
+            ],
+            [
+                [17.6667, 19.0000],
+                [28.5000, 30.5000],
+                [32.5000, 34.5000],
+                [23.0000, 24.3333],
+            ],
+        ]]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = avg_pool3d(
+                x,
+                [self.kernel_size_1, self.kernel_size_2, self.kernel_size_3],
+                [self.stride_1, self.stride_2, self.stride_3],
+                [self.padding_1, self.padding_2, self.padding_3],
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
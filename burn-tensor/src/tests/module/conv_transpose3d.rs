@@ -0,0 +1,254 @@
+#[burn_tensor_testgen::testgen(module_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv_transpose3d;
+    use burn_tensor::ops::ConvTransposeOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv_transpose3d_simple() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[128., 280., 154.], [304., 664., 364.], [184., 400., 218.]],
+                [[352., 768., 420.], [832., 1808., 984.], [496., 1072., 580.]],
+                [[256., 552., 298.], [592., 1272., 684.], [344., 736., 394.]],
+            ],
+            [
+                [[193., 425., 235.], [465., 1017., 557.], [281., 609., 331.]],
+                [
+                    [545., 1185., 645.],
+                    [1281., 2769., 1497.],
+                    [753., 1617., 869.],
+                ],
+                [
+                    [385., 825., 443.],
+                    [881., 1881., 1005.],
+                    [505., 1073., 571.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_complex() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 2,
+            groups: 2,
+            depth: 2,
+            height: 3,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [
+                    [0., 6., 0.],
+                    [0., 40., 0.],
+                    [0., 110., 0.],
+                    [0., 128., 0.],
+                    [0., 94., 0.],
+                ],
+                [
+                    [0., 6., 0.],
+                    [0., 40., 0.],
+                    [0., 110., 0.],
+                    [0., 128., 0.],
+                    [0., 94., 0.],
+                ],
+                [
+                    [0., 84., 0.],
+                    [0., 220., 0.],
+                    [0., 416., 0.],
+                    [0., 356., 0.],
+                    [0., 220., 0.],
+                ],
+            ],
+            [
+                [
+                    [1., 19., 1.],
+                    [1., 113., 1.],
+                    [1., 291., 1.],
+                    [1., 297., 1.],
+                    [1., 203., 1.],
+                ],
+                [
+                    [1., 163., 1.],
+                    [1., 401., 1.],
+                    [1., 723., 1.],
+                    [1., 585., 1.],
+                    [1., 347., 1.],
+                ],
+                [
+                    [1., 241., 1.],
+                    [1., 581., 1.],
+                    [1., 1029., 1.],
+                    [1., 813., 1.],
+                    [1., 473., 1.],
+                ],
+            ],
+            [
+                [
+                    [2., 764., 2.],
+                    [2., 1698., 2.],
+                    [2., 2812., 2.],
+                    [2., 2074., 2.],
+                    [2., 1140., 2.],
+                ],
+                [
+                    [2., 908., 2.],
+                    [2., 1986., 2.],
+                    [2., 3244., 2.],
+                    [2., 2362., 2.],
+                    [2., 1284., 2.],
+                ],
+                [
+                    [2., 1130., 2.],
+                    [2., 2454., 2.],
+                    [2., 3982., 2.],
+                    [2., 2878., 2.],
+                    [2., 1554., 2.],
+                ],
+            ],
+            [
+                [
+                    [3., 1065., 3.],
+                    [3., 2347., 3.],
+                    [3., 3857., 3.],
+                    [3., 2819., 3.],
+                    [3., 1537., 3.],
+                ],
+                [
+                    [3., 1353., 3.],
+                    [3., 2923., 3.],
+                    [3., 4721., 3.],
+                    [3., 3395., 3.],
+                    [3., 1825., 3.],
+                ],
+                [
+                    [3., 1575., 3.],
+                    [3., 3391., 3.],
+                    [3., 5459., 3.],
+                    [3., 3911., 3.],
+                    [3., 2095., 3.],
+                ],
+            ],
+        ]]));
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        padding_out_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weights = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weights = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weights.num_elements())
+                    .reshape(shape_weights)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv_transpose3d(
+                x,
+                weights,
+                Some(bias),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.padding_out_1, self.padding_out_2, self.padding_out_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(module_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool1d, max_pool1d_with_indexes};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_max_pool1d_simple() {
+        let kernel_size = 4;
+        let padding = 0;
+        let stride = 1;
+
+        let x = TestTensor::from_floats([[[0.2380, 0.5442, 0.3700, 0.6039, 0.6257, 0.0655]]]);
+        let y = TestTensor::from_floats([[[0.6039, 0.6257, 0.6257]]]);
+
+        let output = max_pool1d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_complex() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestTensor::from_floats([[
+            [0.0132, 0.8375, 0.2594, 0.2343, 0.9956, 0.4703, 0.8365],
+            [0.4764, 0.6391, 0.1506, 0.6349, 0.8680, 0.5232, 0.7413],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [0.8375, 0.8375, 0.9956, 0.8365],
+            [0.6391, 0.6391, 0.8680, 0.7413],
+        ]]);
+
+        let output = max_pool1d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_with_indexes() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestTensor::from_floats([[
+            [0.0132, 0.8375, 0.2594, 0.2343, 0.9956, 0.4703, 0.8365],
+            [0.4764, 0.6391, 0.1506, 0.6349, 0.8680, 0.5232, 0.7413],
+        ]]);
+        let indexes = Data::<i64, 3>::from([[[1, 1, 4, 6], [1, 1, 4, 6]]]);
+        let y = TestTensor::from_floats([[
+            [0.8375, 0.8375, 0.9956, 0.8365],
+            [0.6391, 0.6391, 0.8680, 0.7413],
+        ]]);
+
+        let (output, output_indexes) = max_pool1d_with_indexes(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indexes.value, output_indexes.into_data().value);
+    }
+}
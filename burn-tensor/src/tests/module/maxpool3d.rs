@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indexes};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let kernel_size = [2, 2, 2];
+        let padding = [1, 1, 1];
+        let stride = [1, 1, 1];
+
+        let x = TestTensor::from_floats([[[
+            [
+                [0.0000, 0.3889, 0.7778],
+                [0.1667, 0.5556, 0.9444],
+                [0.3333, 0.7222, 0.1111],
+            ],
+            [
+                [0.5000, 0.8889, 0.2778],
+                [0.6667, 0.0556, 0.4444],
+                [0.8333, 0.2222, 0.6111],
+            ],
+        ]]]);
+        let y = TestTensor::from_floats([[[
+            [
+                [0.0000, 0.3889, 0.7778, 0.7778],
+                [0.1667, 0.5556, 0.9444, 0.9444],
+                [0.3333, 0.7222, 0.9444, 0.9444],
+                [0.3333, 0.7222, 0.7222, 0.1111],
+            ],
+            [
+                [0.5000, 0.8889, 0.8889, 0.7778],
+                [0.6667, 0.8889, 0.9444, 0.9444],
+                [0.8333, 0.8333, 0.9444, 0.9444],
+                [0.8333, 0.8333, 0.7222, 0.6111],
+            ],
+            [
+                [0.5000, 0.8889, 0.8889, 0.2778],
+                [0.6667, 0.8889, 0.8889, 0.4444],
+                [0.8333, 0.8333, 0.6111, 0.6111],
+                [0.8333, 0.8333, 0.6111, 0.6111],
+            ],
+        ]]]);
+
+        let output = max_pool3d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_complex() {
+        let kernel_size = [3, 2, 2];
+        let padding = [1, 0, 1];
+        let stride = [2, 2, 1];
+
+        let x = TestTensor::from_floats([[[
+            [
+                [0.0000, 0.1458, 0.2917, 0.4375],
+                [0.5833, 0.7292, 0.8750, 0.0208],
+                [0.1667, 0.3125, 0.4583, 0.6042],
+                [0.7500, 0.8958, 0.0417, 0.1875],
+            ],
+            [
+                [0.3333, 0.4792, 0.6250, 0.7708],
+                [0.9167, 0.0625, 0.2083, 0.3542],
+                [0.5000, 0.6458, 0.7917, 0.9375],
+                [0.0833, 0.2292, 0.3750, 0.5208],
+            ],
+            [
+                [0.6667, 0.8125, 0.9583, 0.1042],
+                [0.2500, 0.3958, 0.5417, 0.6875],
+                [0.8333, 0.9792, 0.1250, 0.2708],
+                [0.4167, 0.5625, 0.7083, 0.8542],
+            ],
+        ]]]);
+        let y = TestTensor::from_floats([[[
+            [
+                [0.9167, 0.9167, 0.8750, 0.8750, 0.7708],
+                [0.7500, 0.8958, 0.8958, 0.9375, 0.9375],
+            ],
+            [
+                [0.9167, 0.9167, 0.9583, 0.9583, 0.7708],
+                [0.8333, 0.9792, 0.9792, 0.9375, 0.9375],
+            ],
+        ]]]);
+
+        let output = max_pool3d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indexes() {
+        let kernel_size = [2, 2, 2];
+        let padding = [0, 0, 0];
+        let stride = [1, 2, 2];
+
+        let x = TestTensor::from_floats([[[
+            [
+                [0.0000, 0.1458, 0.2917, 0.4375],
+                [0.5833, 0.7292, 0.8750, 0.0208],
+                [0.1667, 0.3125, 0.4583, 0.6042],
+                [0.7500, 0.8958, 0.0417, 0.1875],
+            ],
+            [
+                [0.3333, 0.4792, 0.6250, 0.7708],
+                [0.9167, 0.0625, 0.2083, 0.3542],
+                [0.5000, 0.6458, 0.7917, 0.9375],
+                [0.0833, 0.2292, 0.3750, 0.5208],
+            ],
+            [
+                [0.6667, 0.8125, 0.9583, 0.1042],
+                [0.2500, 0.3958, 0.5417, 0.6875],
+                [0.8333, 0.9792, 0.1250, 0.2708],
+                [0.4167, 0.5625, 0.7083, 0.8542],
+            ],
+        ]]]);
+        let indexes = Data::<i64, 5>::from([[[[[20, 6], [13, 27]], [[20, 34], [41, 27]]]]]);
+        let y = TestTensor::from_floats([[[
+            [[0.9167, 0.8750], [0.8958, 0.9375]],
+            [[0.9167, 0.9583], [0.9792, 0.9375]],
+        ]]]);
+
+        let (output, output_indexes) = max_pool3d_with_indexes(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indexes.value, output_indexes.into_data().value);
+    }
+}
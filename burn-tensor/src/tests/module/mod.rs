@@ -1,7 +1,15 @@
+mod adaptive_avgpool1d;
+mod adaptive_avgpool2d;
+mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod forward;
+mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(copy)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_copy_ops() {
+        let mut tensor = TestTensor::<2>::zeros([2, 2]);
+        let source = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        tensor.copy_(source);
+
+        assert_eq!(tensor.into_data(), Data::from([[1.0, 2.0], [3.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_support_copy_broadcasted_source() {
+        let mut tensor = TestTensor::<2>::zeros([2, 2]);
+        let source = TestTensor::from_data(Data::from([[1.0, 2.0]]));
+
+        tensor.copy_(source);
+
+        assert_eq!(tensor.into_data(), Data::from([[1.0, 2.0], [1.0, 2.0]]));
+    }
+}
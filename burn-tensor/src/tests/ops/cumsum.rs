@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(cumsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_cumsum_ops() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = tensor.cumsum(1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_cumsum_first_dim() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = tensor.cumsum(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]])
+        );
+    }
+}
@@ -0,0 +1,148 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_einsum_matmul() {
+        let (lhs, rhs) = operands_2d();
+
+        let output: TestTensor<2> = Tensor::einsum("ij,jk->ik", lhs, rhs);
+
+        assert_eq!(output.into_data(), Data::from([[13.0, 16.0], [40.0, 52.0]]));
+    }
+
+    #[test]
+    fn should_support_einsum_implicit_output() {
+        let (lhs, rhs) = operands_2d();
+
+        let output: TestTensor<2> = Tensor::einsum("ij,jk", lhs, rhs);
+
+        assert_eq!(output.into_data(), Data::from([[13.0, 16.0], [40.0, 52.0]]));
+    }
+
+    #[test]
+    fn should_support_einsum_permuted_output() {
+        let (lhs, rhs) = operands_2d();
+
+        let output: TestTensor<2> = Tensor::einsum("ij,jk->ki", lhs, rhs);
+
+        assert_eq!(output.into_data(), Data::from([[13.0, 40.0], [16.0, 52.0]]));
+    }
+
+    #[test]
+    fn should_support_einsum_batch_matmul() {
+        let lhs = TestTensor::from_data(Data::from([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[1.0, 0.0, 1.0], [2.0, 2.0, 2.0]],
+        ]));
+        let rhs = TestTensor::from_data(Data::from([
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]],
+        ]));
+
+        let output: TestTensor<3> = Tensor::einsum("bij,bjk->bik", lhs, rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[13.0, 16.0], [40.0, 52.0]], [[2.0, 1.0], [4.0, 4.0]]])
+        );
+    }
+
+    #[test]
+    fn should_support_einsum_ellipsis() {
+        let lhs = TestTensor::from_data(Data::from([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[1.0, 0.0, 1.0], [2.0, 2.0, 2.0]],
+        ]));
+        let (_, rhs) = operands_2d();
+
+        let output: TestTensor<3> = Tensor::einsum("...ij,jk->...ik", lhs, rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[13.0, 16.0], [40.0, 52.0]], [[6.0, 8.0], [18.0, 24.0]]])
+        );
+    }
+
+    #[test]
+    fn should_support_einsum_outer_product() {
+        let lhs = TestTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+        let rhs = TestTensor::from_data(Data::from([4.0, 5.0]));
+
+        let output: TestTensor<2> = Tensor::einsum("i,j->ij", lhs, rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[4.0, 5.0], [8.0, 10.0], [12.0, 15.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_einsum_row_wise_dot_product() {
+        let lhs = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+        let rhs = TestTensor::from_data(Data::from([[4.0, 5.0, 6.0], [1.0, 1.0, 1.0]]));
+
+        let output: TestTensor<1> = Tensor::einsum("ij,ij->i", lhs, rhs);
+
+        assert_eq!(output.into_data(), Data::from([32.0, 15.0]));
+    }
+
+    #[test]
+    fn should_support_einsum_diagonal() {
+        let lhs = TestTensor::from_data(Data::from([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]));
+        let rhs = TestTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+
+        let output: TestTensor<1> = Tensor::einsum("ii,i->i", lhs, rhs);
+
+        assert_eq!(output.into_data(), Data::from([1.0, 10.0, 27.0]));
+    }
+
+    #[test]
+    fn should_support_einsum_summed_dimension() {
+        let (lhs, _) = operands_2d();
+        let rhs = TestTensor::from_data(Data::from([4.0, 5.0]));
+
+        let output: TestTensor<2> = Tensor::einsum("ij,k->jk", lhs, rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[12.0, 15.0], [20.0, 25.0], [28.0, 35.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_einsum_broadcasted_dimension() {
+        let lhs = TestTensor::from_data(Data::from([[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]));
+        let rhs = TestTensor::from_data(Data::from([
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]],
+        ]));
+
+        let output: TestTensor<3> = Tensor::einsum("bij,bjk->bik", lhs, rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[13.0, 16.0], [40.0, 52.0]], [[2.0, 3.0], [8.0, 9.0]]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_subscripts_mismatch_dimensions() {
+        let (lhs, rhs) = operands_2d();
+
+        let _output: TestTensor<2> = Tensor::einsum("ijk,jk->ik", lhs, rhs);
+    }
+
+    fn operands_2d() -> (TestTensor<2>, TestTensor<2>) {
+        let lhs = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+        let rhs = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+
+        (lhs, rhs)
+    }
+}
@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_expand_ops() {
+        let tensor = TestTensor::from_data(Data::from([[1.0], [2.0]]));
+
+        let output = tensor.expand(vec![2, 3], false);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_keep_size_when_expanding_with_max() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0]]));
+
+        let output = tensor.expand(vec![3, usize::MAX], false);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_expanding_non_singleton_dim() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0]]));
+
+        tensor.expand(vec![1, 3], false);
+    }
+}
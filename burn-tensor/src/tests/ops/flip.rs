@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_flip_ops() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = tensor.flip(vec![1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_flip_multiple_dims() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = tensor.flip(vec![0, 1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[5.0, 4.0, 3.0], [2.0, 1.0, 0.0]])
+        );
+    }
+}
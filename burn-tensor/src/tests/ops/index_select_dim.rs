@@ -81,4 +81,18 @@ mod tests {
             Data::from([[4.0, 6.0, 8.0], [4.0, 6.0, 8.0]])
         );
     }
+
+    #[test]
+    fn should_select_assign_2d_dim1() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+        let values = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+        let indexes = TestTensorInt::from_data(Data::from([1, 0, 1]));
+
+        let output = tensor.index_select_dim_assign(1, indexes, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 5.0, 2.0], [8.0, 14.0, 5.0]])
+        );
+    }
 }
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(index_tch)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_index_with_one_index_tensor() {
+        let tensor = tensor_3x3();
+        let indices = vec![TestTensorInt::from_data(Data::from([[2], [0]]))];
+
+        let output: TestTensor<3> = tensor.index_tch(indices);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[6.0, 7.0, 8.0]], [[0.0, 1.0, 2.0]]])
+        );
+    }
+
+    #[test]
+    fn should_support_index_with_multiple_index_tensors() {
+        let tensor = tensor_3x3();
+        let indices = vec![
+            TestTensorInt::from_data(Data::from([[0, 2]])),
+            TestTensorInt::from_data(Data::from([[1, 0]])),
+        ];
+
+        let output: TestTensor<2> = tensor.index_tch(indices);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_support_index_with_broadcasted_negative_indices() {
+        let tensor = tensor_3x3();
+        let indices = vec![
+            TestTensorInt::from_data(Data::from([[-1], [0]])),
+            TestTensorInt::from_data(Data::from([[0, -1]])),
+        ];
+
+        let output: TestTensor<2> = tensor.index_tch(indices);
+
+        assert_eq!(output.into_data(), Data::from([[6.0, 8.0], [0.0, 2.0]]));
+    }
+
+    fn tensor_3x3() -> TestTensor<2> {
+        TestTensor::from_data(Data::from([
+            [0.0, 1.0, 2.0],
+            [3.0, 4.0, 5.0],
+            [6.0, 7.0, 8.0],
+        ]))
+    }
+}
@@ -1,14 +1,20 @@
 mod add;
 mod aggregation;
 mod arg;
+mod copy;
 mod cos;
+mod cumsum;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
+mod expand;
 mod flatten;
+mod flip;
 mod index;
 mod index_select;
 mod index_select_dim;
+mod index_tch;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -16,11 +22,17 @@ mod mask;
 mod matmul;
 mod maxmin;
 mod mul;
+mod narrow;
 mod neg;
+mod pad;
 mod powf;
 mod repeat;
+mod repeat_interleave;
 mod reshape;
+mod select;
 mod sin;
 mod sub;
 mod tanh;
 mod transpose;
+mod upsample_bilinear2d;
+mod where_self;
@@ -0,0 +1,23 @@
+#[burn_tensor_testgen::testgen(narrow)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_narrow_ops() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = tensor.narrow(1, 1, 2);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 2.0], [4.0, 5.0]]));
+    }
+
+    #[test]
+    fn should_support_narrow_first_dim() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = tensor.narrow(0, 1, 1);
+
+        assert_eq!(output.into_data(), Data::from([[3.0, 4.0, 5.0]]));
+    }
+}
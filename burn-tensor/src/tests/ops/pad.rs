@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_constant_pad() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let output = tensor.pad(&[1, 2], "constant", None);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [0.0, 1.0, 2.0, 3.0, 0.0, 0.0],
+                [0.0, 4.0, 5.0, 6.0, 0.0, 0.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_constant_pad_multiple_dims_with_value() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let output = tensor.pad(&[1, 1, 1, 0], "constant", 9.0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [9.0, 9.0, 9.0, 9.0, 9.0],
+                [9.0, 1.0, 2.0, 3.0, 9.0],
+                [9.0, 4.0, 5.0, 6.0, 9.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_reflect_pad() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let output = tensor.pad(&[2, 1], "reflect", None);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+                [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_replicate_pad() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let output = tensor.pad(&[2, 1], "replicate", None);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [1.0, 1.0, 1.0, 2.0, 3.0, 3.0],
+                [4.0, 4.0, 4.0, 5.0, 6.0, 6.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_circular_pad() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let output = tensor.pad(&[2, 1], "circular", None);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [2.0, 3.0, 1.0, 2.0, 3.0, 1.0],
+                [5.0, 6.0, 4.0, 5.0, 6.0, 4.0]
+            ])
+        );
+    }
+}
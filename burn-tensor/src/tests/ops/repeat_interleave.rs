@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(repeat_interleave)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_repeat_interleave_flattened() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        let output: TestTensor<1> = tensor.repeat_interleave_self_int(2, None, None);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0])
+        );
+    }
+
+    #[test]
+    fn should_support_repeat_interleave_dim() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        let output_dim0: TestTensor<2> = tensor.repeat_interleave_self_int(2, Some(0), None);
+        let output_dim1: TestTensor<2> = tensor.repeat_interleave_self_int(2, Some(1), Some(4));
+
+        assert_eq!(
+            output_dim0.into_data(),
+            Data::from([[1.0, 2.0], [1.0, 2.0], [3.0, 4.0], [3.0, 4.0]])
+        );
+        assert_eq!(
+            output_dim1.into_data(),
+            Data::from([[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]])
+        );
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(select)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_select_ops() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output: TestTensor<1> = tensor.select(0, 1);
+
+        assert_eq!(output.into_data(), Data::from([3.0, 4.0, 5.0]));
+    }
+
+    #[test]
+    fn should_support_select_negative_index() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output: TestTensor<1> = tensor.select(1, -1);
+
+        assert_eq!(output.into_data(), Data::from([2.0, 5.0]));
+    }
+
+    #[test]
+    fn should_support_select_negative_dim() {
+        let tensor = TestTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output: TestTensor<1> = tensor.select(-1, 0);
+
+        assert_eq!(output.into_data(), Data::from([0.0, 3.0]));
+    }
+}
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(upsample_bilinear2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_upsample_bilinear2d_align_corners() {
+        let tensor = TestTensor::from_data(Data::from([[[[1.0, 2.0], [3.0, 4.0]]]]));
+
+        let output: TestTensor<4> = tensor.upsample_bilinear2d(vec![3, 3], true, None, None);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_upsample_bilinear2d() {
+        let tensor = TestTensor::from_data(Data::from([[[[1.0, 2.0], [3.0, 4.0]]]]));
+
+        let output: TestTensor<4> = tensor.upsample_bilinear2d(vec![4, 4], false, None, None);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.25, 1.75, 2.0],
+                [1.5, 1.75, 2.25, 2.5],
+                [2.5, 2.75, 3.25, 3.5],
+                [3.0, 3.25, 3.75, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_upsample_bilinear2d_with_scales() {
+        let tensor = TestTensor::from_data(Data::from([[[[1.0, 2.0]]]]));
+
+        let output: TestTensor<4> = tensor.upsample_bilinear2d(vec![1, 4], false, None, 2.0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 1.25, 1.75, 2.0]]]]), 3);
+    }
+}
@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(where_self)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_support_where_ops() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let condition =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+        let other = TestTensor::from_data(Data::from([[5.0, 6.0], [7.0, 8.0]]));
+
+        let output = tensor.where_self(condition, other);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 6.0], [7.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_support_where_broadcasted_other() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let condition =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+        let other = TestTensor::from_data(Data::from([[0.0, -1.0]]));
+
+        let output = tensor.where_self(condition, other);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, -1.0], [0.0, 4.0]]));
+    }
+}
@@ -7,11 +7,16 @@ use std::{
 enum Message<T, V> {
     LogTrain(LearnerItem<T>),
     LogValid(LearnerItem<V>),
+    StartTrain(usize),
+    StartValid(usize),
     ClearTrain(usize),
     ClearValid(usize),
     End,
 }
 
+/// Run a [callback](LearnerCallback) on its own thread.
+///
+/// Only the items and the start and end of epoch hooks are forwarded to the wrapped callback.
 pub struct AsyncTrainerCallback<T, V> {
     sender: mpsc::Sender<Message<T, V>>,
     handler: Option<JoinHandle<()>>,
@@ -31,6 +36,14 @@ impl<T, V> CallbackThread<T, V> {
                     let mut callback = self.callback.lock().unwrap();
                     callback.on_train_item(item);
                 }
+                Message::StartTrain(epoch) => {
+                    let mut callback = self.callback.lock().unwrap();
+                    callback.on_train_start_epoch(epoch);
+                }
+                Message::StartValid(epoch) => {
+                    let mut callback = self.callback.lock().unwrap();
+                    callback.on_valid_start_epoch(epoch);
+                }
                 Message::ClearTrain(epoch) => {
                     let mut callback = self.callback.lock().unwrap();
                     callback.on_train_end_epoch(epoch);
@@ -64,6 +77,14 @@ impl<T: Send + Sync + 'static, V: Send + Sync + 'static> AsyncTrainerCallback<T,
 }
 
 impl<T: Send, V: Send> LearnerCallback<T, V> for AsyncTrainerCallback<T, V> {
+    fn on_train_start_epoch(&mut self, epoch: usize) {
+        self.sender.send(Message::StartTrain(epoch)).unwrap();
+    }
+
+    fn on_valid_start_epoch(&mut self, epoch: usize) {
+        self.sender.send(Message::StartValid(epoch)).unwrap();
+    }
+
     fn on_train_item(&mut self, item: LearnerItem<T>) {
         self.sender.send(Message::LogTrain(item)).unwrap();
     }
@@ -1,13 +1,37 @@
-use burn_core::{data::dataloader::Progress, LearningRate};
+use burn_core::{data::dataloader::Progress, optim::GradientsParams, LearningRate};
 
+/// Hooks called by the [learner](crate::Learner) during training.
+///
+/// All hooks have a default implementation doing nothing, so only the required ones need to be
+/// implemented.
 pub trait LearnerCallback<T, V>: Send {
+    /// Called before the first epoch.
+    fn on_fit_start(&mut self) {}
+    /// Called after the training loop ended, either after the last epoch or early stopping.
+    fn on_fit_end(&mut self) {}
+    /// Called before the training items of an epoch.
+    fn on_train_start_epoch(&mut self, _epoch: usize) {}
+    /// Called before the validation items of an epoch.
+    fn on_valid_start_epoch(&mut self, _epoch: usize) {}
     fn on_train_item(&mut self, _item: LearnerItem<T>) {}
     fn on_valid_item(&mut self, _item: LearnerItem<V>) {}
     fn on_train_end_epoch(&mut self, _epoch: usize) {}
     fn on_valid_end_epoch(&mut self, _epoch: usize) {}
+    /// Called with the gradients that are about to be applied by the optimizer.
+    fn on_before_optimizer_step(
+        &mut self,
+        _epoch: usize,
+        _iteration: usize,
+        _grads: &GradientsParams,
+    ) {
+    }
+    /// Called after the optimizer updated the model.
+    fn on_after_optimizer_step(&mut self, _epoch: usize, _iteration: usize) {}
+    /// Called when a checkpoint has been handed to the checkpointers.
+    fn on_checkpoint_saved(&mut self, _checkpoint: SavedCheckpoint) {}
 }
 
-#[derive(new)]
+#[derive(new, Clone)]
 pub struct LearnerItem<T> {
     pub item: T,
     pub progress: Progress,
@@ -16,3 +40,14 @@ pub struct LearnerItem<T> {
     pub iteration: usize,
     pub lr: Option<LearningRate>,
 }
+
+/// A checkpoint saved by the [learner](crate::Learner).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SavedCheckpoint {
+    /// Checkpoint saved at the end of the given epoch.
+    Epoch(usize),
+    /// Step checkpoint with the given index, saved during the given epoch.
+    Step { epoch: usize, index: usize },
+    /// Best model, found at the given epoch.
+    Best(usize),
+}
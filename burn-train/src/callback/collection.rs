@@ -0,0 +1,190 @@
+use super::{LearnerCallback, LearnerItem, SavedCheckpoint};
+use burn_core::optim::GradientsParams;
+
+/// Dispatch every hook to the user callbacks, then to the main callback.
+///
+/// The main callback receives the items, while the user callbacks receive a clone.
+pub(crate) struct LearnerCallbacks<T, V> {
+    main: Box<dyn LearnerCallback<T, V>>,
+    callbacks: Vec<Box<dyn SharedLearnerCallback<T, V>>>,
+}
+
+/// A [callback](LearnerCallback) receiving the items by reference.
+pub(crate) trait SharedLearnerCallback<T, V>: Send {
+    fn callback(&mut self) -> &mut dyn LearnerCallback<T, V>;
+    fn on_train_item(&mut self, item: &LearnerItem<T>);
+    fn on_valid_item(&mut self, item: &LearnerItem<V>);
+}
+
+/// Share the items with a [callback](LearnerCallback) by cloning them.
+#[derive(new)]
+pub(crate) struct ClonedItemsCallback<C> {
+    callback: C,
+}
+
+impl<T, V, C> SharedLearnerCallback<T, V> for ClonedItemsCallback<C>
+where
+    T: Clone,
+    V: Clone,
+    C: LearnerCallback<T, V>,
+{
+    fn callback(&mut self) -> &mut dyn LearnerCallback<T, V> {
+        &mut self.callback
+    }
+
+    fn on_train_item(&mut self, item: &LearnerItem<T>) {
+        self.callback.on_train_item(item.clone());
+    }
+
+    fn on_valid_item(&mut self, item: &LearnerItem<V>) {
+        self.callback.on_valid_item(item.clone());
+    }
+}
+
+impl<T, V> LearnerCallbacks<T, V> {
+    pub(crate) fn new(
+        main: Box<dyn LearnerCallback<T, V>>,
+        callbacks: Vec<Box<dyn SharedLearnerCallback<T, V>>>,
+    ) -> Self {
+        Self { main, callbacks }
+    }
+
+    fn for_each<F>(&mut self, mut func: F)
+    where
+        F: FnMut(&mut dyn LearnerCallback<T, V>),
+    {
+        for callback in self.callbacks.iter_mut() {
+            func(callback.callback());
+        }
+        func(self.main.as_mut());
+    }
+}
+
+impl<T, V> LearnerCallback<T, V> for LearnerCallbacks<T, V> {
+    fn on_fit_start(&mut self) {
+        self.for_each(|callback| callback.on_fit_start());
+    }
+
+    fn on_fit_end(&mut self) {
+        self.for_each(|callback| callback.on_fit_end());
+    }
+
+    fn on_train_start_epoch(&mut self, epoch: usize) {
+        self.for_each(|callback| callback.on_train_start_epoch(epoch));
+    }
+
+    fn on_valid_start_epoch(&mut self, epoch: usize) {
+        self.for_each(|callback| callback.on_valid_start_epoch(epoch));
+    }
+
+    fn on_train_item(&mut self, item: LearnerItem<T>) {
+        for callback in self.callbacks.iter_mut() {
+            callback.on_train_item(&item);
+        }
+        self.main.on_train_item(item);
+    }
+
+    fn on_valid_item(&mut self, item: LearnerItem<V>) {
+        for callback in self.callbacks.iter_mut() {
+            callback.on_valid_item(&item);
+        }
+        self.main.on_valid_item(item);
+    }
+
+    fn on_train_end_epoch(&mut self, epoch: usize) {
+        self.for_each(|callback| callback.on_train_end_epoch(epoch));
+    }
+
+    fn on_valid_end_epoch(&mut self, epoch: usize) {
+        self.for_each(|callback| callback.on_valid_end_epoch(epoch));
+    }
+
+    fn on_before_optimizer_step(
+        &mut self,
+        epoch: usize,
+        iteration: usize,
+        grads: &GradientsParams,
+    ) {
+        self.for_each(|callback| callback.on_before_optimizer_step(epoch, iteration, grads));
+    }
+
+    fn on_after_optimizer_step(&mut self, epoch: usize, iteration: usize) {
+        self.for_each(|callback| callback.on_after_optimizer_step(epoch, iteration));
+    }
+
+    fn on_checkpoint_saved(&mut self, checkpoint: SavedCheckpoint) {
+        self.for_each(|callback| callback.on_checkpoint_saved(checkpoint));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataloader::Progress;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(new)]
+    struct RecordingCallback {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl LearnerCallback<u32, u32> for RecordingCallback {
+        fn on_fit_start(&mut self) {
+            self.push("fit_start".to_string());
+        }
+
+        fn on_train_item(&mut self, item: LearnerItem<u32>) {
+            self.push(format!("train_item {}", item.item));
+        }
+
+        fn on_checkpoint_saved(&mut self, checkpoint: SavedCheckpoint) {
+            self.push(format!("{checkpoint:?}"));
+        }
+    }
+
+    impl RecordingCallback {
+        fn push(&self, event: String) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", self.name, event));
+        }
+    }
+
+    #[test]
+    fn should_dispatch_hooks_to_user_callbacks_before_main_callback() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let main = Box::new(RecordingCallback::new("main", events.clone()));
+        let user: Box<dyn SharedLearnerCallback<u32, u32>> = Box::new(ClonedItemsCallback::new(
+            RecordingCallback::new("user", events.clone()),
+        ));
+        let mut callbacks = LearnerCallbacks::new(main, vec![user]);
+
+        callbacks.on_fit_start();
+        callbacks.on_train_item(LearnerItem::new(
+            7,
+            Progress {
+                items_processed: 1,
+                items_total: 1,
+            },
+            1,
+            1,
+            1,
+            None,
+        ));
+        callbacks.on_checkpoint_saved(SavedCheckpoint::Epoch(1));
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "user fit_start",
+                "main fit_start",
+                "user train_item 7",
+                "main train_item 7",
+                "user Epoch(1)",
+                "main Epoch(1)",
+            ]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 mod async_callback;
 mod base;
+mod collection;
 
 pub use async_callback::*;
 pub use base::*;
+pub(crate) use collection::*;
@@ -4,6 +4,8 @@ use std::sync::{mpsc, Arc};
 
 enum Message<R> {
     Save(usize, R),
+    SaveBest(usize, R),
+    Sync(mpsc::SyncSender<()>),
     End,
 }
 
@@ -18,6 +20,10 @@ impl<R: Record> CheckpointerThread<R> {
         for item in self.receiver.iter() {
             match item {
                 Message::Save(epoch, state) => self.checkpointer.save(epoch, state).unwrap(),
+                Message::SaveBest(epoch, state) => {
+                    self.checkpointer.save_best(epoch, state).unwrap()
+                }
+                Message::Sync(sender) => sender.send(()).unwrap(),
                 Message::End => {
                     return;
                 }
@@ -32,6 +38,15 @@ pub struct AsyncCheckpointer<E> {
     handler: Option<std::thread::JoinHandle<()>>,
 }
 
+impl<E> AsyncCheckpointer<E> {
+    /// Wait until the checkpoints sent before are saved.
+    fn sync(&self) {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.sender.send(Message::Sync(sender)).unwrap();
+        receiver.recv().unwrap();
+    }
+}
+
 impl<R: Record + 'static> AsyncCheckpointer<R> {
     pub fn new(checkpointer: Arc<dyn Checkpointer<R> + Send + Sync>) -> Self {
         // Only on checkpoint can be done in advance.
@@ -58,8 +73,20 @@ where
     }
 
     fn restore(&self, epoch: usize) -> Result<R, CheckpointerError> {
+        self.sync();
         self.checkpointer.restore(epoch)
     }
+
+    fn save_best(&self, epoch: usize, record: R) -> Result<(), CheckpointerError> {
+        self.sender.send(Message::SaveBest(epoch, record)).unwrap();
+
+        Ok(())
+    }
+
+    fn restore_best(&self) -> Result<R, CheckpointerError> {
+        self.sync();
+        self.checkpointer.restore_best()
+    }
 }
 
 impl<E> Drop for AsyncCheckpointer<E> {
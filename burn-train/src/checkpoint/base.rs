@@ -10,4 +10,18 @@ pub enum CheckpointerError {
 pub trait Checkpointer<R: Record> {
     fn save(&self, epoch: usize, record: R) -> Result<(), CheckpointerError>;
     fn restore(&self, epoch: usize) -> Result<R, CheckpointerError>;
+
+    /// Save the record of the best epoch, replacing the previous one.
+    ///
+    /// By default, the best record isn't kept.
+    fn save_best(&self, _epoch: usize, _record: R) -> Result<(), CheckpointerError> {
+        Ok(())
+    }
+
+    /// Restore the record of the best epoch.
+    fn restore_best(&self) -> Result<R, CheckpointerError> {
+        Err(CheckpointerError::Unknown(
+            "The best record isn't kept by this checkpointer".to_string(),
+        ))
+    }
 }
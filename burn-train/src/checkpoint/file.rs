@@ -22,6 +22,9 @@ impl<FR> FileCheckpointer<FR> {
     fn path_for_epoch(&self, epoch: usize) -> String {
         format!("{}/{}-{}", self.directory, self.name, epoch)
     }
+    fn path_for_best(&self) -> String {
+        format!("{}/{}-best", self.directory, self.name)
+    }
 }
 
 impl<FR, R> Checkpointer<R> for FileCheckpointer<FR>
@@ -65,4 +68,26 @@ where
 
         Ok(record)
     }
+
+    fn save_best(&self, epoch: usize, record: R) -> Result<(), CheckpointerError> {
+        let file_path = self.path_for_best();
+        log::info!("Saving best checkpoint {} to {}", epoch, file_path);
+
+        self.recorder
+            .record(record, file_path.into())
+            .map_err(CheckpointerError::RecorderError)?;
+
+        Ok(())
+    }
+
+    fn restore_best(&self) -> Result<R, CheckpointerError> {
+        let file_path = self.path_for_best();
+        log::info!("Restoring best checkpoint from {}", file_path);
+        let record = self
+            .recorder
+            .load(file_path.into())
+            .map_err(CheckpointerError::RecorderError)?;
+
+        Ok(record)
+    }
 }
@@ -0,0 +1,118 @@
+use super::EvaluationSummary;
+use crate::metric::dashboard::Dashboard;
+use crate::{LearnerCallback, LearnerItem, ValidStep};
+use burn_core::data::dataloader::DataLoader;
+use std::sync::Arc;
+
+/// Evaluator struct running a single pass of a model over a dataset and reporting its metrics.
+///
+/// To create an evaluator, use the [builder](crate::EvaluatorBuilder) struct.
+pub struct Evaluator<V>
+where
+    V: Send + Sync + 'static,
+{
+    pub(super) dashboard: Dashboard<(), V>,
+}
+
+impl<V> Evaluator<V>
+where
+    V: Send + Sync + 'static,
+{
+    /// Run the [validation step](ValidStep) of the model on every item of the dataloader and
+    /// return the metrics aggregated over all the items.
+    ///
+    /// The model is used as is, so it should already be in its inference state, e.g. the
+    /// [valid](burn_core::module::ADModule::valid) module of a trained model.
+    pub fn eval<M, I>(mut self, model: &M, dataloader: Arc<dyn DataLoader<I>>) -> EvaluationSummary
+    where
+        M: ValidStep<I, V>,
+    {
+        log::info!("Executing evaluation");
+        self.dashboard.on_valid_start_epoch(1);
+
+        let mut iterator = dataloader.iter();
+        let mut iteration = 0;
+
+        while let Some(item) = iterator.next() {
+            let progress = iterator.progress();
+            iteration += 1;
+
+            let item = model.step(item);
+            let item = LearnerItem::new(item, progress, 1, 1, iteration, None);
+
+            self.dashboard.on_valid_item(item);
+        }
+
+        let summary = self.dashboard.summary_valid();
+        self.dashboard.on_valid_end_epoch(1);
+        log::info!("Evaluation summary:\n{}", summary);
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::{FileMetricLogger, NoopMetricLogger};
+    use crate::metric::dashboard::{DashboardMetricState, DashboardRenderer, TrainingProgress};
+    use crate::metric::{Adaptor, LossInput, LossMetric};
+    use crate::TestBackend;
+    use burn_core::data::dataloader::{batcher::Batcher, BatchDataLoader, FixBatchStrategy};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::tensor::Tensor;
+
+    struct TestBatcher;
+    struct TestModel;
+    struct TestOutput(f32);
+    struct TestRenderer;
+
+    impl Batcher<usize, Vec<usize>> for TestBatcher {
+        fn batch(&self, items: Vec<usize>) -> Vec<usize> {
+            items
+        }
+    }
+
+    impl ValidStep<Vec<usize>, TestOutput> for TestModel {
+        fn step(&self, item: Vec<usize>) -> TestOutput {
+            TestOutput(item.iter().sum::<usize>() as f32 / item.len() as f32)
+        }
+    }
+
+    impl Adaptor<LossInput<TestBackend>> for TestOutput {
+        fn adapt(&self) -> LossInput<TestBackend> {
+            LossInput::new(Tensor::from_floats([self.0]))
+        }
+    }
+
+    impl DashboardRenderer for TestRenderer {
+        fn update_train(&mut self, _state: DashboardMetricState) {}
+        fn update_valid(&mut self, _state: DashboardMetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    #[test]
+    fn should_aggregate_metrics_over_all_items() {
+        let directory = std::env::temp_dir().join("burn-train-evaluator");
+        let directory = directory.to_str().unwrap();
+        let mut dashboard = Dashboard::new(
+            Box::new(TestRenderer),
+            Box::new(NoopMetricLogger),
+            Box::new(FileMetricLogger::new(directory)),
+        );
+        dashboard.register_valid_plot(LossMetric::<TestBackend>::new());
+        let evaluator = Evaluator { dashboard };
+        let dataloader = Arc::new(BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(2)),
+            Arc::new(InMemDataset::new((0..5).collect())),
+            Arc::new(TestBatcher),
+        ));
+
+        let summary = evaluator.eval(&TestModel, dataloader);
+
+        // The batch losses are 0.5, 2.5 and 4.0.
+        assert_eq!(summary.metrics.len(), 1);
+        assert!((summary.value("Loss").unwrap() - 7.0 / 3.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,85 @@
+use super::Evaluator;
+use crate::learner::log::update_log_file;
+use crate::logger::{
+    FileMetricLogger, MetricLogFormat, MetricLogger, NoopMetricLogger, StructuredMetricLogger,
+};
+use crate::metric::dashboard::cli::CLIDashboardRenderer;
+use crate::metric::dashboard::Dashboard;
+use crate::metric::{Adaptor, Metric, Numeric};
+
+/// Struct to configure and create an [evaluator](Evaluator).
+///
+/// The metrics are logged in the `test` subdirectory of the evaluation directory.
+pub struct EvaluatorBuilder<V>
+where
+    V: Send + Sync + 'static,
+{
+    dashboard: Dashboard<(), V>,
+    directory: String,
+}
+
+impl<V> EvaluatorBuilder<V>
+where
+    V: Send + Sync + 'static,
+{
+    pub fn new(directory: &str) -> Self {
+        let renderer = Box::new(CLIDashboardRenderer::new());
+        // The training split isn't used during evaluation.
+        let logger_train = Box::new(NoopMetricLogger);
+        let logger_test = Box::new(FileMetricLogger::new(format!("{directory}/test").as_str()));
+
+        Self {
+            dashboard: Dashboard::new(renderer, logger_train, logger_test),
+            directory: directory.to_string(),
+        }
+    }
+
+    /// Register a numeric metric, whose aggregated value is part of the
+    /// [summary](crate::EvaluationSummary), and display it on a plot.
+    pub fn metric<M>(mut self, metric: M) -> Self
+    where
+        M: Metric + Numeric + 'static,
+        V: Adaptor<M::Input>,
+    {
+        self.dashboard.register_valid_plot(metric);
+        self
+    }
+
+    /// Register a metric only displayed as text, e.g. a
+    /// [confusion matrix](crate::metric::ConfusionMatrixMetric).
+    ///
+    /// The summary contains its last formatted entry.
+    pub fn metric_text<M: Metric + 'static>(mut self, metric: M) -> Self
+    where
+        V: Adaptor<M::Input>,
+    {
+        self.dashboard.register_valid(metric);
+        self
+    }
+
+    /// Register a logger for the metrics, in addition to the default file logger.
+    pub fn metric_logger<L: MetricLogger + 'static>(mut self, logger: L) -> Self {
+        self.dashboard.register_logger_valid(Box::new(logger));
+        self
+    }
+
+    /// Write the metrics as machine-readable records in the `test` subdirectory of the
+    /// evaluation directory.
+    ///
+    /// The records can be loaded back with [read_metrics](crate::logger::read_metrics).
+    pub fn structured_metric_logger(self, format: MetricLogFormat) -> Self {
+        let directory = format!("{}/test", self.directory);
+
+        self.metric_logger(StructuredMetricLogger::new(&directory, "test", format))
+    }
+
+    /// Create the [evaluator](Evaluator).
+    pub fn build(self) -> Evaluator<V> {
+        let file_path = format!("{}/evaluation.log", self.directory);
+        update_log_file(file_path.as_str());
+
+        Evaluator {
+            dashboard: self.dashboard,
+        }
+    }
+}
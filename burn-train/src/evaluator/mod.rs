@@ -0,0 +1,7 @@
+mod base;
+mod builder;
+mod summary;
+
+pub use base::*;
+pub use builder::*;
+pub use summary::*;
@@ -0,0 +1,43 @@
+/// The final value of a metric after an evaluation.
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct MetricSummary {
+    /// The name of the metric.
+    pub name: String,
+    /// The value aggregated over all the items, for [numeric](crate::metric::Numeric) metrics.
+    pub value: Option<f64>,
+    /// The last entry displayed for the metric.
+    pub formatted: String,
+}
+
+/// The metrics of an [evaluation](crate::Evaluator): the text metrics first, then the
+/// [numeric](crate::metric::Numeric) ones registered for plotting, each in the order they were
+/// registered.
+#[derive(new, Clone, Debug, Default)]
+pub struct EvaluationSummary {
+    pub metrics: Vec<MetricSummary>,
+}
+
+impl EvaluationSummary {
+    /// Get the summary of a metric by name.
+    pub fn get(&self, name: &str) -> Option<&MetricSummary> {
+        self.metrics.iter().find(|metric| metric.name == name)
+    }
+
+    /// Get the aggregated value of a numeric metric by name.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.get(name).and_then(|metric| metric.value)
+    }
+}
+
+impl core::fmt::Display for EvaluationSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for metric in self.metrics.iter() {
+            match metric.value {
+                Some(value) => writeln!(f, "{}: {}", metric.name, value)?,
+                None => writeln!(f, "{}: {}", metric.name, metric.formatted)?,
+            }
+        }
+
+        Ok(())
+    }
+}
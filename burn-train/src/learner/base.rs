@@ -1,9 +1,13 @@
-use crate::checkpoint::Checkpointer;
+use super::{EarlyStopping, EarlyStoppingRecord, NonFiniteGuard};
+use crate::checkpoint::{AsyncCheckpointer, Checkpointer};
 use crate::LearnerCallback;
+use burn_core as burn;
 use burn_core::lr_scheduler::LRScheduler;
 use burn_core::module::{ADModule, Module};
 use burn_core::optim::Optimizer;
+use burn_core::record::Record;
 use burn_core::tensor::backend::ADBackend;
+use std::sync::Arc;
 
 /// Learner struct encapsulating all components necessary to train a Neural Network model.
 ///
@@ -20,17 +24,141 @@ where
     pub(super) lr_scheduler: LR,
     pub(super) num_epochs: usize,
     pub(super) callback: Box<dyn LearnerCallback<TO, VO>>,
-    pub(super) checkpoint: Option<usize>,
-    pub(super) checkpointer_model: CheckpointModel<M, B>,
-    pub(super) checkpointer_optimizer: CheckpointOptim<O, M, B>,
-    pub(super) checkpointer_scheduler: CheckpointScheduler<LR>,
+    pub(super) checkpoint: Option<LearnerCheckpoint>,
+    pub(super) checkpointer: LearnerCheckpointerOption<M, O, LR, B>,
+    pub(super) checkpointer_step: LearnerCheckpointerOption<M, O, LR, B>,
+    pub(super) checkpoint_interval: Option<usize>,
     pub(super) grad_accumulation: Option<usize>,
     pub(super) devices: Vec<B::Device>,
+    pub(super) early_stopping: Option<EarlyStopping<VO>>,
+    pub(super) non_finite_guard: Option<NonFiniteGuard<TO>>,
 }
 
-type CheckpointModel<M, B> = Option<Box<dyn Checkpointer<<M as Module<B>>::Record>>>;
-type CheckpointOptim<O, M, B> = Option<Box<dyn Checkpointer<<O as Optimizer<M, B>>::Record>>>;
-type CheckpointScheduler<LR> = Option<Box<dyn Checkpointer<<LR as LRScheduler>::Record>>>;
+/// The checkpoint from which the training resumes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum LearnerCheckpoint {
+    /// Resume from the checkpoint saved at the end of an epoch.
+    Epoch(usize),
+    /// Resume from a checkpoint saved during an epoch.
+    Step(usize),
+}
+
+/// Position of the training loop when a checkpoint is saved.
+#[derive(Record, Clone, Debug, new)]
+pub struct TrainingStateRecord {
+    /// The epoch being trained.
+    pub epoch: usize,
+    /// The number of batches of the epoch already processed, which is the position of the
+    /// training dataloader.
+    pub iteration: usize,
+    /// The [epoch](burn_core::data::dataloader::DataLoader::set_epoch) of the training
+    /// dataloader, which selects the order of its shuffled items.
+    pub dataloader_epoch: usize,
+    /// The number of step checkpoints saved so far.
+    pub num_step_checkpoints: usize,
+    /// The state of the early stopping, if enabled.
+    pub early_stopping: Option<EarlyStoppingRecord>,
+}
+
+/// All the checkpointers required to resume the training.
+pub(super) struct LearnerCheckpointer<MR, OR, SR> {
+    model: Box<dyn Checkpointer<MR>>,
+    optim: Box<dyn Checkpointer<OR>>,
+    scheduler: Box<dyn Checkpointer<SR>>,
+    state: Box<dyn Checkpointer<TrainingStateRecord>>,
+}
+
+pub(super) type LearnerCheckpointerOption<M, O, LR, B> = Option<
+    LearnerCheckpointer<
+        <M as Module<B>>::Record,
+        <O as Optimizer<M, B>>::Record,
+        <LR as LRScheduler>::Record,
+    >,
+>;
+
+/// Shared checkpointers used by the [builder](crate::LearnerBuilder) before they are made
+/// asynchronous.
+pub(super) struct SharedCheckpointers<MR, OR, SR> {
+    pub(super) model: Arc<dyn Checkpointer<MR> + Send + Sync>,
+    pub(super) optim: Arc<dyn Checkpointer<OR> + Send + Sync>,
+    pub(super) scheduler: Arc<dyn Checkpointer<SR> + Send + Sync>,
+    pub(super) state: Arc<dyn Checkpointer<TrainingStateRecord> + Send + Sync>,
+}
+
+impl<MR, OR, SR> SharedCheckpointers<MR, OR, SR>
+where
+    MR: Record + 'static,
+    OR: Record + 'static,
+    SR: Record + 'static,
+{
+    pub(super) fn into_async(self) -> LearnerCheckpointer<MR, OR, SR> {
+        LearnerCheckpointer {
+            model: Box::new(AsyncCheckpointer::new(self.model)),
+            optim: Box::new(AsyncCheckpointer::new(self.optim)),
+            scheduler: Box::new(AsyncCheckpointer::new(self.scheduler)),
+            state: Box::new(AsyncCheckpointer::new(self.state)),
+        }
+    }
+}
+
+impl<MR, OR, SR> LearnerCheckpointer<MR, OR, SR>
+where
+    MR: Record,
+    OR: Record,
+    SR: Record,
+{
+    pub(super) fn save<B, M, O, LR>(
+        &self,
+        key: usize,
+        model: &M,
+        optim: &O,
+        scheduler: &LR,
+        state: TrainingStateRecord,
+    ) where
+        B: ADBackend,
+        M: ADModule<B, Record = MR>,
+        O: Optimizer<M, B, Record = OR>,
+        LR: LRScheduler<Record = SR>,
+    {
+        self.model.save(key, model.clone().into_record()).unwrap();
+        self.optim.save(key, optim.to_record()).unwrap();
+        self.scheduler.save(key, scheduler.to_record()).unwrap();
+        self.state.save(key, state).unwrap();
+    }
+
+    pub(super) fn save_best(&self, epoch: usize, record: MR) {
+        self.model.save_best(epoch, record).unwrap();
+    }
+
+    /// Restore the model record of the best epoch, if it was kept.
+    pub(super) fn restore_best(&self) -> Option<MR> {
+        self.model.restore_best().ok()
+    }
+
+    /// Load the records saved with the given key.
+    ///
+    /// The training state is optional, since it wasn't saved by previous versions.
+    pub(super) fn load<B, M, O, LR>(
+        &self,
+        key: usize,
+        model: M,
+        optim: O,
+        scheduler: LR,
+    ) -> (M, O, LR, Option<TrainingStateRecord>)
+    where
+        B: ADBackend,
+        M: ADModule<B, Record = MR>,
+        O: Optimizer<M, B, Record = OR>,
+        LR: LRScheduler<Record = SR>,
+    {
+        let model = model.load_record(self.model.restore(key).unwrap());
+        let optim = optim.load_record(self.optim.restore(key).unwrap());
+        let scheduler = scheduler.load_record(self.scheduler.restore(key).unwrap());
+        let state = self.state.restore(key).ok();
+
+        (model, optim, scheduler, state)
+    }
+}
 
 impl<B, M, O, LR, TO, VO> Learner<B, M, O, LR, TO, VO>
 where
@@ -41,46 +169,57 @@ where
     O: Optimizer<M, B>,
     LR: LRScheduler,
 {
-    pub(super) fn checkpoint(
-        model: &M,
-        optim: &O,
-        scheduler: &LR,
-        checkpointer_model: &CheckpointModel<M, B>,
-        checkpointer_optimizer: &CheckpointOptim<O, M, B>,
-        checkpointer_scheduler: &CheckpointScheduler<LR>,
-        epoch: usize,
-    ) {
-        if let Some(checkpointer) = &checkpointer_model {
-            checkpointer
-                .save(epoch, model.clone().into_record())
-                .unwrap();
-        }
+    /// Load the checkpoint from which the training must resume, returning the training state
+    /// from which it resumes.
+    pub(super) fn load_checkpoint(
+        mut self,
+        checkpoint: LearnerCheckpoint,
+    ) -> (Self, TrainingStateRecord) {
+        let (checkpointer, key) = match checkpoint {
+            LearnerCheckpoint::Epoch(epoch) => (&self.checkpointer, epoch),
+            LearnerCheckpoint::Step(index) => (&self.checkpointer_step, index),
+        };
 
-        if let Some(checkpointer) = &checkpointer_optimizer {
-            checkpointer.save(epoch, optim.to_record()).unwrap();
-        }
+        let state = match checkpointer {
+            Some(checkpointer) => {
+                let (model, optim, scheduler, state) =
+                    checkpointer.load(key, self.model, self.optim, self.lr_scheduler);
+                self.model = model;
+                self.optim = optim;
+                self.lr_scheduler = scheduler;
+                state
+            }
+            None => None,
+        };
 
-        if let Some(checkpointer) = &checkpointer_scheduler {
-            checkpointer.save(epoch, scheduler.to_record()).unwrap();
-        }
-    }
-
-    pub(super) fn load_checkpoint(mut self, epoch: usize) -> Self {
-        if let Some(checkpointer) = &self.checkpointer_model {
-            let record = checkpointer.restore(epoch).unwrap();
-            self.model = self.model.load_record(record);
-        }
+        let state = match (checkpoint, state) {
+            (_, Some(state)) => state,
+            (LearnerCheckpoint::Epoch(epoch), None) => {
+                TrainingStateRecord::new(epoch, 0, epoch - 1, 0, None)
+            }
+            (LearnerCheckpoint::Step(index), None) => {
+                panic!("The training state of the step checkpoint {index} can't be restored")
+            }
+        };
 
-        if let Some(checkpointer) = &self.checkpointer_optimizer {
-            let record = checkpointer.restore(epoch).unwrap();
-            self.optim = self.optim.load_record(record);
-        }
+        // An epoch checkpoint is saved once the epoch is over, so the training resumes at the
+        // start of the next one.
+        let state = match checkpoint {
+            LearnerCheckpoint::Epoch(_) => TrainingStateRecord {
+                epoch: state.epoch + 1,
+                iteration: 0,
+                dataloader_epoch: state.dataloader_epoch + 1,
+                ..state
+            },
+            LearnerCheckpoint::Step(_) => state,
+        };
 
-        if let Some(checkpointer) = &self.checkpointer_scheduler {
-            let record = checkpointer.restore(epoch).unwrap();
-            self.lr_scheduler = self.lr_scheduler.load_record(record);
+        if let (Some(early_stopping), Some(record)) =
+            (&mut self.early_stopping, state.early_stopping.clone())
+        {
+            early_stopping.load_record(record);
         }
 
-        self
+        (self, state)
     }
 }
@@ -1,11 +1,17 @@
 use super::log::update_log_file;
-use super::Learner;
-use crate::checkpoint::{AsyncCheckpointer, Checkpointer, FileCheckpointer};
-use crate::logger::FileMetricLogger;
+use super::{
+    EarlyStopping, Learner, LearnerCheckpoint, MetricEarlyStoppingStrategy, NonFiniteAction,
+    NonFiniteGuard, SharedCheckpointers,
+};
+use crate::checkpoint::FileCheckpointer;
+use crate::logger::{FileMetricLogger, MetricLogFormat, MetricLogger, StructuredMetricLogger};
 use crate::metric::dashboard::cli::CLIDashboardRenderer;
 use crate::metric::dashboard::Dashboard;
-use crate::metric::{Adaptor, Metric, Numeric};
-use crate::AsyncTrainerCallback;
+use crate::metric::{Adaptor, LossInput, Metric, Numeric};
+use crate::{
+    AsyncTrainerCallback, ClonedItemsCallback, LearnerCallback, LearnerCallbacks,
+    SharedLearnerCallback,
+};
 use burn_core::lr_scheduler::LRScheduler;
 use burn_core::module::ADModule;
 use burn_core::optim::Optimizer;
@@ -25,14 +31,17 @@ where
     S: LRScheduler,
 {
     dashboard: Dashboard<T, V>,
-    checkpointer_model: Option<Arc<dyn Checkpointer<M::Record> + Send + Sync>>,
-    checkpointer_optimizer: Option<Arc<dyn Checkpointer<O::Record> + Send + Sync>>,
-    checkpointer_scheduler: Option<Arc<dyn Checkpointer<S::Record> + Send + Sync>>,
+    callbacks: Vec<Box<dyn SharedLearnerCallback<T, V>>>,
+    checkpointer: Option<SharedCheckpointers<M::Record, O::Record, S::Record>>,
+    checkpointer_step: Option<SharedCheckpointers<M::Record, O::Record, S::Record>>,
+    checkpoint_interval: Option<usize>,
     num_epochs: usize,
-    checkpoint: Option<usize>,
+    checkpoint: Option<LearnerCheckpoint>,
     directory: String,
     grad_accumulation: Option<usize>,
     devices: Vec<B::Device>,
+    early_stopping: Option<EarlyStopping<V>>,
+    non_finite_guard: Option<NonFiniteGuard<T>>,
 }
 
 impl<B, T, V, Model, Optim, LR> LearnerBuilder<B, T, V, Model, Optim, LR>
@@ -51,14 +60,17 @@ where
 
         Self {
             dashboard: Dashboard::new(renderer, logger_train, logger_valid),
+            callbacks: Vec::new(),
             num_epochs: 1,
             checkpoint: None,
-            checkpointer_model: None,
-            checkpointer_optimizer: None,
-            checkpointer_scheduler: None,
+            checkpointer: None,
+            checkpointer_step: None,
+            checkpoint_interval: None,
             directory: directory.to_string(),
             grad_accumulation: None,
             devices: vec![B::Device::default()],
+            early_stopping: None,
+            non_finite_guard: None,
         }
     }
 
@@ -80,6 +92,48 @@ where
         self
     }
 
+    /// Register a [callback](LearnerCallback) called during training.
+    ///
+    /// # Notes
+    ///
+    /// Callbacks are called in the training loop, in the order they are registered, before
+    /// the dashboard. They receive a clone of each training and validation item.
+    pub fn with_callback<C>(mut self, callback: C) -> Self
+    where
+        C: LearnerCallback<T, V> + 'static,
+        T: Clone,
+        V: Clone,
+    {
+        self.callbacks
+            .push(Box::new(ClonedItemsCallback::new(callback)));
+        self
+    }
+
+    /// Register loggers for the training and validation metrics, in addition to the default
+    /// file loggers.
+    pub fn metric_loggers<MT, MV>(mut self, logger_train: MT, logger_valid: MV) -> Self
+    where
+        MT: MetricLogger + 'static,
+        MV: MetricLogger + 'static,
+    {
+        self.dashboard
+            .register_loggers(Box::new(logger_train), Box::new(logger_valid));
+        self
+    }
+
+    /// Write the metrics as machine-readable records in the `train` and `valid` subdirectories
+    /// of the training directory.
+    ///
+    /// The records can be loaded back with [read_metrics](crate::logger::read_metrics).
+    pub fn structured_metric_logger(self, format: MetricLogFormat) -> Self {
+        let directory = self.directory.clone();
+
+        self.metric_loggers(
+            StructuredMetricLogger::new(&format!("{directory}/train"), "train", format),
+            StructuredMetricLogger::new(&format!("{directory}/valid"), "valid", format),
+        )
+    }
+
     /// Enable gradients accumulation.
     ///
     /// # Notes
@@ -126,21 +180,90 @@ where
         self
     }
 
+    /// Stop the training when the given validation metric stops improving.
+    ///
+    /// The epoch value of the metric is its running value at the end of the validation, and the
+    /// state of the early stopping is saved with the checkpoints.
+    /// The best epoch is tracked and, when a [file checkpointer](Self::with_file_checkpointer)
+    /// is registered, its model record is saved alongside the other checkpoints.
+    pub fn early_stopping<M>(mut self, metric: M, strategy: MetricEarlyStoppingStrategy) -> Self
+    where
+        M: Metric + Numeric + 'static,
+        V: Adaptor<M::Input>,
+    {
+        self.early_stopping = Some(EarlyStopping::new(metric, strategy));
+        self
+    }
+
+    /// Check the loss and the gradients after each training step for infinite and NaN values,
+    /// and take the given action when some are found.
+    ///
+    /// The parameters with non-finite gradients are reported through the logger. Reloading the
+    /// last checkpoint requires a [file checkpointer](Self::with_file_checkpointer).
+    ///
+    /// With an optimizer [skipping the non-finite gradients][skips] itself, e.g.
+    /// [mixed precision](burn_core::optim::MixedPrecision), only the loss is checked, so the
+    /// optimizer can reduce its loss scale. The loss of the outputs must then be the unscaled
+    /// loss.
+    ///
+    /// [skips]: burn_core::optim::Optimizer::skips_non_finite_grads
+    pub fn non_finite_guard(mut self, action: NonFiniteAction) -> Self
+    where
+        T: Adaptor<LossInput<B>>,
+    {
+        self.non_finite_guard = Some(NonFiniteGuard::new(action));
+        self
+    }
+
     /// The number of epochs the training should last.
     pub fn num_epochs(mut self, num_epochs: usize) -> Self {
         self.num_epochs = num_epochs;
         self
     }
 
-    /// Run the training loop on multiple devices.
+    /// Run the training loop on multiple devices with data parallelism.
+    ///
+    /// Each device processes its own batch, so a global batch is made of one batch per device.
+    /// The gradients are averaged on the first device, which holds the reference model.
+    ///
+    /// Each device has the same weight in the average, which is exact only when all the batches
+    /// have the same size. The dataloader can [drop its last batch][drop_last] to avoid a smaller
+    /// one.
+    ///
+    /// [drop_last]: burn_core::data::dataloader::DataLoaderBuilder::drop_last
     pub fn devices(mut self, devices: Vec<B::Device>) -> Self {
         self.devices = devices;
         self
     }
 
-    /// The epoch from which the training must resume.
+    /// The epoch checkpoint from which the training must resume.
+    ///
+    /// The checkpoint of an epoch is saved once it is over, so the training resumes at the start
+    /// of the next epoch.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
-        self.checkpoint = Some(checkpoint);
+        self.checkpoint = Some(LearnerCheckpoint::Epoch(checkpoint));
+        self
+    }
+
+    /// The step checkpoint from which the training must resume.
+    ///
+    /// Step checkpoints are numbered from one, in the order they are saved. The batches of the
+    /// epoch already processed when the checkpoint was saved are skipped, so the training
+    /// continues exactly where it stopped.
+    pub fn checkpoint_step(mut self, checkpoint: usize) -> Self {
+        self.checkpoint = Some(LearnerCheckpoint::Step(checkpoint));
+        self
+    }
+
+    /// Save a checkpoint every `num_iterations` iterations, in addition to the checkpoints saved
+    /// at the end of each epoch.
+    ///
+    /// # Notes
+    ///
+    /// A [file checkpointer](Self::with_file_checkpointer) must be registered. With gradients
+    /// accumulation, the checkpoint is postponed until the accumulated gradients are applied.
+    pub fn checkpoint_interval(mut self, num_iterations: usize) -> Self {
+        self.checkpoint_interval = Some(num_iterations);
         self
     }
 
@@ -154,27 +277,38 @@ where
     where
         FR: FileRecorder + 'static,
     {
-        self.checkpointer_model = Some(Arc::new(FileCheckpointer::new(
-            recorder.clone(),
-            format!("{}/checkpoint", self.directory).as_str(),
-            "model",
-            num_keep,
-        )));
-        self.checkpointer_optimizer = Some(Arc::new(FileCheckpointer::new(
-            recorder.clone(),
-            format!("{}/checkpoint", self.directory).as_str(),
-            "optim",
-            num_keep,
-        )));
-        self.checkpointer_scheduler = Some(Arc::new(FileCheckpointer::new(
-            recorder,
-            format!("{}/checkpoint", self.directory).as_str(),
-            "scheduler",
-            num_keep,
-        )));
+        self.checkpointer = Some(self.file_checkpointers(num_keep, recorder.clone(), ""));
+        self.checkpointer_step = Some(self.file_checkpointers(num_keep, recorder, "-step"));
         self
     }
 
+    fn file_checkpointers<FR>(
+        &self,
+        num_keep: usize,
+        recorder: FR,
+        suffix: &str,
+    ) -> SharedCheckpointers<Model::Record, Optim::Record, LR::Record>
+    where
+        FR: FileRecorder + 'static,
+    {
+        let directory = format!("{}/checkpoint", self.directory);
+        let checkpointer = |name: &str| {
+            FileCheckpointer::new(
+                recorder.clone(),
+                directory.as_str(),
+                format!("{name}{suffix}").as_str(),
+                num_keep,
+            )
+        };
+
+        SharedCheckpointers {
+            model: Arc::new(checkpointer("model")),
+            optim: Arc::new(checkpointer("optim")),
+            scheduler: Arc::new(checkpointer("scheduler")),
+            state: Arc::new(checkpointer("state")),
+        }
+    }
+
     /// Create the [learner](Learner) from a [model](ADModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LRScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
@@ -192,31 +326,14 @@ where
         self.init_logger();
         let callack = Box::new(self.dashboard);
         let callback = Box::new(AsyncTrainerCallback::new(callack));
+        let callback = Box::new(LearnerCallbacks::new(callback, self.callbacks));
 
-        let checkpointer_optimizer = match self.checkpointer_optimizer {
-            Some(checkpointer) => {
-                let checkpointer: Box<dyn Checkpointer<Optim::Record>> =
-                    Box::new(AsyncCheckpointer::new(checkpointer));
-                Some(checkpointer)
-            }
-            None => None,
-        };
-        let checkpointer_model = match self.checkpointer_model {
-            Some(checkpointer) => {
-                let checkpointer: Box<dyn Checkpointer<Model::Record>> =
-                    Box::new(AsyncCheckpointer::new(checkpointer));
-                Some(checkpointer)
-            }
-            None => None,
-        };
-        let checkpointer_scheduler = match self.checkpointer_scheduler {
-            Some(checkpointer) => {
-                let checkpointer: Box<dyn Checkpointer<LR::Record>> =
-                    Box::new(AsyncCheckpointer::new(checkpointer));
-                Some(checkpointer)
-            }
-            None => None,
-        };
+        let checkpointer = self
+            .checkpointer
+            .map(|checkpointer| checkpointer.into_async());
+        let checkpointer_step = self
+            .checkpointer_step
+            .map(|checkpointer| checkpointer.into_async());
 
         Learner {
             model,
@@ -225,11 +342,13 @@ where
             num_epochs: self.num_epochs,
             callback,
             checkpoint: self.checkpoint,
-            checkpointer_model,
-            checkpointer_optimizer,
-            checkpointer_scheduler,
+            checkpointer,
+            checkpointer_step,
+            checkpoint_interval: self.checkpoint_interval,
             grad_accumulation: self.grad_accumulation,
             devices: self.devices,
+            early_stopping: self.early_stopping,
+            non_finite_guard: self.non_finite_guard,
         }
     }
 
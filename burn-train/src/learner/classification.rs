@@ -1,9 +1,11 @@
-use crate::metric::{AccuracyInput, Adaptor, LossInput};
+use crate::metric::{
+    AccuracyInput, Adaptor, AurocInput, ConfusionMatrixInput, LossInput, TopKAccuracyInput,
+};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::{Int, Tensor};
 
 /// Simple classification output adapted for multiple metrics.
-#[derive(new)]
+#[derive(new, Clone)]
 pub struct ClassificationOutput<B: Backend> {
     pub loss: Tensor<B, 1>,
     pub output: Tensor<B, 2>,
@@ -21,3 +23,21 @@ impl<B: Backend> Adaptor<LossInput<B>> for ClassificationOutput<B> {
         LossInput::new(self.loss.clone())
     }
 }
+
+impl<B: Backend> Adaptor<TopKAccuracyInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> TopKAccuracyInput<B> {
+        TopKAccuracyInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionMatrixInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ConfusionMatrixInput<B> {
+        ConfusionMatrixInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<AurocInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> AurocInput<B> {
+        AurocInput::new(self.output.clone(), self.targets.clone())
+    }
+}
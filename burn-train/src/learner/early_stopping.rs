@@ -0,0 +1,287 @@
+use crate::metric::{Adaptor, Metric, MetricMetadata, Numeric};
+use crate::LearnerItem;
+use burn_core as burn;
+use burn_core::record::Record;
+
+/// The direction in which a metric is considered to improve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricDirection {
+    /// Lower values are better, e.g. the loss.
+    Lowest,
+    /// Higher values are better, e.g. the accuracy.
+    Highest,
+}
+
+/// Strategy to stop the training when a validation metric stops improving.
+#[derive(Clone, Debug)]
+pub struct MetricEarlyStoppingStrategy {
+    direction: MetricDirection,
+    patience: usize,
+    min_delta: f64,
+    restore_best: bool,
+}
+
+impl MetricEarlyStoppingStrategy {
+    /// Create the strategy with the direction in which the metric improves.
+    pub fn new(direction: MetricDirection) -> Self {
+        Self {
+            direction,
+            patience: 1,
+            min_delta: 0.0,
+            restore_best: false,
+        }
+    }
+
+    /// Number of epochs without improvement after which the training is stopped.
+    ///
+    /// # Panics
+    ///
+    /// If the patience is zero, since the training would stop after the first epoch.
+    pub fn patience(mut self, patience: usize) -> Self {
+        if patience == 0 {
+            panic!("The early stopping patience must be at least one epoch");
+        }
+        self.patience = patience;
+        self
+    }
+
+    /// Minimum change of the metric to be considered an improvement.
+    pub fn min_delta(mut self, min_delta: f64) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
+
+    /// Return the model of the best epoch instead of the model of the last epoch when the
+    /// training ends.
+    pub fn restore_best(mut self, restore_best: bool) -> Self {
+        self.restore_best = restore_best;
+        self
+    }
+}
+
+/// The result of an epoch for the [early stopping](EarlyStopping) tracker.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct EarlyStoppingStatus {
+    pub improved: bool,
+    pub should_stop: bool,
+}
+
+/// State of the [early stopping](EarlyStopping) tracker saved with the checkpoints, so a
+/// resumed training keeps its best epoch and the epochs without improvement.
+#[derive(Record, Clone, Debug, new)]
+pub struct EarlyStoppingRecord {
+    /// The best epoch so far.
+    pub best_epoch: Option<usize>,
+    /// The metric value of the best epoch.
+    pub best_value: Option<f64>,
+    /// The number of epochs since the best epoch.
+    pub num_epochs_without_improvement: usize,
+}
+
+/// Track the epoch values of a validation metric to find the best epoch and when to stop.
+///
+/// The value of an epoch is the running value of the metric at the end of the validation, so
+/// every item has the same weight.
+pub(crate) struct EarlyStopping<V> {
+    metric: Box<dyn NumericMetricUpdater<V>>,
+    strategy: MetricEarlyStoppingStrategy,
+    best: Option<(usize, f64)>,
+    num_epochs_without_improvement: usize,
+}
+
+impl<V> EarlyStopping<V> {
+    pub fn new<M>(metric: M, strategy: MetricEarlyStoppingStrategy) -> Self
+    where
+        M: Metric + Numeric + 'static,
+        V: Adaptor<M::Input> + 'static,
+    {
+        Self {
+            metric: Box::new(MetricUpdater { metric }),
+            strategy,
+            best: None,
+            num_epochs_without_improvement: 0,
+        }
+    }
+
+    pub fn update(&mut self, item: &LearnerItem<V>) {
+        self.metric.update(item, &item.into());
+    }
+
+    pub fn end_epoch(&mut self, epoch: usize) -> EarlyStoppingStatus {
+        let value = self.metric.running_value();
+        self.metric.clear();
+
+        let improved = match self.best {
+            Some((_, best)) => self.is_improvement(value, best),
+            None => !value.is_nan(),
+        };
+
+        if improved {
+            self.best = Some((epoch, value));
+            self.num_epochs_without_improvement = 0;
+        } else {
+            self.num_epochs_without_improvement += 1;
+        }
+
+        EarlyStoppingStatus {
+            improved,
+            should_stop: self.num_epochs_without_improvement >= self.strategy.patience,
+        }
+    }
+
+    pub fn best_epoch(&self) -> Option<usize> {
+        self.best.map(|(epoch, _)| epoch)
+    }
+
+    pub fn best_value(&self) -> Option<f64> {
+        self.best.map(|(_, value)| value)
+    }
+
+    pub fn restore_best(&self) -> bool {
+        self.strategy.restore_best
+    }
+
+    pub fn to_record(&self) -> EarlyStoppingRecord {
+        EarlyStoppingRecord::new(
+            self.best_epoch(),
+            self.best_value(),
+            self.num_epochs_without_improvement,
+        )
+    }
+
+    pub fn load_record(&mut self, record: EarlyStoppingRecord) {
+        self.best = record.best_epoch.zip(record.best_value);
+        self.num_epochs_without_improvement = record.num_epochs_without_improvement;
+    }
+
+    fn is_improvement(&self, value: f64, best: f64) -> bool {
+        match self.strategy.direction {
+            MetricDirection::Lowest => value < best - self.strategy.min_delta,
+            MetricDirection::Highest => value > best + self.strategy.min_delta,
+        }
+    }
+}
+
+trait NumericMetricUpdater<T> {
+    fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata);
+    fn running_value(&self) -> f64;
+    fn clear(&mut self);
+}
+
+struct MetricUpdater<M> {
+    metric: M,
+}
+
+impl<T, M> NumericMetricUpdater<T> for MetricUpdater<M>
+where
+    M: Metric + Numeric,
+    T: Adaptor<M::Input>,
+{
+    fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) {
+        self.metric.update(&item.item.adapt(), metadata);
+    }
+
+    fn running_value(&self) -> f64 {
+        self.metric.running_value()
+    }
+
+    fn clear(&mut self) {
+        self.metric.clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::{LossInput, LossMetric};
+    use crate::TestBackend;
+    use burn_core::data::dataloader::Progress;
+    use burn_core::tensor::Tensor;
+
+    struct LossOutput(f32);
+
+    impl Adaptor<LossInput<TestBackend>> for LossOutput {
+        fn adapt(&self) -> LossInput<TestBackend> {
+            LossInput::new(Tensor::from_floats([self.0]))
+        }
+    }
+
+    #[test]
+    fn should_stop_after_patience_epochs_without_improvement() {
+        let strategy = MetricEarlyStoppingStrategy::new(MetricDirection::Lowest).patience(2);
+        let mut early_stopping = EarlyStopping::new(LossMetric::<TestBackend>::new(), strategy);
+
+        let status = run_epoch(&mut early_stopping, 1, &[1.0, 0.8]);
+        assert_eq!(status, status_of(true, false));
+        let status = run_epoch(&mut early_stopping, 2, &[0.5, 0.6]);
+        assert_eq!(status, status_of(true, false));
+        let status = run_epoch(&mut early_stopping, 3, &[0.7, 0.6]);
+        assert_eq!(status, status_of(false, false));
+        let status = run_epoch(&mut early_stopping, 4, &[0.8, 0.6]);
+        assert_eq!(status, status_of(false, true));
+
+        assert_eq!(early_stopping.best_epoch(), Some(2));
+        assert!((early_stopping.best_value().unwrap() - 0.55).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_ignore_improvements_smaller_than_min_delta() {
+        let strategy = MetricEarlyStoppingStrategy::new(MetricDirection::Highest)
+            .patience(1)
+            .min_delta(0.1);
+        let mut early_stopping = EarlyStopping::new(LossMetric::<TestBackend>::new(), strategy);
+
+        let status = run_epoch(&mut early_stopping, 1, &[0.5]);
+        assert_eq!(status, status_of(true, false));
+        let status = run_epoch(&mut early_stopping, 2, &[0.55]);
+        assert_eq!(status, status_of(false, true));
+
+        assert_eq!(early_stopping.best_epoch(), Some(1));
+    }
+
+    #[test]
+    fn should_keep_patience_when_resumed_from_record() {
+        let strategy = MetricEarlyStoppingStrategy::new(MetricDirection::Lowest).patience(2);
+        let mut early_stopping =
+            EarlyStopping::new(LossMetric::<TestBackend>::new(), strategy.clone());
+        run_epoch(&mut early_stopping, 1, &[0.5]);
+        run_epoch(&mut early_stopping, 2, &[0.6]);
+
+        let mut resumed = EarlyStopping::new(LossMetric::<TestBackend>::new(), strategy);
+        resumed.load_record(early_stopping.to_record());
+        let status = run_epoch(&mut resumed, 3, &[0.7]);
+
+        assert_eq!(status, status_of(false, true));
+        assert_eq!(resumed.best_epoch(), Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_reject_zero_patience() {
+        MetricEarlyStoppingStrategy::new(MetricDirection::Lowest).patience(0);
+    }
+
+    fn run_epoch(
+        early_stopping: &mut EarlyStopping<LossOutput>,
+        epoch: usize,
+        losses: &[f32],
+    ) -> EarlyStoppingStatus {
+        for (i, loss) in losses.iter().enumerate() {
+            let progress = Progress {
+                items_processed: i + 1,
+                items_total: losses.len(),
+            };
+            let item = LearnerItem::new(LossOutput(*loss), progress, epoch, 4, i + 1, None);
+            early_stopping.update(&item);
+        }
+
+        early_stopping.end_epoch(epoch)
+    }
+
+    fn status_of(improved: bool, should_stop: bool) -> EarlyStoppingStatus {
+        EarlyStoppingStatus {
+            improved,
+            should_stop,
+        }
+    }
+}
@@ -1,13 +1,16 @@
 use burn_core::{
-    data::dataloader::DataLoader,
+    data::dataloader::{DataLoader, DataLoaderIterator},
     lr_scheduler::LRScheduler,
     module::ADModule,
-    optim::{GradientsAccumulator, Optimizer},
+    optim::{GradientsAccumulator, GradientsParams, Optimizer},
     tensor::backend::ADBackend,
 };
 use std::sync::Arc;
 
-use crate::{LearnerCallback, LearnerItem, MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{
+    EarlyStopping, LearnerCallback, LearnerItem, MultiDevicesTrainStep, NonFiniteGuard,
+    SavedCheckpoint, TrainStep, ValidStep,
+};
 
 #[derive(new)]
 pub struct ValidEpoch<VI> {
@@ -22,17 +25,41 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    /// Number of batches already processed, which are skipped when resuming an epoch.
+    iteration_start: usize,
 }
 
+/// Called after each optimizer step with the number of batches of the epoch processed so far.
+///
+/// Returns the checkpoint saved during the hook, if any.
+pub type TrainStepHook<'a, M, O, LR> =
+    dyn FnMut(&M, &O, &LR, usize) -> Option<SavedCheckpoint> + 'a;
+
 impl<I> ValidEpoch<I> {
     pub fn run<B, M, TO, VO>(&self, model: &M, callback: &mut Box<dyn LearnerCallback<TO, VO>>)
     where
         B: ADBackend,
         M: ADModule<B>,
         M::InnerModule: ValidStep<I, VO>,
+    {
+        self.run_with_early_stopping(model, callback, None)
+    }
+
+    /// Run the validation, updating the metric of the [early stopping](EarlyStopping) with
+    /// every item.
+    pub(crate) fn run_with_early_stopping<B, M, TO, VO>(
+        &self,
+        model: &M,
+        callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+        mut early_stopping: Option<&mut EarlyStopping<VO>>,
+    ) where
+        B: ADBackend,
+        M: ADModule<B>,
+        M::InnerModule: ValidStep<I, VO>,
     {
         log::info!("Executing validation step for epoch {}", self.epoch);
         let model = model.valid();
+        callback.on_valid_start_epoch(self.epoch);
 
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
@@ -51,6 +78,10 @@ impl<I> ValidEpoch<I> {
                 None,
             );
 
+            if let Some(early_stopping) = early_stopping.as_mut() {
+                early_stopping.update(&item);
+            }
+
             callback.on_valid_item(item);
         }
         callback.on_valid_end_epoch(self.epoch);
@@ -59,11 +90,41 @@ impl<I> ValidEpoch<I> {
 
 impl<TI> TrainEpoch<TI> {
     pub fn run<B, M, O, LR, TO, VO>(
+        &self,
+        model: M,
+        optim: O,
+        scheduler: &mut LR,
+        callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+    ) -> (M, O)
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+        O: Optimizer<M, B>,
+        M: TrainStep<TI, TO>,
+        LR: LRScheduler,
+    {
+        self.run_with_hooks(
+            model,
+            optim,
+            scheduler,
+            callback,
+            &mut None,
+            &mut |_, _, _, _| None,
+        )
+    }
+
+    /// Train the model on one device.
+    ///
+    /// When the [guard](NonFiniteGuard) finds non-finite values, the update of the step is
+    /// skipped, and the epoch ends early if the guard interrupts the training.
+    pub(crate) fn run_with_hooks<B, M, O, LR, TO, VO>(
         &self,
         mut model: M,
         mut optim: O,
         scheduler: &mut LR,
         callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+        guard: &mut Option<NonFiniteGuard<TO>>,
+        on_step: &mut TrainStepHook<M, O, LR>,
     ) -> (M, O)
     where
         B: ADBackend,
@@ -73,9 +134,10 @@ impl<TI> TrainEpoch<TI> {
         LR: LRScheduler,
     {
         log::info!("Executing training step for epoch {}", self.epoch,);
+        callback.on_train_start_epoch(self.epoch);
 
         let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
+        let mut iteration = self.skip(&mut iterator);
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
@@ -86,19 +148,40 @@ impl<TI> TrainEpoch<TI> {
 
             let progress = iterator.progress();
             let item = model.step(item);
+            let is_finite = match guard {
+                Some(guard) => guard.check(
+                    &model,
+                    core::slice::from_ref(&item.item),
+                    (!optim.skips_non_finite_grads()).then_some(&item.grads),
+                    self.epoch,
+                    iteration,
+                ),
+                None => true,
+            };
 
             match self.grad_accumulation {
+                _ if !is_finite => {}
                 Some(accumulation) => {
                     accumulator.accumulate(&model, item.grads);
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
+                        callback.on_before_optimizer_step(self.epoch, iteration, &grads);
                         model = optim.step(lr, model, grads);
                         accumulation_current = 0;
+                        self.after_optimizer_step(
+                            &model, &optim, scheduler, iteration, callback, on_step,
+                        );
                     }
                 }
-                None => model = optim.step(lr, model, item.grads),
+                None => {
+                    callback.on_before_optimizer_step(self.epoch, iteration, &item.grads);
+                    model = optim.step(lr, model, item.grads);
+                    self.after_optimizer_step(
+                        &model, &optim, scheduler, iteration, callback, on_step,
+                    );
+                }
             }
 
             let item = LearnerItem::new(
@@ -111,6 +194,10 @@ impl<TI> TrainEpoch<TI> {
             );
 
             callback.on_train_item(item);
+
+            if is_interrupted(guard) {
+                break;
+            }
         }
         callback.on_train_end_epoch(self.epoch);
 
@@ -120,12 +207,57 @@ impl<TI> TrainEpoch<TI> {
 
 impl<TI> TrainEpoch<TI> {
     pub fn run_multi_device<B, M, O, S, TO, VO>(
+        &self,
+        model: M,
+        optim: O,
+        lr_scheduler: &mut S,
+        callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+        devices: Vec<B::Device>,
+    ) -> (M, O)
+    where
+        B: ADBackend,
+        M: ADModule<B> + 'static,
+        O: Optimizer<M, B>,
+        M: TrainStep<TI, TO>,
+        S: LRScheduler,
+        TI: Send + 'static,
+        TO: Send + 'static,
+    {
+        self.run_multi_device_with_hooks(
+            model,
+            optim,
+            lr_scheduler,
+            callback,
+            &mut None,
+            devices,
+            &mut |_, _, _, _| None,
+        )
+    }
+
+    /// Train the model with data parallelism.
+    ///
+    /// At each step, every device computes the gradients of its own batch, so the batches of
+    /// the dataloader are the splits of a global batch across devices. The gradients are
+    /// averaged on the first device, where the optimizer updates the model once, and the
+    /// updated weights are sent to every device for the next step.
+    ///
+    /// The gradients of each device have the same weight in the average, since the size of the
+    /// batches isn't known, which approximates the gradients of the global batch when the batches
+    /// don't all have the same size.
+    ///
+    /// With gradients accumulation, the averaged gradients of multiple steps are summed before
+    /// updating the model. The [guard](NonFiniteGuard) checks the loss of each device and the
+    /// averaged gradients.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn run_multi_device_with_hooks<B, M, O, S, TO, VO>(
         &self,
         mut model: M,
         mut optim: O,
         lr_scheduler: &mut S,
         callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+        guard: &mut Option<NonFiniteGuard<TO>>,
         devices: Vec<B::Device>,
+        on_step: &mut TrainStepHook<M, O, S>,
     ) -> (M, O)
     where
         B: ADBackend,
@@ -141,51 +273,79 @@ impl<TI> TrainEpoch<TI> {
             self.epoch,
             devices
         );
+        callback.on_train_start_epoch(self.epoch);
 
         let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
+        let mut iteration = self.skip(&mut iterator);
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
-        let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
+        let accumulation = self.grad_accumulation.unwrap_or(1);
         let step = MultiDevicesTrainStep::new(&devices);
 
         // The main device is always the first in the list.
         let device_main = devices.get(0).unwrap().clone();
 
         loop {
-            let items = step.step(&mut iterator, &model);
-            if items.is_empty() {
+            let outputs = step.step(&mut iterator, &model);
+            if outputs.is_empty() {
                 break;
             }
 
-            for item in items {
-                iteration += 1;
-                let lr = lr_scheduler.step();
-                let progress = iterator.progress();
+            let lr = lr_scheduler.step();
+            let progress = iterator.progress();
+            let (grads, items): (Vec<_>, Vec<_>) = outputs
+                .into_iter()
+                .map(|output| (output.grads, output.item))
+                .unzip();
+            iteration += items.len();
 
-                let grads = item.grads.to_device(&device_main, &model);
+            let grads = GradientsParams::mean(grads, &device_main, &model);
+            let is_finite = match guard {
+                Some(guard) => {
+                    let grads = (!optim.skips_non_finite_grads()).then_some(&grads);
+                    guard.check(&model, &items, grads, self.epoch, iteration)
+                }
+                None => true,
+            };
 
+            if is_finite {
                 accumulator.accumulate(&model, grads);
                 accumulation_current += 1;
+            }
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = optim.step(lr, model, grads);
-                    accumulation_current = 0;
-                }
+            if is_finite && accumulation <= accumulation_current {
+                let grads = accumulator.grads();
+                callback.on_before_optimizer_step(self.epoch, iteration, &grads);
+                model = optim.step(lr, model, grads);
+                accumulation_current = 0;
+                self.after_optimizer_step(
+                    &model,
+                    &optim,
+                    lr_scheduler,
+                    iteration,
+                    callback,
+                    on_step,
+                );
+            }
 
+            let num_items = items.len();
+            for (i, item) in items.into_iter().enumerate() {
                 let item = LearnerItem::new(
-                    item.item,
-                    progress,
+                    item,
+                    progress.clone(),
                     self.epoch,
                     self.epoch_total,
-                    iteration,
+                    iteration + 1 + i - num_items,
                     Some(lr),
                 );
 
                 callback.on_train_item(item);
             }
+
+            if is_interrupted(guard) {
+                break;
+            }
         }
 
         callback.on_train_end_epoch(self.epoch);
@@ -193,3 +353,210 @@ impl<TI> TrainEpoch<TI> {
         (model, optim)
     }
 }
+
+fn is_interrupted<T>(guard: &Option<NonFiniteGuard<T>>) -> bool {
+    matches!(guard, Some(guard) if guard.is_interrupted())
+}
+
+impl<TI> TrainEpoch<TI> {
+    fn after_optimizer_step<M, O, LR, TO, VO>(
+        &self,
+        model: &M,
+        optim: &O,
+        scheduler: &LR,
+        iteration: usize,
+        callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+        on_step: &mut TrainStepHook<M, O, LR>,
+    ) {
+        callback.on_after_optimizer_step(self.epoch, iteration);
+
+        if let Some(checkpoint) = on_step(model, optim, scheduler, iteration) {
+            callback.on_checkpoint_saved(checkpoint);
+        }
+    }
+
+    /// Skip the batches already processed, returning the number of skipped batches.
+    fn skip(&self, iterator: &mut Box<dyn DataLoaderIterator<TI> + '_>) -> usize {
+        if self.iteration_start > 0 {
+            log::info!(
+                "Skipping {} batches already processed in epoch {}",
+                self.iteration_start,
+                self.epoch
+            );
+        }
+
+        let mut iteration = 0;
+        while iteration < self.iteration_start && iterator.next().is_some() {
+            iteration += 1;
+        }
+        iteration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NonFiniteAction, RegressionOutput, TestADBackend, TrainOutput};
+    use burn_core::data::dataloader::{batcher::Batcher, BatchDataLoader, FixBatchStrategy};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::{Data, Shape, Tensor};
+
+    struct TestBatcher;
+    struct TensorBatcher;
+    struct NoopCallback;
+
+    impl Batcher<usize, Vec<usize>> for TestBatcher {
+        fn batch(&self, items: Vec<usize>) -> Vec<usize> {
+            items
+        }
+    }
+
+    impl Batcher<[f32; 2], Tensor<TestADBackend, 2>> for TensorBatcher {
+        fn batch(&self, items: Vec<[f32; 2]>) -> Tensor<TestADBackend, 2> {
+            let shape = Shape::new([items.len(), 2]);
+            Tensor::from_data(Data::new(items.concat(), shape))
+        }
+    }
+
+    type TestOutput = RegressionOutput<TestADBackend>;
+
+    impl TrainStep<Tensor<TestADBackend, 2>, TestOutput> for Linear<TestADBackend> {
+        fn step(&self, item: Tensor<TestADBackend, 2>) -> TrainOutput<TestOutput> {
+            let output = self.forward(item);
+            let loss = output.clone().powf(2.0).mean();
+            let grads = loss.backward();
+
+            TrainOutput::new(
+                self,
+                grads,
+                RegressionOutput::new(loss, output.clone(), output),
+            )
+        }
+    }
+
+    impl LearnerCallback<TestOutput, ()> for NoopCallback {}
+
+    fn dataloader(
+        items: Vec<[f32; 2]>,
+        batch_size: usize,
+    ) -> Arc<dyn DataLoader<Tensor<TestADBackend, 2>>> {
+        Arc::new(BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(batch_size)),
+            Arc::new(InMemDataset::new(items)),
+            Arc::new(TensorBatcher),
+        ))
+    }
+
+    fn train(
+        model: Linear<TestADBackend>,
+        items: Vec<[f32; 2]>,
+        guard: &mut Option<NonFiniteGuard<TestOutput>>,
+    ) -> Linear<TestADBackend> {
+        let mut callback: Box<dyn LearnerCallback<TestOutput, ()>> = Box::new(NoopCallback);
+        let (model, _) = TrainEpoch::new(dataloader(items, 2), 1, 1, None, 0).run_with_hooks(
+            model,
+            SgdConfig::new().init(),
+            &mut 0.5,
+            &mut callback,
+            guard,
+            &mut |_, _, _, _| None,
+        );
+
+        model
+    }
+
+    fn assert_same_weights(model: Linear<TestADBackend>, expected: Linear<TestADBackend>) {
+        model
+            .into_record()
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.into_record().weight.to_data(), 5);
+    }
+
+    #[test]
+    fn should_skip_already_processed_batches() {
+        let dataloader = Arc::new(BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(2)),
+            Arc::new(InMemDataset::new((0..10).collect())),
+            Arc::new(TestBatcher),
+        ));
+        let epoch = TrainEpoch::new(dataloader.clone(), 1, 1, None, 3);
+        let mut iterator = dataloader.iter();
+
+        let iteration = epoch.skip(&mut iterator);
+
+        assert_eq!(iteration, 3);
+        assert_eq!(iterator.next(), Some(vec![6, 7]));
+    }
+
+    #[test]
+    fn data_parallel_step_should_match_single_device_step_on_global_batch() {
+        let model = LinearConfig::new(2, 3).init::<TestADBackend>();
+        let items: Vec<[f32; 2]> = (0..8)
+            .map(|i| [i as f32 / 8.0, 1.0 - i as f32 / 4.0])
+            .collect();
+        let mut callback: Box<dyn LearnerCallback<TestOutput, ()>> = Box::new(NoopCallback);
+
+        let (model_single, _) = TrainEpoch::new(dataloader(items.clone(), 4), 1, 1, None, 0).run(
+            model.clone(),
+            SgdConfig::new().init(),
+            &mut 0.5,
+            &mut callback,
+        );
+        let (model_parallel, _) = TrainEpoch::new(dataloader(items, 2), 1, 1, None, 0)
+            .run_multi_device(
+                model,
+                SgdConfig::new().init(),
+                &mut 0.5,
+                &mut callback,
+                vec![Default::default(); 2],
+            );
+
+        let record_single = model_single.into_record();
+        let record_parallel = model_parallel.into_record();
+        record_parallel
+            .weight
+            .to_data()
+            .assert_approx_eq(&record_single.weight.to_data(), 5);
+        record_parallel
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&record_single.bias.unwrap().to_data(), 5);
+    }
+
+    #[test]
+    fn non_finite_guard_should_skip_step_with_nan_loss() {
+        let model = LinearConfig::new(2, 3).init::<TestADBackend>();
+        let items_valid = vec![[0.5, 1.0], [1.0, 0.5], [0.2, 0.1], [0.3, 0.4]];
+        let mut items = items_valid.clone();
+        items.splice(2..2, [[f32::NAN, 1.0], [1.0, 1.0]]);
+        let mut guard = Some(NonFiniteGuard::new(NonFiniteAction::SkipStep));
+
+        let model_guarded = train(model.clone(), items, &mut guard);
+        let model_expected = train(model, items_valid, &mut None);
+
+        assert!(!guard.unwrap().is_interrupted());
+        assert_same_weights(model_guarded, model_expected);
+    }
+
+    #[test]
+    fn non_finite_guard_should_interrupt_epoch() {
+        let model = LinearConfig::new(2, 3).init::<TestADBackend>();
+        let items = vec![[0.5, 1.0], [1.0, 0.5], [f32::INFINITY, 1.0], [1.0, 1.0]];
+        let items = [items.clone(), items].concat();
+        let mut guard = Some(NonFiniteGuard::new(NonFiniteAction::Stop));
+
+        let model_guarded = train(model.clone(), items.clone(), &mut guard);
+        let model_expected = train(model, items[..2].to_vec(), &mut None);
+
+        assert_eq!(
+            guard.unwrap().take_interruption(),
+            Some(NonFiniteAction::Stop)
+        );
+        assert_same_weights(model_guarded, model_expected);
+    }
+}
@@ -1,7 +1,12 @@
 mod base;
 mod builder;
 mod classification;
+mod early_stopping;
 mod epoch;
+mod multi_label;
+mod non_finite;
+mod regression;
+mod sam;
 mod step;
 mod train_val;
 
@@ -10,7 +15,12 @@ pub(crate) mod log;
 pub use base::*;
 pub use builder::*;
 pub use classification::*;
+pub use early_stopping::*;
 pub use epoch::*;
+pub use multi_label::*;
+pub use non_finite::*;
+pub use regression::*;
+pub use sam::*;
 pub use step::*;
 pub use train::*;
 pub use train_val::*;
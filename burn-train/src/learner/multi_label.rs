@@ -0,0 +1,26 @@
+use crate::metric::{Adaptor, LossInput, MultiLabelInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// Multi-label classification output adapted for multiple metrics.
+///
+/// The output contains one logit per label and the targets are `1` for the labels that are
+/// present and `0` otherwise.
+#[derive(new, Clone)]
+pub struct MultiLabelClassificationOutput<B: Backend> {
+    pub loss: Tensor<B, 1>,
+    pub output: Tensor<B, 2>,
+    pub targets: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for MultiLabelClassificationOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<MultiLabelInput<B>> for MultiLabelClassificationOutput<B> {
+    fn adapt(&self) -> MultiLabelInput<B> {
+        MultiLabelInput::new(self.output.clone(), self.targets.clone())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::metric::{Adaptor, LossInput};
+use burn_core::module::{ADModule, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{ADBackend, Backend};
+
+/// The action taken by the learner when a training step produces a loss or gradients with
+/// infinite or NaN values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonFiniteAction {
+    /// Skip the update of the step and continue the training.
+    SkipStep,
+    /// Stop the training.
+    Stop,
+    /// Reload the last checkpoint saved and resume the training from it.
+    ///
+    /// The training is stopped when no checkpoint was saved since the start of the training or
+    /// since the last reload, since the same failure would likely happen again.
+    ReloadCheckpoint,
+}
+
+/// Check the loss and the gradients of each training step for infinite and NaN values.
+pub struct NonFiniteGuard<T> {
+    loss: Box<dyn Fn(&T) -> f64>,
+    action: NonFiniteAction,
+    interruption: Option<NonFiniteAction>,
+}
+
+impl<T> NonFiniteGuard<T> {
+    /// Create the guard taking the given action when non-finite values are found.
+    pub fn new<B>(action: NonFiniteAction) -> Self
+    where
+        B: Backend,
+        T: Adaptor<LossInput<B>> + 'static,
+    {
+        Self {
+            loss: Box::new(|item: &T| {
+                let loss: LossInput<B> = item.adapt();
+                loss.mean()
+            }),
+            action,
+            interruption: None,
+        }
+    }
+
+    /// Check the outputs of a training step, one per device, returning if the losses and all
+    /// the gradients are finite.
+    ///
+    /// The gradients aren't checked when they are `None`, which is the case when the optimizer
+    /// [skips the non-finite gradients](burn_core::optim::Optimizer::skips_non_finite_grads)
+    /// itself, e.g. with mixed precision where the loss scale must be reduced.
+    ///
+    /// The parameters with non-finite gradients are reported through the logger. When the
+    /// action interrupts the training, it is kept until [taken](Self::take_interruption).
+    pub(crate) fn check<B, M>(
+        &mut self,
+        model: &M,
+        items: &[T],
+        grads: Option<&GradientsParams>,
+        epoch: usize,
+        iteration: usize,
+    ) -> bool
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+    {
+        let losses: Vec<f64> = items.iter().map(|item| (self.loss)(item)).collect();
+        let params = match grads {
+            Some(grads) => grads.non_finite_params(model),
+            None => Vec::new(),
+        };
+
+        if losses.iter().all(|loss| loss.is_finite()) && params.is_empty() {
+            return true;
+        }
+
+        let params: Vec<String> = params.iter().map(ParamId::to_string).collect();
+        log::warn!(
+            "Non-finite values at epoch {} iteration {}: loss {:?}, gradients of the parameters \
+             [{}], action {:?}",
+            epoch,
+            iteration,
+            losses,
+            params.join(", "),
+            self.action
+        );
+
+        if self.action != NonFiniteAction::SkipStep {
+            self.interruption = Some(self.action);
+        }
+
+        false
+    }
+
+    /// If the training must be interrupted.
+    pub(crate) fn is_interrupted(&self) -> bool {
+        self.interruption.is_some()
+    }
+
+    /// Take the action interrupting the training, if any.
+    pub(crate) fn take_interruption(&mut self) -> Option<NonFiniteAction> {
+        self.interruption.take()
+    }
+}
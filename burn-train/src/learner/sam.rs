@@ -0,0 +1,39 @@
+use super::TrainOutput;
+use burn_core::module::ADModule;
+use burn_core::optim::{GradientsParams, SamConfig};
+use burn_core::tensor::backend::ADBackend;
+use burn_core::tensor::Tensor;
+
+/// Helper to compute the gradients of a [TrainStep](super::TrainStep) with
+/// Sharpness-Aware Minimization.
+///
+/// The gradients are computed on the model perturbed towards the point of highest loss in its
+/// neighborhood, so the update made by the optimizer in [Learner::fit](super::Learner::fit)
+/// follows the SAM update rule. Any optimizer can be used, including [Sam](burn_core::optim::Sam),
+/// which acts as the wrapped optimizer when no perturbation is pending.
+#[derive(new)]
+pub struct SamTrainStep {
+    config: SamConfig,
+}
+
+impl SamTrainStep {
+    /// Compute the train output of the given model.
+    ///
+    /// The forward function returns the loss used to compute the gradients and the item
+    /// forwarded to the metrics. It is called twice: once on the model and once on the
+    /// perturbed model.
+    pub fn step<B, M, TO, F>(&self, model: &M, forward: F) -> TrainOutput<TO>
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+        F: Fn(&M) -> (Tensor<B, 1>, TO),
+    {
+        let (loss, _) = forward(model);
+        let grads = GradientsParams::from_grads(loss.backward(), model);
+
+        let model_perturbed = self.config.perturb(model.clone(), grads);
+        let (loss, item) = forward(&model_perturbed);
+
+        TrainOutput::new(&model_perturbed, loss.backward(), item)
+    }
+}
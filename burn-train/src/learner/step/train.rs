@@ -5,9 +5,13 @@ use burn_core::{
 use std::sync::mpsc::{Receiver, Sender};
 use std::thread::spawn;
 
+/// Run the training step of a module on multiple devices in parallel.
+///
+/// Each device processes its own batch with a copy of the module, forked from the reference
+/// module at every step so that all devices use the same weights.
 pub struct MultiDevicesTrainStep<B: ADBackend, M, TI, TO> {
     workers: Vec<Worker<B, M, TI>>,
-    receiver: Receiver<TrainOutput<TO>>,
+    receiver: Receiver<(usize, TrainOutput<TO>)>,
 }
 
 struct Message<M, TI> {
@@ -16,6 +20,7 @@ struct Message<M, TI> {
 }
 
 struct Worker<B: ADBackend, M, TI> {
+    index: usize,
     sender_input: Sender<Message<M, TI>>,
     device: B::Device,
 }
@@ -35,7 +40,7 @@ where
 
     fn start<TO>(
         &self,
-        sender_output: Sender<TrainOutput<TO>>,
+        sender_output: Sender<(usize, TrainOutput<TO>)>,
         receiver_input: Receiver<Message<M, TI>>,
     ) where
         TI: Send + 'static,
@@ -43,6 +48,7 @@ where
         M: TrainStep<TI, TO> + Send + 'static,
     {
         let device = self.device.clone();
+        let index = self.index;
 
         spawn(move || loop {
             match receiver_input.recv() {
@@ -50,7 +56,7 @@ where
                     let step = item.model.fork(&device);
                     let output = step.step(item.item);
 
-                    sender_output.send(output).unwrap();
+                    sender_output.send((index, output)).unwrap();
                 }
                 Err(_err) => {
                     log::info!("Closing thread on device {:?}", device);
@@ -75,9 +81,11 @@ where
         let (sender_output, receiver_output) = std::sync::mpsc::channel();
         let workers = devices
             .iter()
-            .map(|device| {
+            .enumerate()
+            .map(|(index, device)| {
                 let (sender_input, receiver_input) = std::sync::mpsc::channel();
                 let worker = Worker {
+                    index,
                     sender_input,
                     device: device.clone(),
                 };
@@ -93,6 +101,10 @@ where
         }
     }
 
+    /// Send one batch to each device and wait for their outputs.
+    ///
+    /// The outputs are in the order of the devices, and there are fewer outputs than devices
+    /// only when the dataloader is exhausted.
     pub fn step<'a>(
         &self,
         dataloader: &mut Box<dyn DataLoaderIterator<TI> + 'a>,
@@ -113,7 +125,8 @@ where
             let output = self.receiver.recv().unwrap();
             outputs.push(output);
         }
+        outputs.sort_by_key(|(index, _)| *index);
 
-        outputs
+        outputs.into_iter().map(|(_, output)| output).collect()
     }
 }
@@ -1,6 +1,9 @@
-use super::Learner;
+use super::{
+    EarlyStopping, Learner, LearnerCheckpoint, LearnerCheckpointer, NonFiniteAction,
+    NonFiniteGuard, TrainingStateRecord,
+};
 
-use crate::{TrainEpoch, ValidEpoch};
+use crate::{SavedCheckpoint, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::lr_scheduler::LRScheduler;
 use burn_core::module::ADModule;
@@ -54,52 +57,385 @@ where
             self.model = self.model.fork(device);
         }
 
-        let starting_epoch = match self.checkpoint {
+        let state = match self.checkpoint {
             Some(checkpoint) => {
-                self = self.load_checkpoint(checkpoint);
-                checkpoint
+                let (learner, state) = self.load_checkpoint(checkpoint);
+                self = learner;
+                state
             }
-            None => 1,
+            None => TrainingStateRecord::new(1, 0, 0, 0, None),
         };
+        let mut epoch = state.epoch;
+        let mut iteration_start = state.iteration;
+        let mut dataloader_epoch = state.dataloader_epoch;
+        let mut num_step_checkpoints = state.num_step_checkpoints;
 
         let mut model = self.model;
         let mut optim = self.optim;
+        let mut model_best = None;
+        let mut num_iterations_since_checkpoint = 0;
+        let mut checkpoint_last = self.checkpoint;
+        let mut checkpoint_reloaded = None;
 
-        for epoch in starting_epoch..self.num_epochs + 1 {
+        self.callback.on_fit_start();
+
+        while epoch <= self.num_epochs {
+            dataloader_train.set_epoch(dataloader_epoch);
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
+                iteration_start,
             );
 
+            let mut iteration_last = iteration_start;
+            iteration_start = 0;
+
+            let checkpointer_step = &self.checkpointer_step;
+            let checkpoint_interval = self.checkpoint_interval;
+            let early_stopping_record = self.early_stopping.as_ref().map(EarlyStopping::to_record);
+            let mut on_step = |model: &M, optim: &O, scheduler: &LR, iteration: usize| {
+                num_iterations_since_checkpoint += iteration - iteration_last;
+                iteration_last = iteration;
+
+                if let (Some(checkpointer), Some(interval)) =
+                    (checkpointer_step, checkpoint_interval)
+                {
+                    if num_iterations_since_checkpoint >= interval {
+                        num_step_checkpoints += 1;
+                        num_iterations_since_checkpoint = 0;
+
+                        checkpoint_last = Some(LearnerCheckpoint::Step(num_step_checkpoints));
+                        let state = TrainingStateRecord::new(
+                            epoch,
+                            iteration,
+                            dataloader_epoch,
+                            num_step_checkpoints,
+                            early_stopping_record.clone(),
+                        );
+                        checkpointer.save::<B, M, O, LR>(
+                            num_step_checkpoints,
+                            model,
+                            optim,
+                            scheduler,
+                            state,
+                        );
+
+                        return Some(SavedCheckpoint::Step {
+                            epoch,
+                            index: num_step_checkpoints,
+                        });
+                    }
+                }
+
+                None
+            };
+
             if self.devices.len() > 1 {
-                (model, optim) = epoch_train.run_multi_device(
+                (model, optim) = epoch_train.run_multi_device_with_hooks(
                     model,
                     optim,
                     &mut self.lr_scheduler,
                     &mut self.callback,
+                    &mut self.non_finite_guard,
                     self.devices.clone(),
+                    &mut on_step,
                 )
             } else {
-                (model, optim) =
-                    epoch_train.run(model, optim, &mut self.lr_scheduler, &mut self.callback);
+                (model, optim) = epoch_train.run_with_hooks(
+                    model,
+                    optim,
+                    &mut self.lr_scheduler,
+                    &mut self.callback,
+                    &mut self.non_finite_guard,
+                    &mut on_step,
+                );
             }
 
-            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run(&model, &mut self.callback);
+            let interruption = self
+                .non_finite_guard
+                .as_mut()
+                .and_then(NonFiniteGuard::take_interruption);
+
+            if let Some(action) = interruption {
+                let checkpoint = match (action, checkpoint_last) {
+                    (NonFiniteAction::ReloadCheckpoint, Some(checkpoint))
+                        if checkpoint_reloaded != Some(checkpoint) =>
+                    {
+                        checkpoint
+                    }
+                    _ => {
+                        log::warn!(
+                            "Stopping the training at epoch {epoch} after non-finite values"
+                        );
+                        break;
+                    }
+                };
+
+                log::warn!("Reloading the checkpoint {checkpoint:?} after non-finite values");
+                self.model = model;
+                self.optim = optim;
+                let (learner, state) = self.load_checkpoint(checkpoint);
+                self = learner;
+                model = self.model;
+                optim = self.optim;
 
-            Self::checkpoint(
+                epoch = state.epoch;
+                iteration_start = state.iteration;
+                dataloader_epoch = state.dataloader_epoch;
+                checkpoint_reloaded = Some(checkpoint);
+                num_iterations_since_checkpoint = 0;
+                continue;
+            }
+
+            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
+            epoch_valid.run_with_early_stopping(
                 &model,
-                &optim,
-                &self.lr_scheduler,
-                &self.checkpointer_model,
-                &self.checkpointer_optimizer,
-                &self.checkpointer_scheduler,
-                epoch,
+                &mut self.callback,
+                self.early_stopping.as_mut(),
             );
+
+            // The early stopping state of the epoch is part of its checkpoint.
+            let status = self
+                .early_stopping
+                .as_mut()
+                .map(|early_stopping| early_stopping.end_epoch(epoch));
+
+            if let Some(checkpointer) = &self.checkpointer {
+                let state = TrainingStateRecord::new(
+                    epoch,
+                    iteration_last,
+                    dataloader_epoch,
+                    num_step_checkpoints,
+                    self.early_stopping.as_ref().map(EarlyStopping::to_record),
+                );
+                checkpointer.save::<B, M, O, LR>(epoch, &model, &optim, &self.lr_scheduler, state);
+                checkpoint_last = Some(LearnerCheckpoint::Epoch(epoch));
+                self.callback
+                    .on_checkpoint_saved(SavedCheckpoint::Epoch(epoch));
+            }
+
+            if let (Some(early_stopping), Some(status)) = (&self.early_stopping, status) {
+                if status.improved {
+                    if let Some(checkpointer) = &self.checkpointer {
+                        checkpointer.save_best(epoch, model.clone().into_record());
+                        self.callback
+                            .on_checkpoint_saved(SavedCheckpoint::Best(epoch));
+                    }
+
+                    if early_stopping.restore_best() {
+                        model_best = Some(model.clone());
+                    }
+                }
+
+                if status.should_stop {
+                    if let (Some(best_epoch), Some(best_value)) =
+                        (early_stopping.best_epoch(), early_stopping.best_value())
+                    {
+                        log::info!(
+                            "Early stopping at epoch {}, the best epoch is {} with a value of {}",
+                            epoch,
+                            best_epoch,
+                            best_value
+                        );
+                    }
+                    break;
+                }
+            }
+
+            epoch += 1;
+            dataloader_epoch += 1;
+        }
+
+        self.callback.on_fit_end();
+
+        // The best model of a resumed training may come from before the resume.
+        let restore_best = matches!(
+            &self.early_stopping,
+            Some(early_stopping) if early_stopping.restore_best() && early_stopping.best_epoch().is_some()
+        );
+        if model_best.is_none() && restore_best {
+            model_best = self
+                .checkpointer
+                .as_ref()
+                .and_then(LearnerCheckpointer::restore_best)
+                .map(|record| model.clone().load_record(record));
         }
 
-        model
+        model_best.unwrap_or(model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkpoint::FileCheckpointer;
+    use crate::learner::SharedCheckpointers;
+    use crate::{LearnerCallback, RegressionOutput, TestADBackend, TestBackend};
+    use burn_core::data::dataloader::FixBatchStrategy;
+    use burn_core::data::dataloader::{batcher::Batcher, BatchDataLoader, DataLoaderBuilder};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::record::{BinFileRecorder, FullPrecisionSettings};
+    use burn_core::tensor::{backend::Backend, Data, Shape, Tensor};
+    use std::sync::Mutex;
+
+    type Item = [f32; 2];
+    type TestOutput = RegressionOutput<TestADBackend>;
+
+    #[derive(Clone)]
+    struct TestBatcher;
+    struct NoopCallback;
+
+    impl Batcher<Item, Vec<Item>> for TestBatcher {
+        fn batch(&self, items: Vec<Item>) -> Vec<Item> {
+            items
+        }
+    }
+
+    impl LearnerCallback<TestOutput, ()> for NoopCallback {}
+
+    struct CheckpointsCallback(Arc<Mutex<Vec<SavedCheckpoint>>>);
+
+    impl LearnerCallback<TestOutput, ()> for CheckpointsCallback {
+        fn on_checkpoint_saved(&mut self, checkpoint: SavedCheckpoint) {
+            self.0.lock().unwrap().push(checkpoint);
+        }
+    }
+
+    fn tensor<B: Backend>(items: Vec<Item>) -> Tensor<B, 2> {
+        let shape = Shape::new([items.len(), 2]);
+        Tensor::from_data(Data::new(items.concat(), shape).convert())
+    }
+
+    impl TrainStep<Vec<Item>, TestOutput> for Linear<TestADBackend> {
+        fn step(&self, item: Vec<Item>) -> TrainOutput<TestOutput> {
+            let targets = tensor(item.clone());
+            let output = self.forward(tensor(item));
+            let loss = (output.clone() - targets.clone()).powf(2.0).mean();
+            let grads = loss.backward();
+
+            TrainOutput::new(self, grads, RegressionOutput::new(loss, output, targets))
+        }
+    }
+
+    impl ValidStep<Vec<Item>, ()> for Linear<TestBackend> {
+        fn step(&self, _item: Vec<Item>) {}
+    }
+
+    /// Fit the model for two epochs with a step checkpoint every three iterations.
+    fn fit(
+        directory: &str,
+        checkpoint: Option<LearnerCheckpoint>,
+        model: Linear<TestADBackend>,
+        callback: Box<dyn LearnerCallback<TestOutput, ()>>,
+    ) -> Linear<TestADBackend> {
+        let items: Vec<Item> = (0..8)
+            .map(|i| [i as f32 / 8.0, 1.0 - i as f32 / 4.0])
+            .collect();
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .shuffle(42)
+            .build(InMemDataset::new(items.clone()));
+        let dataloader_valid = Arc::new(BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(2)),
+            Arc::new(InMemDataset::new(items)),
+            Arc::new(TestBatcher),
+        ));
+
+        let checkpointers = |suffix: &str| {
+            let checkpointer = |name: &str| {
+                FileCheckpointer::new(
+                    BinFileRecorder::<FullPrecisionSettings>::new(),
+                    directory,
+                    &format!("{name}{suffix}"),
+                    10,
+                )
+            };
+            SharedCheckpointers {
+                model: Arc::new(checkpointer("model")),
+                optim: Arc::new(checkpointer("optim")),
+                scheduler: Arc::new(checkpointer("scheduler")),
+                state: Arc::new(checkpointer("state")),
+            }
+            .into_async()
+        };
+
+        let learner = Learner {
+            model,
+            optim: SgdConfig::new().init(),
+            lr_scheduler: 0.1,
+            num_epochs: 2,
+            callback,
+            checkpoint,
+            checkpointer: Some(checkpointers("")),
+            checkpointer_step: Some(checkpointers("-step")),
+            checkpoint_interval: Some(3),
+            grad_accumulation: None,
+            devices: vec![Default::default()],
+            early_stopping: None,
+            non_finite_guard: None,
+        };
+
+        learner.fit(dataloader_train, dataloader_valid)
+    }
+
+    fn assert_same_model(model: Linear<TestADBackend>, expected: &Linear<TestADBackend>) {
+        let (record, expected) = (model.into_record(), expected.clone().into_record());
+
+        record
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.weight.to_data(), 5);
+        record
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected.bias.unwrap().to_data(), 5);
+    }
+
+    #[test]
+    fn resumed_training_should_match_uninterrupted_training() {
+        let directory = std::env::temp_dir().join("burn-train-resume");
+        let directory = directory.to_str().unwrap();
+        let init = || LinearConfig::new(2, 2).init::<TestADBackend>();
+
+        let fit = |checkpoint, model| fit(directory, checkpoint, model, Box::new(NoopCallback));
+
+        let model = fit(None, init());
+
+        // The second step checkpoint is saved in the middle of the second epoch.
+        let model_resumed_step = fit(Some(LearnerCheckpoint::Step(2)), init());
+        let model_resumed_epoch = fit(Some(LearnerCheckpoint::Epoch(1)), init());
+
+        assert_same_model(model_resumed_step, &model);
+        assert_same_model(model_resumed_epoch, &model);
+    }
+
+    #[test]
+    fn should_notify_callback_of_saved_checkpoints() {
+        let directory = std::env::temp_dir().join("burn-train-checkpoints");
+        let checkpoints = Arc::new(Mutex::new(Vec::new()));
+        let callback = Box::new(CheckpointsCallback(checkpoints.clone()));
+
+        fit(
+            directory.to_str().unwrap(),
+            None,
+            LinearConfig::new(2, 2).init(),
+            callback,
+        );
+
+        assert_eq!(
+            *checkpoints.lock().unwrap(),
+            vec![
+                SavedCheckpoint::Step { epoch: 1, index: 1 },
+                SavedCheckpoint::Epoch(1),
+                SavedCheckpoint::Step { epoch: 2, index: 2 },
+                SavedCheckpoint::Epoch(2),
+            ]
+        );
     }
 }
@@ -6,10 +6,15 @@ pub mod logger;
 pub mod metric;
 
 mod callback;
+mod evaluator;
 mod learner;
 
 pub use callback::*;
+pub use evaluator::*;
 pub use learner::*;
 
 #[cfg(test)]
 pub(crate) type TestBackend = burn_ndarray::NdArrayBackend<f32>;
+
+#[cfg(test)]
+pub(crate) type TestADBackend = burn_autodiff::ADBackendDecorator<TestBackend>;
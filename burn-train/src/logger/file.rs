@@ -17,6 +17,14 @@ impl FileLogger {
 
         Self { file }
     }
+
+    /// Create a logger appending to the file at the given path, creating it if needed.
+    pub fn append(path: &str) -> Self {
+        let mut options = std::fs::File::options();
+        let file = options.append(true).create(true).open(path).unwrap();
+
+        Self { file }
+    }
 }
 
 impl<T> Logger<T> for FileLogger
@@ -1,10 +1,18 @@
 use super::{AsyncLogger, FileLogger, Logger};
-use crate::metric::MetricEntry;
+use crate::{metric::MetricEntry, EvaluationSummary};
 use std::collections::HashMap;
 
 pub trait MetricLogger: Send {
     fn log(&mut self, item: &MetricEntry);
+    /// Log the entry of a [numeric](crate::metric::Numeric) metric along with its value.
+    fn log_numeric(&mut self, item: &MetricEntry, _value: f64) {
+        self.log(item);
+    }
     fn epoch(&mut self, epoch: usize);
+    /// Called at the end of an epoch with the metrics aggregated over the epoch.
+    fn summary(&mut self, _epoch: usize, _summary: &EvaluationSummary) {}
+    /// Called with the iteration of the item before its metrics are logged.
+    fn iteration(&mut self, _iteration: usize) {}
 }
 
 pub struct FileMetricLogger {
@@ -51,3 +59,13 @@ impl MetricLogger for FileMetricLogger {
         self.epoch = epoch;
     }
 }
+
+/// Metric logger discarding all the entries, for splits whose metrics aren't recorded.
+#[derive(Default)]
+pub struct NoopMetricLogger;
+
+impl MetricLogger for NoopMetricLogger {
+    fn log(&mut self, _item: &MetricEntry) {}
+
+    fn epoch(&mut self, _epoch: usize) {}
+}
@@ -2,8 +2,10 @@ mod async_logger;
 mod base;
 mod file;
 mod metric;
+mod structured;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
 pub use metric::*;
+pub use structured::*;
@@ -0,0 +1,465 @@
+use super::{AsyncLogger, FileLogger, Logger, MetricLogger};
+use crate::{metric::MetricEntry, EvaluationSummary};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The file format used by the [structured metric logger](StructuredMetricLogger).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricLogFormat {
+    /// Comma separated values, with a header.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+impl MetricLogFormat {
+    fn file_name(&self) -> &'static str {
+        match self {
+            MetricLogFormat::Csv => "metrics.csv",
+            MetricLogFormat::JsonLines => "metrics.jsonl",
+        }
+    }
+
+    fn summary_file_name(&self) -> &'static str {
+        match self {
+            MetricLogFormat::Csv => "summary.csv",
+            MetricLogFormat::JsonLines => "summary.jsonl",
+        }
+    }
+
+    fn serialize(&self, record: &MetricRecord) -> String {
+        match self {
+            MetricLogFormat::Csv => csv::serialize(record),
+            MetricLogFormat::JsonLines => serde_json::to_string(record).unwrap(),
+        }
+    }
+}
+
+/// A metric value logged by the [structured metric logger](StructuredMetricLogger).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MetricRecord {
+    /// The epoch, starting at 1.
+    pub epoch: usize,
+    /// The iteration in the epoch, the last one of the epoch for a summary record.
+    pub iteration: usize,
+    /// The split, e.g. `train` or `valid`.
+    pub split: String,
+    /// The name of the metric.
+    pub name: String,
+    /// The numeric value, if the metric is numeric.
+    pub value: Option<f64>,
+    /// Number of seconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+/// Metric logger writing machine-readable records in a single file per split.
+///
+/// Each batch value is written to the `metrics` file, and the value of each metric aggregated
+/// over an epoch is written to the `summary` file at the end of the epoch. The files are
+/// appended to, so a resumed training continues the records of the previous run.
+///
+/// The records can be loaded back with [read_metrics].
+pub struct StructuredMetricLogger {
+    logger: Box<dyn Logger<String>>,
+    logger_summary: Box<dyn Logger<String>>,
+    split: String,
+    format: MetricLogFormat,
+    epoch: usize,
+    iteration: usize,
+}
+
+impl StructuredMetricLogger {
+    /// Create the logger writing the records of the given split in the given directory.
+    pub fn new(directory: &str, split: &str, format: MetricLogFormat) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        Self {
+            logger: Self::open(directory, format.file_name(), format),
+            logger_summary: Self::open(directory, format.summary_file_name(), format),
+            split: split.to_string(),
+            format,
+            epoch: 1,
+            iteration: 0,
+        }
+    }
+
+    fn open(directory: &str, file_name: &str, format: MetricLogFormat) -> Box<dyn Logger<String>> {
+        let file_path = format!("{directory}/{file_name}");
+        let is_new = std::fs::metadata(&file_path)
+            .map(|metadata| metadata.len() == 0)
+            .unwrap_or(true);
+        let mut logger = AsyncLogger::new(Box::new(FileLogger::append(&file_path)));
+
+        if let (MetricLogFormat::Csv, true) = (format, is_new) {
+            logger.log(CSV_HEADER.join(","));
+        }
+
+        Box::new(logger)
+    }
+
+    fn record(&self, name: &str, value: Option<f64>) -> String {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs_f64())
+            .unwrap_or(0.0);
+
+        let record = MetricRecord {
+            epoch: self.epoch,
+            iteration: self.iteration,
+            split: self.split.clone(),
+            name: name.to_string(),
+            // Neither format can represent NaN or infinite values.
+            value: value.filter(|value| value.is_finite()),
+            timestamp,
+        };
+
+        self.format.serialize(&record)
+    }
+}
+
+impl MetricLogger for StructuredMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let line = self.record(&item.name, None);
+        self.logger.log(line);
+    }
+
+    fn log_numeric(&mut self, item: &MetricEntry, value: f64) {
+        let line = self.record(&item.name, Some(value));
+        self.logger.log(line);
+    }
+
+    fn epoch(&mut self, epoch: usize) {
+        self.epoch = epoch;
+        self.iteration = 0;
+    }
+
+    fn iteration(&mut self, iteration: usize) {
+        self.iteration = iteration;
+    }
+
+    fn summary(&mut self, epoch: usize, summary: &EvaluationSummary) {
+        self.epoch = epoch;
+
+        for metric in summary.metrics.iter() {
+            let line = self.record(&metric.name, metric.value);
+            self.logger_summary.log(line);
+        }
+    }
+}
+
+/// Metrics loaded from a training directory, grouped by split and metric name.
+#[derive(Default, Debug)]
+pub struct MetricsHistory {
+    series: HashMap<String, HashMap<String, Vec<MetricRecord>>>,
+    summaries: HashMap<String, HashMap<String, Vec<MetricRecord>>>,
+}
+
+impl MetricsHistory {
+    /// The records of a metric for a split, in the order they were logged.
+    pub fn get(&self, split: &str, name: &str) -> Option<&[MetricRecord]> {
+        self.series
+            .get(split)
+            .and_then(|metrics| metrics.get(name))
+            .map(|records| records.as_slice())
+    }
+
+    /// The summary records of a metric for a split, one per epoch.
+    pub fn summary(&self, split: &str, name: &str) -> Option<&[MetricRecord]> {
+        self.summaries
+            .get(split)
+            .and_then(|metrics| metrics.get(name))
+            .map(|records| records.as_slice())
+    }
+
+    /// The value of a numeric metric aggregated over each epoch, as computed by the metric.
+    pub fn epoch_values(&self, split: &str, name: &str) -> Vec<(usize, f64)> {
+        self.summary(split, name)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|record| Some((record.epoch, record.value?)))
+            .collect()
+    }
+
+    /// The names of the splits.
+    pub fn splits(&self) -> Vec<&str> {
+        let mut splits: Vec<_> = self.series.keys().map(|split| split.as_str()).collect();
+        splits.sort();
+        splits
+    }
+
+    /// The names of the metrics logged for a split.
+    pub fn metrics(&self, split: &str) -> Vec<&str> {
+        let mut names: Vec<_> = match self.series.get(split) {
+            Some(metrics) => metrics.keys().map(|name| name.as_str()).collect(),
+            None => Vec::new(),
+        };
+        names.sort();
+        names
+    }
+
+    /// The mean of the batch values of a numeric metric for each epoch.
+    pub fn epoch_means(&self, split: &str, name: &str) -> Vec<(usize, f64)> {
+        let mut means: Vec<(usize, f64, usize)> = Vec::new();
+
+        for record in self.get(split, name).unwrap_or_default() {
+            let value = match record.value {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match means.last_mut() {
+                Some((epoch, sum, count)) if *epoch == record.epoch => {
+                    *sum += value;
+                    *count += 1;
+                }
+                _ => means.push((record.epoch, value, 1)),
+            }
+        }
+
+        means
+            .into_iter()
+            .map(|(epoch, sum, count)| (epoch, sum / count as f64))
+            .collect()
+    }
+
+    fn insert(
+        series: &mut HashMap<String, HashMap<String, Vec<MetricRecord>>>,
+        record: MetricRecord,
+    ) {
+        series
+            .entry(record.split.clone())
+            .or_default()
+            .entry(record.name.clone())
+            .or_default()
+            .push(record);
+    }
+}
+
+/// Load the metrics written by [structured metric loggers](StructuredMetricLogger) in the
+/// given directory and its direct subdirectories, e.g. `train` and `valid`.
+pub fn read_metrics(directory: &str) -> std::io::Result<MetricsHistory> {
+    let mut history = MetricsHistory::default();
+    let mut directories = vec![Path::new(directory).to_path_buf()];
+
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            directories.push(path);
+        }
+    }
+
+    for directory in directories {
+        for format in [MetricLogFormat::Csv, MetricLogFormat::JsonLines] {
+            for (file_name, series) in [
+                (format.file_name(), &mut history.series),
+                (format.summary_file_name(), &mut history.summaries),
+            ] {
+                let path = directory.join(file_name);
+                if !path.exists() {
+                    continue;
+                }
+
+                let content = std::fs::read_to_string(path)?;
+                for record in parse_records(&content, format)? {
+                    MetricsHistory::insert(series, record);
+                }
+            }
+        }
+    }
+
+    Ok(history)
+}
+
+fn parse_records(content: &str, format: MetricLogFormat) -> std::io::Result<Vec<MetricRecord>> {
+    let lines = content.lines().filter(|line| !line.is_empty());
+
+    match format {
+        MetricLogFormat::Csv => lines.skip(1).map(csv::deserialize).collect(),
+        MetricLogFormat::JsonLines => lines
+            .map(|line| serde_json::from_str(line).map_err(std::io::Error::from))
+            .collect(),
+    }
+}
+
+const CSV_HEADER: [&str; 6] = ["epoch", "iteration", "split", "name", "value", "timestamp"];
+
+mod csv {
+    use super::MetricRecord;
+    use burn_core::data::dataset::source::file::{escape_csv_field, split_csv_record};
+    use std::io::{Error, ErrorKind};
+
+    pub fn serialize(record: &MetricRecord) -> String {
+        let value = match record.value {
+            Some(value) => value.to_string(),
+            None => String::new(),
+        };
+
+        [
+            record.epoch.to_string(),
+            record.iteration.to_string(),
+            escape_csv_field(&record.split, b','),
+            escape_csv_field(&record.name, b','),
+            value,
+            record.timestamp.to_string(),
+        ]
+        .join(",")
+    }
+
+    pub fn deserialize(line: &str) -> Result<MetricRecord, Error> {
+        let invalid = || Error::new(ErrorKind::InvalidData, format!("Invalid record: {line}"));
+        let fields = split_csv_record(line.as_bytes(), b',').map_err(|_| invalid())?;
+
+        if fields.len() != super::CSV_HEADER.len() {
+            return Err(invalid());
+        }
+
+        let value = match fields[4].as_str() {
+            "" => None,
+            value => Some(value.parse().map_err(|_| invalid())?),
+        };
+
+        Ok(MetricRecord {
+            epoch: fields[0].parse().map_err(|_| invalid())?,
+            iteration: fields[1].parse().map_err(|_| invalid())?,
+            split: fields[2].clone(),
+            name: fields[3].clone(),
+            value,
+            timestamp: fields[5].parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MetricSummary;
+
+    #[test]
+    fn should_write_and_read_csv_records() {
+        should_write_and_read_records(MetricLogFormat::Csv);
+    }
+
+    #[test]
+    fn should_write_and_read_json_lines_records() {
+        should_write_and_read_records(MetricLogFormat::JsonLines);
+    }
+
+    #[test]
+    fn csv_should_escape_special_characters() {
+        let record = MetricRecord {
+            epoch: 1,
+            iteration: 2,
+            split: "train".to_string(),
+            name: "F1, \"macro\"".to_string(),
+            value: None,
+            timestamp: 10.5,
+        };
+
+        let line = csv::serialize(&record);
+
+        assert_eq!(line, "1,2,train,\"F1, \"\"macro\"\"\",,10.5");
+        assert_eq!(csv::deserialize(&line).unwrap(), record);
+    }
+
+    #[test]
+    fn csv_should_keep_quotes_inside_unquoted_fields() {
+        let record = csv::deserialize("1,2,valid,Accuracy 5\" crop,0.5,10.5").unwrap();
+
+        assert_eq!(record.name, "Accuracy 5\" crop");
+        assert_eq!(record.value, Some(0.5));
+    }
+
+    #[test]
+    fn should_append_to_the_records_of_a_resumed_training() {
+        let directory = std::env::temp_dir().join("burn-train-metrics-resumed");
+        std::fs::remove_dir_all(&directory).ok();
+        let directory = directory.to_str().unwrap();
+
+        for epoch in 1..3 {
+            let mut logger = StructuredMetricLogger::new(directory, "train", MetricLogFormat::Csv);
+            logger.epoch(epoch);
+            logger.iteration(1);
+            logger.log_numeric(&entry("Loss", "-"), epoch as f64);
+            logger.summary(epoch, &summary("Loss", epoch as f64));
+        }
+
+        let history = read_metrics(directory).unwrap();
+
+        assert_eq!(
+            history.epoch_means("train", "Loss"),
+            vec![(1, 1.0), (2, 2.0)]
+        );
+        assert_eq!(
+            history.epoch_values("train", "Loss"),
+            vec![(1, 1.0), (2, 2.0)]
+        );
+    }
+
+    fn should_write_and_read_records(format: MetricLogFormat) {
+        let directory = std::env::temp_dir().join(format!("burn-train-metrics-{format:?}"));
+        std::fs::remove_dir_all(&directory).ok();
+        let directory = directory.to_str().unwrap();
+
+        let mut logger_train =
+            StructuredMetricLogger::new(&format!("{directory}/train"), "train", format);
+        let mut logger_valid =
+            StructuredMetricLogger::new(&format!("{directory}/valid"), "valid", format);
+
+        for epoch in 1..3 {
+            logger_train.epoch(epoch);
+            for iteration in 1..3 {
+                let loss = (epoch * 10 + iteration) as f64;
+                logger_train.iteration(iteration);
+                // The value is the one given by the dashboard, not the formatted entry.
+                logger_train.log_numeric(&entry("Loss", "-"), loss);
+                logger_train.log(&entry("Device", "cpu"));
+            }
+            logger_train.summary(epoch, &summary("Loss", epoch as f64));
+            logger_valid.epoch(epoch);
+            logger_valid.iteration(1);
+            logger_valid.log_numeric(&entry("Loss", "-"), epoch as f64);
+            logger_valid.summary(epoch, &summary("Loss", epoch as f64));
+        }
+        // Loggers write their files asynchronously.
+        core::mem::drop(logger_train);
+        core::mem::drop(logger_valid);
+
+        let history = read_metrics(directory).unwrap();
+
+        assert_eq!(history.splits(), vec!["train", "valid"]);
+        assert_eq!(history.metrics("train"), vec!["Device", "Loss"]);
+        let loss = history.get("train", "Loss").unwrap();
+        assert_eq!(loss.len(), 4);
+        assert_eq!((loss[3].epoch, loss[3].iteration), (2, 2));
+        assert_eq!(loss[3].value, Some(22.0));
+        assert_eq!(history.get("train", "Device").unwrap()[0].value, None);
+        assert_eq!(
+            history.epoch_means("train", "Loss"),
+            vec![(1, 11.5), (2, 21.5)]
+        );
+        assert_eq!(
+            history.epoch_means("valid", "Loss"),
+            vec![(1, 1.0), (2, 2.0)]
+        );
+        let summary = history.summary("train", "Loss").unwrap();
+        assert_eq!((summary[1].epoch, summary[1].iteration), (2, 2));
+        assert_eq!(
+            history.epoch_values("train", "Loss"),
+            vec![(1, 1.0), (2, 2.0)]
+        );
+    }
+
+    fn entry(name: &str, value: &str) -> MetricEntry {
+        MetricEntry::new(name.to_string(), value.to_string(), value.to_string())
+    }
+
+    fn summary(name: &str, value: f64) -> EvaluationSummary {
+        EvaluationSummary::new(vec![MetricSummary::new(
+            name.to_string(),
+            Some(value),
+            value.to_string(),
+        )])
+    }
+}
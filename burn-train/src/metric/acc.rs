@@ -80,6 +80,10 @@ impl<B: Backend> Numeric for AccuracyMetric<B> {
     fn value(&self) -> f64 {
         self.state.value()
     }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
 }
 
 #[cfg(test)]
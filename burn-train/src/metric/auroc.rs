@@ -0,0 +1,175 @@
+use super::state::FormatOptions;
+use super::{Metric, MetricEntry, MetricMetadata, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The area under the receiver operating characteristic curve for binary classification.
+///
+/// The outputs can either have one column, the score of the positive class, or two columns,
+/// the scores of the negative and positive classes. Only the ranking of the scores matters, so
+/// logits and probabilities give the same value.
+///
+/// # Notes
+///
+/// The [numeric value](Numeric::value) is the AUROC of the last batch, while the
+/// [running value](Numeric::running_value) is computed from all the items of the epoch so far,
+/// not averaged over the batches. Since it requires sorting all the items, the running value is
+/// only computed when requested, e.g. at the end of the epoch, and isn't displayed.
+#[derive(Default)]
+pub struct AurocMetric<B: Backend> {
+    items: Vec<(f64, bool)>,
+    current: f64,
+    pad_token: Option<usize>,
+    _b: B,
+}
+
+/// The [AUROC metric](AurocMetric) input type.
+#[derive(new)]
+pub struct AurocInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> AurocMetric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self {
+            current: f64::NAN,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for AurocMetric<B> {
+    type Input = AurocInput<B>;
+
+    fn update(&mut self, input: &AurocInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, n_classes] = input.outputs.dims();
+
+        let scores = match n_classes {
+            1 => input.outputs.clone(),
+            2 => {
+                let outputs = input.outputs.clone();
+                let negative = outputs.clone().index([0..batch_size, 0..1]);
+                let positive = outputs.index([0..batch_size, 1..2]);
+                positive - negative
+            }
+            _ => panic!("AUROC only supports binary classification, got {n_classes} classes"),
+        };
+        let scores = scores.into_data().convert::<f64>().value;
+        let targets = input.targets.to_data().convert::<i64>().value;
+
+        let mut items: Vec<(f64, bool)> = scores
+            .into_iter()
+            .zip(targets)
+            .filter(|(_, target)| Some(*target as usize) != self.pad_token)
+            .map(|(score, target)| (score, target == 1))
+            .collect();
+
+        self.current = auroc(&mut items);
+        self.items.extend(items);
+
+        let formatted = FormatOptions::new("AUROC")
+            .precision(4)
+            .format_current(self.current);
+
+        MetricEntry::new("AUROC".to_string(), formatted, self.current.to_string())
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for AurocMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+
+    fn running_value(&self) -> f64 {
+        auroc(&mut self.items.clone())
+    }
+}
+
+/// Compute the AUROC with the Mann-Whitney U statistic, giving tied scores their average rank.
+///
+/// Returns NaN when there is no positive or no negative item.
+fn auroc(items: &mut [(f64, bool)]) -> f64 {
+    items.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let num_positives = items.iter().filter(|(_, positive)| *positive).count();
+    let num_negatives = items.len() - num_positives;
+
+    if num_positives == 0 || num_negatives == 0 {
+        return f64::NAN;
+    }
+
+    let mut sum_ranks_positives = 0.0;
+    let mut start = 0;
+
+    while start < items.len() {
+        let mut end = start + 1;
+        while end < items.len() && items[end].0 == items[start].0 {
+            end += 1;
+        }
+
+        // Ranks start at 1, so the tied items from `start` to `end` share the rank below.
+        let rank = (start + end + 1) as f64 / 2.0;
+        let num_tied_positives = items[start..end]
+            .iter()
+            .filter(|(_, positive)| *positive)
+            .count();
+        sum_ranks_positives += rank * num_tied_positives as f64;
+
+        start = end;
+    }
+
+    let num_positives = num_positives as f64;
+    let u = sum_ranks_positives - num_positives * (num_positives + 1.0) / 2.0;
+
+    u / (num_positives * num_negatives as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_auroc_over_batches() {
+        let mut metric = AurocMetric::<TestBackend>::new().with_pad_token(2);
+        let input_1 = AurocInput::new(
+            Tensor::from_data([[0.0, 0.1], [0.0, 0.4], [0.0, 0.9]]),
+            Tensor::from_data([0, 1, 2]),
+        );
+        let input_2 = AurocInput::new(
+            Tensor::from_data([[0.0, 0.35], [0.0, 0.8], [0.0, 0.4]]),
+            Tensor::from_data([1, 1, 0]),
+        );
+
+        let _entry = metric.update(&input_1, &MetricMetadata::fake());
+        assert_eq!(metric.value(), 1.0);
+
+        let _entry = metric.update(&input_2, &MetricMetadata::fake());
+        // Positives 0.35, 0.8 against negative 0.4.
+        assert_eq!(metric.value(), 0.5);
+        // Positives 0.4, 0.35, 0.8 against negatives 0.1, 0.4: 4 pairs won and one tie.
+        assert!((metric.running_value() - 4.5 / 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_auroc_undefined_with_single_class() {
+        let mut metric = AurocMetric::<TestBackend>::new();
+        let input = AurocInput::new(Tensor::from_data([[0.2], [0.7]]), Tensor::from_data([1, 1]));
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!(metric.value().is_nan());
+        assert!(metric.running_value().is_nan());
+    }
+}
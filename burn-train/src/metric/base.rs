@@ -55,6 +55,14 @@ pub trait Adaptor<T> {
 /// This is usefull to plot the values of a metric during training.
 pub trait Numeric {
     fn value(&self) -> f64;
+
+    /// The value aggregated over all the items of the epoch so far.
+    ///
+    /// Defaults to the [current value](Numeric::value), for metrics whose value is already
+    /// aggregated over the epoch.
+    fn running_value(&self) -> f64 {
+        self.value()
+    }
 }
 
 /// Data type that contains the current state of a metric at a given time.
@@ -0,0 +1,190 @@
+use super::{Metric, MetricEntry, MetricMetadata};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The [confusion matrix metric](ConfusionMatrixMetric) input type.
+///
+/// The same input is used by the [precision, recall and F1 score](super::ClassScoreMetric)
+/// metrics.
+#[derive(new)]
+pub struct ConfusionMatrixInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+/// Number of items for each pair of target and predicted classes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfusionMatrix {
+    counts: Vec<Vec<usize>>,
+}
+
+impl ConfusionMatrix {
+    /// The number of classes.
+    pub fn num_classes(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The number of items of the `target` class predicted as the `prediction` class.
+    pub fn get(&self, target: usize, prediction: usize) -> usize {
+        self.counts
+            .get(target)
+            .and_then(|row| row.get(prediction))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The number of items of each target class, one row per class.
+    pub fn rows(&self) -> &[Vec<usize>] {
+        &self.counts
+    }
+
+    /// Add the predictions of a batch, ignoring the items whose target is the padding token.
+    pub(crate) fn update<B: Backend>(
+        &mut self,
+        input: &ConfusionMatrixInput<B>,
+        pad_token: Option<usize>,
+    ) {
+        let [batch_size, num_classes] = input.outputs.dims();
+
+        let predictions = input
+            .outputs
+            .clone()
+            .argmax(1)
+            .reshape([batch_size])
+            .into_data()
+            .convert::<i64>();
+        let targets = input.targets.to_data().convert::<i64>();
+
+        self.resize(num_classes);
+
+        for (target, prediction) in targets.value.iter().zip(predictions.value.iter()) {
+            let (target, prediction) = (*target as usize, *prediction as usize);
+
+            if Some(target) == pad_token {
+                continue;
+            }
+
+            self.resize(usize::max(target, prediction) + 1);
+            self.counts[target][prediction] += 1;
+        }
+    }
+
+    fn resize(&mut self, num_classes: usize) {
+        if num_classes <= self.counts.len() {
+            return;
+        }
+
+        for row in self.counts.iter_mut() {
+            row.resize(num_classes, 0);
+        }
+        self.counts.resize(num_classes, vec![0; num_classes]);
+    }
+}
+
+impl core::fmt::Display for ConfusionMatrix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let rows: Vec<String> = self
+            .counts
+            .iter()
+            .map(|row| {
+                let row: Vec<String> = row.iter().map(|count| count.to_string()).collect();
+                format!("[{}]", row.join(", "))
+            })
+            .collect();
+
+        write!(f, "[{}]", rows.join(", "))
+    }
+}
+
+/// The confusion matrix metric, accumulated over the epoch.
+///
+/// Rows are the target classes and columns the predicted classes.
+#[derive(Default)]
+pub struct ConfusionMatrixMetric<B: Backend> {
+    matrix: ConfusionMatrix,
+    pad_token: Option<usize>,
+    _b: B,
+}
+
+impl<B: Backend> ConfusionMatrixMetric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+
+    /// The confusion matrix of the current epoch.
+    pub fn matrix(&self) -> &ConfusionMatrix {
+        &self.matrix
+    }
+}
+
+impl<B: Backend> Metric for ConfusionMatrixMetric<B> {
+    type Input = ConfusionMatrixInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ConfusionMatrixInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        self.matrix.update(input, self.pad_token);
+
+        let matrix = self.matrix.to_string();
+        MetricEntry::new(
+            "Confusion Matrix".to_string(),
+            format!("epoch {matrix}"),
+            matrix,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.matrix = ConfusionMatrix::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_accumulate_confusion_matrix_over_batches() {
+        let mut metric = ConfusionMatrixMetric::<TestBackend>::new().with_pad_token(3);
+        let input_1 = ConfusionMatrixInput::new(
+            Tensor::from_data([
+                [0.0, 0.2, 0.8, 0.0], // 2
+                [1.0, 2.0, 0.5, 0.0], // 1
+                [0.4, 0.1, 0.2, 0.0], // 0
+            ]),
+            Tensor::from_data([2, 2, 1]),
+        );
+        let input_2 = ConfusionMatrixInput::new(
+            Tensor::from_data([
+                [0.6, 0.7, 0.2, 0.0], // 1
+                [0.0, 0.1, 0.2, 5.0], // Padding should not count
+            ]),
+            Tensor::from_data([1, 3]),
+        );
+
+        metric.update(&input_1, &MetricMetadata::fake());
+        let entry = metric.update(&input_2, &MetricMetadata::fake());
+
+        assert_eq!(
+            metric.matrix().rows(),
+            &[
+                vec![0, 0, 0, 0],
+                vec![1, 1, 0, 0],
+                vec![0, 1, 1, 0],
+                vec![0, 0, 0, 0],
+            ]
+        );
+        assert_eq!(
+            entry.serialize,
+            "[[0, 0, 0, 0], [1, 1, 0, 0], [0, 1, 1, 0], [0, 0, 0, 0]]"
+        );
+    }
+}
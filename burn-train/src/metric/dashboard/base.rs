@@ -1,7 +1,7 @@
 use crate::{
     logger::MetricLogger,
     metric::{Adaptor, Metric, MetricEntry, MetricMetadata, Numeric},
-    LearnerCallback, LearnerItem,
+    EvaluationSummary, LearnerCallback, LearnerItem, MetricSummary,
 };
 use burn_core::data::dataloader::Progress;
 
@@ -47,8 +47,8 @@ where
     metrics_valid: Vec<Box<dyn DashboardMetric<V>>>,
     metrics_train_numeric: Vec<Box<dyn DashboardNumericMetric<T>>>,
     metrics_valid_numeric: Vec<Box<dyn DashboardNumericMetric<V>>>,
-    logger_train: Box<dyn MetricLogger>,
-    logger_valid: Box<dyn MetricLogger>,
+    loggers_train: Vec<Box<dyn MetricLogger>>,
+    loggers_valid: Vec<Box<dyn MetricLogger>>,
     renderer: Box<dyn DashboardRenderer>,
 }
 
@@ -67,12 +67,61 @@ where
             metrics_valid: Vec::new(),
             metrics_train_numeric: Vec::new(),
             metrics_valid_numeric: Vec::new(),
-            logger_train,
-            logger_valid,
+            loggers_train: vec![logger_train],
+            loggers_valid: vec![logger_valid],
             renderer,
         }
     }
 
+    /// Register additional loggers for the training and validation metrics.
+    pub fn register_loggers(
+        &mut self,
+        logger_train: Box<dyn MetricLogger>,
+        logger_valid: Box<dyn MetricLogger>,
+    ) {
+        self.loggers_train.push(logger_train);
+        self.loggers_valid.push(logger_valid);
+    }
+
+    /// Register an additional logger for the validation metrics.
+    pub fn register_logger_valid(&mut self, logger: Box<dyn MetricLogger>) {
+        self.loggers_valid.push(logger);
+    }
+
+    /// The values of the validation metrics aggregated over the current epoch.
+    pub(crate) fn summary_valid(&self) -> EvaluationSummary {
+        let metrics = self
+            .metrics_valid
+            .iter()
+            .map(|metric| metric.summary())
+            .chain(
+                self.metrics_valid_numeric
+                    .iter()
+                    .map(|metric| metric.summary()),
+            )
+            .flatten()
+            .collect();
+
+        EvaluationSummary::new(metrics)
+    }
+
+    /// The values of the training metrics aggregated over the current epoch.
+    fn summary_train(&self) -> EvaluationSummary {
+        let metrics = self
+            .metrics_train
+            .iter()
+            .map(|metric| metric.summary())
+            .chain(
+                self.metrics_train_numeric
+                    .iter()
+                    .map(|metric| metric.summary()),
+            )
+            .flatten()
+            .collect();
+
+        EvaluationSummary::new(metrics)
+    }
+
     pub fn register_train<M: Metric + 'static>(&mut self, metric: M)
     where
         T: Adaptor<M::Input>,
@@ -133,18 +182,38 @@ where
     T: Send + Sync + 'static,
     V: Send + Sync + 'static,
 {
+    fn on_train_start_epoch(&mut self, epoch: usize) {
+        // The first epoch isn't 1 when the training is resumed from a checkpoint.
+        for logger in self.loggers_train.iter_mut() {
+            logger.epoch(epoch);
+        }
+    }
+
+    fn on_valid_start_epoch(&mut self, epoch: usize) {
+        for logger in self.loggers_valid.iter_mut() {
+            logger.epoch(epoch);
+        }
+    }
+
     fn on_train_item(&mut self, item: LearnerItem<T>) {
         let metadata = (&item).into();
+        for logger in self.loggers_train.iter_mut() {
+            logger.iteration(item.iteration);
+        }
         for metric in self.metrics_train.iter_mut() {
             let state = metric.update(&item, &metadata);
-            self.logger_train.log(&state);
+            for logger in self.loggers_train.iter_mut() {
+                logger.log(&state);
+            }
 
             self.renderer
                 .update_train(DashboardMetricState::Generic(state));
         }
         for metric in self.metrics_train_numeric.iter_mut() {
             let (state, value) = metric.update(&item, &metadata);
-            self.logger_train.log(&state);
+            for logger in self.loggers_train.iter_mut() {
+                logger.log_numeric(&state, value);
+            }
 
             self.renderer
                 .update_train(DashboardMetricState::Numeric(state, value));
@@ -154,16 +223,23 @@ where
 
     fn on_valid_item(&mut self, item: LearnerItem<V>) {
         let metadata = (&item).into();
+        for logger in self.loggers_valid.iter_mut() {
+            logger.iteration(item.iteration);
+        }
         for metric in self.metrics_valid.iter_mut() {
             let state = metric.update(&item, &metadata);
-            self.logger_valid.log(&state);
+            for logger in self.loggers_valid.iter_mut() {
+                logger.log(&state);
+            }
 
             self.renderer
                 .update_valid(DashboardMetricState::Generic(state));
         }
         for metric in self.metrics_valid_numeric.iter_mut() {
             let (state, value) = metric.update(&item, &metadata);
-            self.logger_valid.log(&state);
+            for logger in self.loggers_valid.iter_mut() {
+                logger.log_numeric(&state, value);
+            }
 
             self.renderer
                 .update_valid(DashboardMetricState::Numeric(state, value));
@@ -172,39 +248,56 @@ where
     }
 
     fn on_train_end_epoch(&mut self, epoch: usize) {
+        let summary = self.summary_train();
+        for logger in self.loggers_train.iter_mut() {
+            logger.summary(epoch, &summary);
+        }
         for metric in self.metrics_train.iter_mut() {
             metric.clear();
         }
         for metric in self.metrics_train_numeric.iter_mut() {
             metric.clear();
         }
-        self.logger_train.epoch(epoch + 1);
+        for logger in self.loggers_train.iter_mut() {
+            logger.epoch(epoch + 1);
+        }
     }
 
     fn on_valid_end_epoch(&mut self, epoch: usize) {
+        let summary = self.summary_valid();
+        for logger in self.loggers_valid.iter_mut() {
+            logger.summary(epoch, &summary);
+        }
         for metric in self.metrics_valid.iter_mut() {
             metric.clear();
         }
         for metric in self.metrics_valid_numeric.iter_mut() {
             metric.clear();
         }
-        self.logger_valid.epoch(epoch + 1);
+        for logger in self.loggers_valid.iter_mut() {
+            logger.epoch(epoch + 1);
+        }
     }
 }
 
 trait DashboardNumericMetric<T>: Send + Sync {
     fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> (MetricEntry, f64);
+    fn summary(&self) -> Option<MetricSummary>;
     fn clear(&mut self);
 }
 
 trait DashboardMetric<T>: Send + Sync {
     fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> MetricEntry;
+    fn summary(&self) -> Option<MetricSummary>;
     fn clear(&mut self);
 }
 
 #[derive(new)]
 struct MetricWrapper<M> {
     metric: M,
+    /// The name and the formatted value of the last entry.
+    #[new(default)]
+    last: Option<(String, String)>,
 }
 
 impl<T, M> DashboardNumericMetric<T> for MetricWrapper<M>
@@ -216,12 +309,21 @@ where
     fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> (MetricEntry, f64) {
         let update = self.metric.update(&item.item.adapt(), metadata);
         let numeric = self.metric.value();
+        self.last = Some((update.name.clone(), update.formatted.clone()));
 
         (update, numeric)
     }
 
+    fn summary(&self) -> Option<MetricSummary> {
+        let (name, formatted) = self.last.clone()?;
+        let value = Some(self.metric.running_value());
+
+        Some(MetricSummary::new(name, value, formatted))
+    }
+
     fn clear(&mut self) {
-        self.metric.clear()
+        self.metric.clear();
+        self.last = None;
     }
 }
 
@@ -232,10 +334,20 @@ where
     T: Adaptor<M::Input>,
 {
     fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> MetricEntry {
-        self.metric.update(&item.item.adapt(), metadata)
+        let update = self.metric.update(&item.item.adapt(), metadata);
+        self.last = Some((update.name.clone(), update.formatted.clone()));
+
+        update
+    }
+
+    fn summary(&self) -> Option<MetricSummary> {
+        let (name, formatted) = self.last.clone()?;
+
+        Some(MetricSummary::new(name, None, formatted))
     }
 
     fn clear(&mut self) {
-        self.metric.clear()
+        self.metric.clear();
+        self.last = None;
     }
 }
@@ -42,4 +42,8 @@ impl Numeric for LearningRateMetric {
     fn value(&self) -> f64 {
         self.state.value()
     }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
 }
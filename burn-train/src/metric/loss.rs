@@ -20,6 +20,13 @@ pub struct LossInput<B: Backend> {
     tensor: Tensor<B, 1>,
 }
 
+impl<B: Backend> LossInput<B> {
+    /// The mean of the loss tensor.
+    pub(crate) fn mean(&self) -> f64 {
+        f64::from_elem(self.tensor.clone().mean().into_data().value[0])
+    }
+}
+
 impl<B: Backend> LossMetric<B> {
     /// Create the metric.
     pub fn new() -> Self {
@@ -31,10 +38,8 @@ impl<B: Backend> Metric for LossMetric<B> {
     type Input = LossInput<B>;
 
     fn update(&mut self, loss: &Self::Input, _metadata: &MetricMetadata) -> MetricEntry {
-        let loss = f64::from_elem(loss.tensor.clone().mean().into_data().value[0]);
-
         self.state
-            .update(loss, 1, FormatOptions::new("Loss").precision(2))
+            .update(loss.mean(), 1, FormatOptions::new("Loss").precision(2))
     }
 
     fn clear(&mut self) {
@@ -46,4 +51,8 @@ impl<B: Backend> Numeric for LossMetric<B> {
     fn value(&self) -> f64 {
         self.state.value()
     }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
 }
@@ -0,0 +1,49 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+use burn_core::optim::LossScale;
+
+/// Track the loss scale of a [mixed precision](burn_core::optim::MixedPrecision) optimizer.
+///
+/// The loss scale is reduced each time a step is skipped because of non-finite gradients.
+pub struct LossScaleMetric {
+    loss_scale: LossScale,
+    state: NumericMetricState,
+}
+
+impl LossScaleMetric {
+    /// Create the metric from the [loss scale](LossScale) handle of the optimizer.
+    pub fn new(loss_scale: LossScale) -> Self {
+        Self {
+            loss_scale,
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Metric for LossScaleMetric {
+    type Input = ();
+
+    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> MetricEntry {
+        let scale = self.loss_scale.value() as f64;
+
+        self.state
+            .update(scale, 1, FormatOptions::new("Loss Scale").precision(0))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for LossScaleMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
+}
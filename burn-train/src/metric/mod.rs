@@ -2,13 +2,27 @@ pub mod dashboard;
 pub mod state;
 
 mod acc;
+mod auroc;
 mod base;
+mod confusion_matrix;
 mod cuda;
 mod learning_rate;
 mod loss;
+mod loss_scale;
+mod multi_label;
+mod precision_recall;
+mod regression;
+mod top_k_acc;
 
 pub use acc::*;
+pub use auroc::*;
 pub use base::*;
+pub use confusion_matrix::*;
 pub use cuda::*;
 pub use learning_rate::*;
 pub use loss::*;
+pub use loss_scale::*;
+pub use multi_label::*;
+pub use precision_recall::*;
+pub use regression::*;
+pub use top_k_acc::*;
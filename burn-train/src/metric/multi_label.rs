@@ -0,0 +1,363 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{Metric, MetricEntry, MetricMetadata, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The multi-label classification metrics input type.
+///
+/// The outputs are the logits of each label and the targets are `1` for the labels that are
+/// present and `0` otherwise, both with the shape `[batch_size, num_labels]`.
+#[derive(new)]
+pub struct MultiLabelInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> MultiLabelInput<B> {
+    /// The predicted and target labels of each item, one row per item.
+    fn labels(&self, threshold: f64) -> Vec<Vec<(bool, bool)>> {
+        let [_batch_size, num_labels] = self.outputs.dims();
+        // A probability above the threshold is a logit above the logit of the threshold.
+        let threshold = (threshold / (1.0 - threshold)).ln();
+
+        let outputs = self.outputs.to_data().convert::<f64>().value;
+        let targets = self.targets.to_data().convert::<i64>().value;
+
+        outputs
+            .chunks(num_labels)
+            .zip(targets.chunks(num_labels))
+            .map(|(outputs, targets)| {
+                outputs
+                    .iter()
+                    .zip(targets.iter())
+                    .map(|(output, target)| (*output > threshold, *target != 0))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+const DEFAULT_THRESHOLD: f64 = 0.5;
+
+/// The hamming accuracy metric, the proportion of labels correctly predicted.
+pub struct HammingAccuracyMetric<B: Backend> {
+    state: NumericMetricState,
+    threshold: f64,
+    _b: B,
+}
+
+impl<B: Backend> HammingAccuracyMetric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The probability above which a label is predicted, `0.5` by default.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<B: Backend> Default for HammingAccuracyMetric<B> {
+    fn default() -> Self {
+        Self {
+            state: NumericMetricState::default(),
+            threshold: DEFAULT_THRESHOLD,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for HammingAccuracyMetric<B> {
+    type Input = MultiLabelInput<B>;
+
+    fn update(&mut self, input: &MultiLabelInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let labels: Vec<(bool, bool)> = input.labels(self.threshold).concat();
+        let num_matches = labels
+            .iter()
+            .filter(|(predicted, target)| predicted == target)
+            .count();
+
+        // Weighted by the number of labels so the epoch value is exact.
+        self.state.update(
+            100.0 * num_matches as f64 / labels.len() as f64,
+            labels.len(),
+            FormatOptions::new("Hamming Accuracy")
+                .unit("%")
+                .precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for HammingAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
+}
+
+/// The subset accuracy metric, the proportion of items whose labels are all correctly
+/// predicted.
+pub struct SubsetAccuracyMetric<B: Backend> {
+    state: NumericMetricState,
+    threshold: f64,
+    _b: B,
+}
+
+impl<B: Backend> SubsetAccuracyMetric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The probability above which a label is predicted, `0.5` by default.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<B: Backend> Default for SubsetAccuracyMetric<B> {
+    fn default() -> Self {
+        Self {
+            state: NumericMetricState::default(),
+            threshold: DEFAULT_THRESHOLD,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for SubsetAccuracyMetric<B> {
+    type Input = MultiLabelInput<B>;
+
+    fn update(&mut self, input: &MultiLabelInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let items = input.labels(self.threshold);
+        let num_matches = items
+            .iter()
+            .filter(|labels| labels.iter().all(|(predicted, target)| predicted == target))
+            .count();
+
+        self.state.update(
+            100.0 * num_matches as f64 / items.len() as f64,
+            items.len(),
+            FormatOptions::new("Subset Accuracy").unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for SubsetAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
+}
+
+/// The F1 score of each label.
+///
+/// # Notes
+///
+/// The true positives, false positives and false negatives of each label are accumulated over
+/// the epoch. The [running value](Numeric::running_value) is the mean of the scores of the
+/// labels that are present in the targets or in the predictions of the epoch so far, and the
+/// [numeric value](Numeric::value) is the same mean for the last batch.
+pub struct PerLabelF1Metric<B: Backend> {
+    counts: Vec<LabelCounts>,
+    current: f64,
+    threshold: f64,
+    _b: B,
+}
+
+#[derive(Default, Clone, Copy)]
+struct LabelCounts {
+    true_positives: usize,
+    false_positives: usize,
+    false_negatives: usize,
+}
+
+impl LabelCounts {
+    fn update(&mut self, predicted: bool, target: bool) {
+        match (predicted, target) {
+            (true, true) => self.true_positives += 1,
+            (true, false) => self.false_positives += 1,
+            (false, true) => self.false_negatives += 1,
+            (false, false) => {}
+        }
+    }
+
+    /// The F1 score, NaN when the label is neither present nor predicted.
+    fn f1(&self) -> f64 {
+        let denominator = 2 * self.true_positives + self.false_positives + self.false_negatives;
+
+        match denominator {
+            0 => f64::NAN,
+            _ => 2.0 * self.true_positives as f64 / denominator as f64,
+        }
+    }
+}
+
+impl<B: Backend> PerLabelF1Metric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The probability above which a label is predicted, `0.5` by default.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// The F1 score of each label for the epoch so far, NaN for the labels that are neither
+    /// present nor predicted.
+    pub fn scores(&self) -> Vec<f64> {
+        self.counts.iter().map(|counts| counts.f1()).collect()
+    }
+}
+
+/// The mean of the F1 scores of the labels that are present or predicted.
+fn mean_f1(counts: &[LabelCounts]) -> f64 {
+    let scores: Vec<f64> = counts
+        .iter()
+        .map(|counts| counts.f1())
+        .filter(|score| !score.is_nan())
+        .collect();
+
+    100.0 * scores.iter().sum::<f64>() / scores.len() as f64
+}
+
+impl<B: Backend> Default for PerLabelF1Metric<B> {
+    fn default() -> Self {
+        Self {
+            counts: Vec::new(),
+            current: f64::NAN,
+            threshold: DEFAULT_THRESHOLD,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for PerLabelF1Metric<B> {
+    type Input = MultiLabelInput<B>;
+
+    fn update(&mut self, input: &MultiLabelInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let mut counts_batch = Vec::new();
+
+        for labels in input.labels(self.threshold) {
+            if self.counts.len() < labels.len() {
+                self.counts.resize(labels.len(), LabelCounts::default());
+            }
+            if counts_batch.len() < labels.len() {
+                counts_batch.resize(labels.len(), LabelCounts::default());
+            }
+
+            for (i, (predicted, target)) in labels.into_iter().enumerate() {
+                self.counts[i].update(predicted, target);
+                counts_batch[i].update(predicted, target);
+            }
+        }
+        self.current = mean_f1(&counts_batch);
+
+        let scores: Vec<String> = self
+            .scores()
+            .iter()
+            .map(|score| format!("{:.2}", 100.0 * score))
+            .collect();
+        let formatted = format!(
+            "epoch {:.2} % - batch {:.2} % - labels [{}] %",
+            self.running_value(),
+            self.current,
+            scores.join(", ")
+        );
+
+        MetricEntry::new(
+            "Per Label F1 Score".to_string(),
+            formatted,
+            self.current.to_string(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for PerLabelF1Metric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+
+    fn running_value(&self) -> f64 {
+        mean_f1(&self.counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_multi_label_metrics_over_batches() {
+        let mut hamming = HammingAccuracyMetric::<TestBackend>::new();
+        let mut subset = SubsetAccuracyMetric::<TestBackend>::new();
+        let mut f1 = PerLabelF1Metric::<TestBackend>::new();
+
+        let inputs = [
+            MultiLabelInput::new(
+                Tensor::from_data([[2.0, -1.0, -3.0], [1.0, 1.0, -2.0]]),
+                Tensor::from_data([[1, 0, 0], [1, 0, 0]]),
+            ),
+            MultiLabelInput::new(
+                Tensor::from_data([[-1.0, 3.0, -1.0], [0.5, -0.5, -1.0]]),
+                Tensor::from_data([[0, 1, 0], [0, 1, 0]]),
+            ),
+        ];
+
+        for input in inputs.iter() {
+            hamming.update(input, &MetricMetadata::fake());
+            subset.update(input, &MetricMetadata::fake());
+            f1.update(input, &MetricMetadata::fake());
+        }
+
+        // Second batch: the first item is correct, the second has two wrong labels.
+        assert_eq!(hamming.value(), 100.0 * 4.0 / 6.0);
+        assert_eq!(subset.value(), 50.0);
+        // Label 0: tp 2, fp 1, fn 0 - Label 1: tp 1, fp 1, fn 1 - Label 2: never present.
+        let scores = f1.scores();
+        assert_eq!(scores[0], 4.0 / 5.0);
+        assert_eq!(scores[1], 2.0 / 4.0);
+        assert!(scores[2].is_nan());
+        assert!((f1.running_value() - 100.0 * (0.8 + 0.5) / 2.0).abs() < 1e-9);
+        // Second batch: label 0: tp 0, fp 1, fn 0 - Label 1: tp 1, fp 0, fn 1.
+        assert!((f1.value() - 100.0 * (0.0 + 2.0 / 3.0) / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hamming_accuracy_with_threshold() {
+        let mut metric = HammingAccuracyMetric::<TestBackend>::new().with_threshold(0.9);
+        let input = MultiLabelInput::new(
+            Tensor::from_data([[1.0, 3.0], [-1.0, 2.5]]),
+            Tensor::from_data([[1, 1], [0, 1]]),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        // Only logits above ln(9) ~= 2.2 are predicted.
+        assert_eq!(metric.value(), 75.0);
+    }
+}
@@ -0,0 +1,265 @@
+use super::confusion_matrix::{ConfusionMatrix, ConfusionMatrixInput};
+use super::state::FormatOptions;
+use super::{Metric, MetricEntry, MetricMetadata, Numeric};
+use burn_core::tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// How the scores of each class are combined into a single value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassReduction {
+    /// Compute the score of each class, then take the unweighted mean.
+    ///
+    /// Only the classes present in the targets or in the predictions are considered.
+    Macro,
+    /// Sum the true positives, false positives and false negatives of all classes, then
+    /// compute the score.
+    Micro,
+}
+
+/// The score computed for each class by the [class score metric](ClassScoreMetric).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassScore {
+    /// The ratio of the predictions of a class that are correct.
+    Precision,
+    /// The ratio of the items of a class that are predicted as such.
+    Recall,
+    /// The harmonic mean of the precision and the recall.
+    F1,
+}
+
+impl ClassScore {
+    fn compute(
+        &self,
+        true_positives: usize,
+        false_positives: usize,
+        false_negatives: usize,
+    ) -> f64 {
+        let precision = ratio(true_positives, true_positives + false_positives);
+        let recall = ratio(true_positives, true_positives + false_negatives);
+
+        match self {
+            ClassScore::Precision => precision,
+            ClassScore::Recall => recall,
+            ClassScore::F1 => match precision + recall {
+                sum if sum > 0.0 => 2.0 * precision * recall / sum,
+                _ => 0.0,
+            },
+        }
+    }
+
+    fn name(&self, reduction: ClassReduction) -> String {
+        let name = match self {
+            ClassScore::Precision => "Precision",
+            ClassScore::Recall => "Recall",
+            ClassScore::F1 => "F1 Score",
+        };
+        let reduction = match reduction {
+            ClassReduction::Macro => "Macro",
+            ClassReduction::Micro => "Micro",
+        };
+
+        format!("{name} ({reduction})")
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    match denominator {
+        0 => 0.0,
+        _ => numerator as f64 / denominator as f64,
+    }
+}
+
+/// The precision, recall or F1 score metric.
+///
+/// # Notes
+///
+/// The [numeric value](Numeric::value) is the score of the last batch, while the
+/// [running value](Numeric::running_value) is computed from the confusion matrix accumulated
+/// over the epoch, not averaged over the batches.
+pub struct ClassScoreMetric<B: Backend> {
+    score: ClassScore,
+    reduction: ClassReduction,
+    pad_token: Option<usize>,
+    matrix: ConfusionMatrix,
+    current: f64,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> ClassScoreMetric<B> {
+    /// Create the metric computing the given score with the given
+    /// [class reduction](ClassReduction).
+    pub fn new(score: ClassScore, reduction: ClassReduction) -> Self {
+        Self {
+            score,
+            reduction,
+            pad_token: None,
+            matrix: ConfusionMatrix::default(),
+            current: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+
+    fn compute(&self, matrix: &ConfusionMatrix) -> f64 {
+        let num_classes = matrix.num_classes();
+        let mut counts = Vec::with_capacity(num_classes);
+
+        for class in 0..num_classes {
+            if Some(class) == self.pad_token {
+                continue;
+            }
+
+            let true_positives = matrix.get(class, class);
+            let false_positives = (0..num_classes)
+                .filter(|target| *target != class)
+                .map(|target| matrix.get(target, class))
+                .sum::<usize>();
+            let false_negatives = (0..num_classes)
+                .filter(|prediction| *prediction != class)
+                .map(|prediction| matrix.get(class, prediction))
+                .sum::<usize>();
+
+            if true_positives + false_positives + false_negatives > 0 {
+                counts.push((true_positives, false_positives, false_negatives));
+            }
+        }
+
+        if counts.is_empty() {
+            return f64::NAN;
+        }
+
+        let score = match self.reduction {
+            ClassReduction::Macro => {
+                let sum = counts
+                    .iter()
+                    .map(|(tp, fp, fn_)| self.score.compute(*tp, *fp, *fn_))
+                    .sum::<f64>();
+
+                sum / counts.len() as f64
+            }
+            ClassReduction::Micro => {
+                let (tp, fp, fn_) = counts.iter().fold((0, 0, 0), |acc, (tp, fp, fn_)| {
+                    (acc.0 + tp, acc.1 + fp, acc.2 + fn_)
+                });
+
+                self.score.compute(tp, fp, fn_)
+            }
+        };
+
+        100.0 * score
+    }
+}
+
+impl<B: Backend> Metric for ClassScoreMetric<B> {
+    type Input = ConfusionMatrixInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ConfusionMatrixInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let mut matrix_batch = ConfusionMatrix::default();
+        matrix_batch.update(input, self.pad_token);
+        self.matrix.update(input, self.pad_token);
+
+        self.current = self.compute(&matrix_batch);
+
+        let name = self.score.name(self.reduction);
+        let format = FormatOptions::new(&name).unit("%").precision(2);
+        let formatted = format.format(self.current, self.running_value());
+
+        MetricEntry::new(name, formatted, self.current.to_string())
+    }
+
+    fn clear(&mut self) {
+        self.matrix = ConfusionMatrix::default();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for ClassScoreMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+
+    fn running_value(&self) -> f64 {
+        self.compute(&self.matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_macro_scores_over_batches() {
+        let (precision, recall, f1) = run_metrics(ClassReduction::Macro);
+
+        // Class 0: tp 0, fp 1, fn 0 - Class 1: tp 1, fp 1, fn 1 - Class 2: tp 1, fp 0, fn 2.
+        assert_approx_eq(precision, 100.0 * (0.0 + 0.5 + 1.0) / 3.0);
+        assert_approx_eq(recall, 100.0 * (0.0 + 0.5 + 1.0 / 3.0) / 3.0);
+        assert_approx_eq(f1, 100.0 * (0.0 + 0.5 + 0.5) / 3.0);
+    }
+
+    #[test]
+    fn test_micro_scores_over_batches() {
+        let (precision, recall, f1) = run_metrics(ClassReduction::Micro);
+
+        // Predicted padding is a false negative without being a false positive.
+        assert_approx_eq(precision, 100.0 * 2.0 / 4.0);
+        assert_approx_eq(recall, 100.0 * 2.0 / 5.0);
+        assert_approx_eq(f1, 100.0 * 2.0 * 0.5 * 0.4 / 0.9);
+    }
+
+    fn run_metrics(reduction: ClassReduction) -> (f64, f64, f64) {
+        let metric =
+            |score| ClassScoreMetric::<TestBackend>::new(score, reduction).with_pad_token(3);
+        let mut precision = metric(ClassScore::Precision);
+        let mut recall = metric(ClassScore::Recall);
+        let mut f1 = metric(ClassScore::F1);
+
+        let inputs = [
+            ConfusionMatrixInput::new(
+                Tensor::from_data([
+                    [0.0, 0.2, 0.8, 0.0], // 2
+                    [1.0, 2.0, 0.5, 0.0], // 1
+                    [0.4, 0.1, 0.2, 0.0], // 0
+                ]),
+                Tensor::from_data([2, 2, 1]),
+            ),
+            ConfusionMatrixInput::new(
+                Tensor::from_data([
+                    [0.6, 0.7, 0.2, 0.0], // 1
+                    [0.0, 0.1, 0.2, 5.0], // Padding should not count
+                    [0.0, 0.1, 0.2, 5.0], // Predicted padding
+                ]),
+                Tensor::from_data([1, 3, 2]),
+            ),
+        ];
+
+        for input in inputs.iter() {
+            precision.update(input, &MetricMetadata::fake());
+            recall.update(input, &MetricMetadata::fake());
+            f1.update(input, &MetricMetadata::fake());
+        }
+
+        (
+            precision.running_value(),
+            recall.running_value(),
+            f1.running_value(),
+        )
+    }
+
+    fn assert_approx_eq(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-6,
+            "Expected {expected}, got {value}"
+        );
+    }
+}
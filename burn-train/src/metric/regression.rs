@@ -0,0 +1,307 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{Metric, MetricEntry, MetricMetadata, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::Tensor;
+
+/// The regression metrics input type.
+///
+/// The outputs and the targets have the shape `[batch_size, num_outputs]`.
+#[derive(new)]
+pub struct RegressionInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+impl<B: Backend> RegressionInput<B> {
+    /// The outputs and the targets in row-major order, with the number of outputs.
+    fn values(&self) -> (Vec<f64>, Vec<f64>, usize) {
+        let [_batch_size, num_outputs] = self.outputs.dims();
+        let outputs = self.outputs.to_data().convert::<f64>().value;
+        let targets = self.targets.to_data().convert::<f64>().value;
+
+        (outputs, targets, num_outputs)
+    }
+}
+
+/// The mean absolute error metric.
+#[derive(Default)]
+pub struct MaeMetric<B: Backend> {
+    state: NumericMetricState,
+    _b: B,
+}
+
+impl<B: Backend> MaeMetric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for MaeMetric<B> {
+    type Input = RegressionInput<B>;
+
+    fn update(&mut self, input: &RegressionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let (outputs, targets, _) = input.values();
+        let num_values = outputs.len();
+        let sum = outputs
+            .iter()
+            .zip(targets.iter())
+            .map(|(output, target)| (output - target).abs())
+            .sum::<f64>();
+
+        // Weighted by the number of values so the epoch value is exact.
+        self.state.update(
+            sum / num_values as f64,
+            num_values,
+            FormatOptions::new("MAE").precision(4),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for MaeMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
+}
+
+/// The root mean squared error metric.
+///
+/// # Notes
+///
+/// The [numeric value](Numeric::value) is the value of the last batch. The squared errors are
+/// accumulated over the epoch before taking the root, so the
+/// [running value](Numeric::running_value) is not the mean of the batch values.
+#[derive(Default)]
+pub struct RmseMetric<B: Backend> {
+    sum_squared_errors: f64,
+    num_values: usize,
+    current: f64,
+    _b: B,
+}
+
+impl<B: Backend> RmseMetric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self {
+            current: f64::NAN,
+            ..Self::default()
+        }
+    }
+}
+
+impl<B: Backend> Metric for RmseMetric<B> {
+    type Input = RegressionInput<B>;
+
+    fn update(&mut self, input: &RegressionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let (outputs, targets, _) = input.values();
+        let sum_squared_errors = outputs
+            .iter()
+            .zip(targets.iter())
+            .map(|(output, target)| (output - target).powi(2))
+            .sum::<f64>();
+
+        self.sum_squared_errors += sum_squared_errors;
+        self.num_values += outputs.len();
+
+        self.current = (sum_squared_errors / outputs.len() as f64).sqrt();
+        let formatted = FormatOptions::new("RMSE")
+            .precision(4)
+            .format(self.current, self.running_value());
+
+        MetricEntry::new("RMSE".to_string(), formatted, self.current.to_string())
+    }
+
+    fn clear(&mut self) {
+        self.sum_squared_errors = 0.0;
+        self.num_values = 0;
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for RmseMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+
+    fn running_value(&self) -> f64 {
+        (self.sum_squared_errors / self.num_values as f64).sqrt()
+    }
+}
+
+/// The coefficient of determination (R²) metric.
+///
+/// With multiple outputs, the R² of each output is computed and the mean is returned.
+///
+/// # Notes
+///
+/// When the targets of an output are constant, its R² is 1 if the predictions are exact and 0
+/// otherwise.
+///
+/// The [numeric value](Numeric::value) is the value of the last batch. The sums required to
+/// compute the R² are accumulated over the epoch, so the [running value](Numeric::running_value)
+/// is not the mean of the batch values.
+#[derive(Default)]
+pub struct R2Metric<B: Backend> {
+    sums: Vec<R2Sums>,
+    current: f64,
+    _b: B,
+}
+
+/// Sums from which the R² of one output is computed.
+///
+/// The squared deviations of the targets are accumulated around their running mean (Welford's
+/// algorithm), which doesn't lose precision when the targets are large compared to their
+/// variance.
+#[derive(Default, Clone, Copy)]
+struct R2Sums {
+    mean_targets: f64,
+    total_sum_squares: f64,
+    squared_errors: f64,
+    count: usize,
+}
+
+impl R2Sums {
+    fn update(&mut self, output: f64, target: f64) {
+        self.count += 1;
+        let delta = target - self.mean_targets;
+        self.mean_targets += delta / self.count as f64;
+        self.total_sum_squares += delta * (target - self.mean_targets);
+        self.squared_errors += (output - target).powi(2);
+    }
+
+    fn value(&self) -> f64 {
+        match (self.count, self.total_sum_squares > 0.0) {
+            (0, _) => f64::NAN,
+            (_, true) => 1.0 - self.squared_errors / self.total_sum_squares,
+            (_, false) if self.squared_errors == 0.0 => 1.0,
+            (_, false) => 0.0,
+        }
+    }
+}
+
+impl<B: Backend> R2Metric<B> {
+    /// Create the metric.
+    pub fn new() -> Self {
+        Self {
+            current: f64::NAN,
+            ..Self::default()
+        }
+    }
+}
+
+fn mean_r2(sums: &[R2Sums]) -> f64 {
+    sums.iter().map(|sums| sums.value()).sum::<f64>() / sums.len() as f64
+}
+
+impl<B: Backend> Metric for R2Metric<B> {
+    type Input = RegressionInput<B>;
+
+    fn update(&mut self, input: &RegressionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let (outputs, targets, num_outputs) = input.values();
+        let mut sums_batch = vec![R2Sums::default(); num_outputs];
+
+        if self.sums.is_empty() {
+            self.sums = vec![R2Sums::default(); num_outputs];
+        }
+
+        for (i, (output, target)) in outputs.iter().zip(targets.iter()).enumerate() {
+            let column = i % num_outputs;
+            sums_batch[column].update(*output, *target);
+            self.sums[column].update(*output, *target);
+        }
+
+        self.current = mean_r2(&sums_batch);
+        let formatted = FormatOptions::new("R²")
+            .precision(4)
+            .format(self.current, self.running_value());
+
+        MetricEntry::new("R²".to_string(), formatted, self.current.to_string())
+    }
+
+    fn clear(&mut self) {
+        self.sums.clear();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for R2Metric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+
+    fn running_value(&self) -> f64 {
+        mean_r2(&self.sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_regression_metrics_over_batches() {
+        let mut mae = MaeMetric::<TestBackend>::new();
+        let mut rmse = RmseMetric::<TestBackend>::new();
+        let mut r2 = R2Metric::<TestBackend>::new();
+
+        for input in inputs() {
+            mae.update(&input, &MetricMetadata::fake());
+            rmse.update(&input, &MetricMetadata::fake());
+            r2.update(&input, &MetricMetadata::fake());
+        }
+
+        // Errors of the first output: 0.5, 0, -1, 0.5 and of the second: 0, 1, 0, -2.
+        let mae_batch_2 = (1.0 + 0.5 + 0.0 + 2.0) / 4.0;
+        assert_approx_eq(mae.value(), mae_batch_2);
+        assert_approx_eq(rmse.value(), (5.25_f64 / 4.0).sqrt());
+        assert_approx_eq(rmse.running_value(), (6.5_f64 / 8.0).sqrt());
+        // Targets of the first output: 1, 2, 3, 4 and of the second: 2, 4, 6, 8.
+        let r2_first = 1.0 - 1.5 / 5.0;
+        let r2_second = 1.0 - 5.0 / 20.0;
+        assert_approx_eq(r2.running_value(), (r2_first + r2_second) / 2.0);
+    }
+
+    #[test]
+    fn test_r2_with_constant_targets() {
+        let mut r2 = R2Metric::<TestBackend>::new();
+        let input = RegressionInput::new(
+            Tensor::from_data([[5.0, 3.0], [5.0, 2.0]]),
+            Tensor::from_data([[5.0, 2.0], [5.0, 2.0]]),
+        );
+
+        r2.update(&input, &MetricMetadata::fake());
+
+        // Exact predictions of the first output, and wrong predictions of the second.
+        assert_eq!(r2.value(), 0.5);
+        assert_eq!(r2.running_value(), 0.5);
+    }
+
+    fn inputs() -> [RegressionInput<TestBackend>; 2] {
+        [
+            RegressionInput::new(
+                Tensor::from_data([[1.5, 2.0], [2.0, 5.0]]),
+                Tensor::from_data([[1.0, 2.0], [2.0, 4.0]]),
+            ),
+            RegressionInput::new(
+                Tensor::from_data([[2.0, 6.0], [4.5, 6.0]]),
+                Tensor::from_data([[3.0, 6.0], [4.0, 8.0]]),
+            ),
+        ]
+    }
+
+    fn assert_approx_eq(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-6,
+            "Expected {expected}, got {value}"
+        );
+    }
+}
@@ -40,6 +40,44 @@ impl FormatOptions {
         self.precision = Some(precision);
         self
     }
+
+    /// Format the current and running values of a metric.
+    pub(crate) fn format(&self, value_current: f64, value_running: f64) -> String {
+        let formatted_current = self.format_value(value_current);
+        let formatted_running = self.format_value(value_running);
+
+        match &self.unit {
+            Some(unit) => {
+                format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
+            }
+            None => format!("epoch {formatted_running} - batch {formatted_current}"),
+        }
+    }
+
+    /// Format the current value of a metric, for metrics whose running value is only computed
+    /// when needed.
+    pub(crate) fn format_current(&self, value_current: f64) -> String {
+        let formatted_current = self.format_value(value_current);
+
+        match &self.unit {
+            Some(unit) => format!("batch {formatted_current} {unit}"),
+            None => format!("batch {formatted_current}"),
+        }
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => {
+                let scientific_notation_threshold = 0.1_f64.powf(precision as f64 - 1.0);
+
+                match scientific_notation_threshold >= value {
+                    true => format!("{value:.precision$e}"),
+                    false => format!("{value:.precision$}"),
+                }
+            }
+            None => format!("{value}"),
+        }
+    }
 }
 
 impl NumericMetricState {
@@ -69,30 +107,7 @@ impl NumericMetricState {
         let value_running = self.sum / self.count as f64;
         let serialized = value_current.to_string();
 
-        let (formatted_current, formatted_running) = match format.precision {
-            Some(precision) => {
-                let scientific_notation_threshold = 0.1_f64.powf(precision as f64 - 1.0);
-
-                (
-                    match scientific_notation_threshold >= value_current {
-                        true => format!("{value_current:.precision$e}"),
-                        false => format!("{value_current:.precision$}"),
-                    },
-                    match scientific_notation_threshold >= value_running {
-                        true => format!("{value_running:.precision$e}"),
-                        false => format!("{value_running:.precision$}"),
-                    },
-                )
-            }
-            None => (format!("{value_current}"), format!("{value_running}")),
-        };
-
-        let formatted = match format.unit {
-            Some(unit) => {
-                format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
-            }
-            None => format!("epoch {formatted_running} - batch {formatted_current}"),
-        };
+        let formatted = format.format(value_current, value_running);
 
         MetricEntry::new(format.name, formatted, serialized)
     }
@@ -102,6 +117,10 @@ impl Numeric for NumericMetricState {
     fn value(&self) -> f64 {
         self.current
     }
+
+    fn running_value(&self) -> f64 {
+        self.sum / self.count as f64
+    }
 }
 
 impl Default for NumericMetricState {
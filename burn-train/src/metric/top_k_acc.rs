@@ -0,0 +1,143 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Int, Tensor};
+
+/// The top-k accuracy metric.
+///
+/// An item is correctly classified when its target class is among the `k` classes with the
+/// highest outputs.
+pub struct TopKAccuracyMetric<B: Backend> {
+    k: usize,
+    state: NumericMetricState,
+    pad_token: Option<usize>,
+    _b: B,
+}
+
+/// The [top-k accuracy metric](TopKAccuracyMetric) input type.
+#[derive(new)]
+pub struct TopKAccuracyInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> TopKAccuracyMetric<B> {
+    /// Create the metric.
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            state: NumericMetricState::default(),
+            pad_token: None,
+            _b: B::default(),
+        }
+    }
+
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for TopKAccuracyMetric<B> {
+    type Input = TopKAccuracyInput<B>;
+
+    fn update(&mut self, input: &TopKAccuracyInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, n_classes] = input.outputs.dims();
+
+        let targets = input.targets.clone().to_device(&B::Device::default());
+        let outputs = input.outputs.clone().to_device(&B::Device::default());
+
+        // The target is in the top k when fewer than k classes have a higher output.
+        let outputs_target = outputs
+            .clone()
+            .index_select(targets.clone().reshape([batch_size, 1]));
+        let num_higher = outputs
+            .greater(outputs_target.repeat(1, n_classes))
+            .into_int()
+            .sum_dim(1)
+            .reshape([batch_size]);
+        let matches = num_higher.lower_elem(self.k as i64).into_int();
+
+        let (matches, num_items) = match self.pad_token {
+            Some(pad_token) => {
+                let mask = targets.equal_elem(pad_token as i64);
+                let num_pad = mask.clone().into_int().sum().into_scalar().elem::<i64>();
+
+                (matches.mask_fill(mask, 0), batch_size - num_pad as usize)
+            }
+            None => (matches, batch_size),
+        };
+
+        let name = format!("Top-{} Accuracy", self.k);
+        let format = FormatOptions::new(&name).unit("%").precision(2);
+
+        if num_items == 0 {
+            // A batch with only padding doesn't change the epoch value.
+            let formatted = format.format(f64::NAN, self.state.running_value());
+            return MetricEntry::new(name, formatted, f64::NAN.to_string());
+        }
+
+        let accuracy = matches.sum().into_scalar().elem::<f64>() / num_items as f64;
+
+        // Weighted by the number of non-padding items so the epoch value is exact.
+        self.state.update(100.0 * accuracy, num_items, format)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for TopKAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+
+    fn running_value(&self) -> f64 {
+        self.state.running_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_top_k_accuracy_with_padding() {
+        let mut metric = TopKAccuracyMetric::<TestBackend>::new(2).with_pad_token(3);
+        let input = TopKAccuracyInput::new(
+            Tensor::from_data([
+                [0.0, 0.2, 0.8, 0.0], // 2, 1
+                [1.0, 2.0, 0.5, 0.0], // 1, 0
+                [0.4, 0.1, 0.2, 0.0], // 0, 2
+                [0.6, 0.7, 0.2, 0.0], // 1, 0
+                [0.0, 0.1, 0.2, 5.0], // Predicted padding should not count
+                [0.0, 0.1, 0.2, 0.0], // Error on padding should not count
+            ]),
+            Tensor::from_data([2, 2, 1, 0, 3, 3]),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(50.0, metric.value());
+    }
+
+    #[test]
+    fn test_top_k_accuracy_batch_with_only_padding_keeps_epoch_value() {
+        let mut metric = TopKAccuracyMetric::<TestBackend>::new(1).with_pad_token(2);
+        let input = TopKAccuracyInput::new(
+            Tensor::from_data([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]),
+            Tensor::from_data([1, 1]),
+        );
+        let padding = TopKAccuracyInput::new(
+            Tensor::from_data([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]),
+            Tensor::from_data([2, 2]),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        let _entry = metric.update(&padding, &MetricMetadata::fake());
+
+        assert_eq!(50.0, metric.state.running_value());
+    }
+}